@@ -346,10 +346,13 @@ where
     reader.read_exact(&mut text).await?;
 
     // § 4.2 The BAM format (2021-06-03): "Plain header text in SAM; not necessarily
-    // NUL-terminated".
-    bytes_with_nul_to_string(&text).or_else(|_| {
-        String::from_utf8(text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-    })
+    // NUL-terminated". The text may also be padded with NULs up to `l_text`, so only the bytes
+    // before the first NUL are used.
+    if let Some(i) = text.iter().position(|&b| b == 0) {
+        text.truncate(i);
+    }
+
+    String::from_utf8(text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
 }
 
 async fn read_reference_sequences<R>(reader: &mut R) -> io::Result<ReferenceSequences>
@@ -393,10 +396,11 @@ async fn read_record<R>(reader: &mut R, record: &mut Record) -> io::Result<usize
 where
     R: AsyncRead + Unpin,
 {
-    let block_size = match reader.read_u32_le().await {
-        Ok(bs) => usize::try_from(bs).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
-        Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(0),
-        Err(e) => return Err(e),
+    let block_size = match read_block_size(reader).await? {
+        Some(bs) => {
+            usize::try_from(bs).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+        }
+        None => return Ok(0),
     };
 
     record.resize(block_size);
@@ -405,6 +409,32 @@ where
     Ok(block_size)
 }
 
+async fn read_block_size<R>(reader: &mut R) -> io::Result<Option<u32>>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut buf = [0; 4];
+    let mut len = 0;
+
+    while len < buf.len() {
+        match reader.read(&mut buf[len..]).await {
+            Ok(0) => break,
+            Ok(n) => len += n,
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    match len {
+        0 => Ok(None),
+        4 => Ok(Some(u32::from_le_bytes(buf))),
+        _ => Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "unexpected EOF in record block size",
+        )),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -482,6 +512,17 @@ mod tests {
         assert_eq!(block_size, 4);
         assert_eq!(&record[..], &data[4..]);
 
+        let data = [];
+        let mut reader = &data[..];
+        assert_eq!(read_record(&mut reader, &mut record).await?, 0);
+
+        let data = [0x04, 0x00]; // block_size[..2]
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_record(&mut reader, &mut record).await,
+            Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
         Ok(())
     }
 }
@@ -18,6 +18,7 @@ where
     W: AsyncWrite,
 {
     inner: bgzf::AsyncWriter<W>,
+    drop_quality_scores: bool,
 }
 
 impl<W> Writer<W>
@@ -48,6 +49,7 @@ where
     pub fn new(inner: W) -> Self {
         Self {
             inner: bgzf::AsyncWriter::new(inner),
+            drop_quality_scores: false,
         }
     }
 
@@ -139,7 +141,7 @@ where
     /// # }
     /// ```
     pub async fn write_record(&mut self, record: &Record) -> io::Result<()> {
-        write_record(&mut self.inner, record).await
+        write_record(&mut self.inner, record, self.drop_quality_scores).await
     }
 
     /// Writes a SAM record.
@@ -167,7 +169,13 @@ where
         reference_sequences: &sam::header::ReferenceSequences,
         record: &sam::Record,
     ) -> io::Result<()> {
-        write_sam_record(&mut self.inner, reference_sequences, record).await
+        write_sam_record(
+            &mut self.inner,
+            reference_sequences,
+            record,
+            self.drop_quality_scores,
+        )
+        .await
     }
 }
 
@@ -177,6 +185,10 @@ where
 {
     use crate::MAGIC_NUMBER;
 
+    header
+        .validate()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
     writer.write_all(MAGIC_NUMBER).await?;
 
     let text = header.to_string();
@@ -230,15 +242,35 @@ where
     Ok(())
 }
 
-async fn write_record<W>(writer: &mut W, record: &Record) -> io::Result<()>
+async fn write_record<W>(
+    writer: &mut W,
+    record: &Record,
+    drop_quality_scores: bool,
+) -> io::Result<()>
 where
     W: AsyncWrite + Unpin,
 {
+    use crate::writer::{quality_scores_bounds, record::NULL_QUALITY_SCORE, validate_record};
+
+    validate_record(record)?;
+
     let block_size =
         u32::try_from(record.len()).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
     writer.write_u32_le(block_size).await?;
 
-    writer.write_all(record).await?;
+    if drop_quality_scores {
+        let (start, end) = quality_scores_bounds(record);
+
+        writer.write_all(&record[..start]).await?;
+
+        for _ in start..end {
+            writer.write_u8(NULL_QUALITY_SCORE).await?;
+        }
+
+        writer.write_all(&record[end..]).await?;
+    } else {
+        writer.write_all(record).await?;
+    }
 
     Ok(())
 }
@@ -247,6 +279,81 @@ where
 mod tests {
     use super::*;
 
+    #[tokio::test]
+    async fn test_write_record_with_drop_quality_scores() -> Result<(), Box<dyn std::error::Error>>
+    {
+        use crate::Reader;
+
+        let mut writer = Writer::builder(Vec::new())
+            .set_drop_quality_scores(true)
+            .build();
+
+        let header = sam::Header::default();
+        let sam_record = sam::Record::builder()
+            .set_sequence("ATCG".parse()?)
+            .set_quality_scores("NDLS".parse()?)
+            .build()?;
+        let record = Record::try_from_sam_record(header.reference_sequences(), &sam_record)?;
+
+        writer.write_record(&record).await?;
+        writer
+            .write_sam_record(header.reference_sequences(), &sam_record)
+            .await?;
+        writer.shutdown().await?;
+
+        let data = writer.inner.into_inner();
+        let mut reader = Reader::new(data.as_slice());
+        let mut actual = Record::default();
+
+        for _ in 0..2 {
+            reader.read_record(&mut actual)?;
+            assert_eq!(actual.l_seq(), 4);
+            assert_eq!(*actual.quality_scores(), [255, 255, 255, 255]);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_record_with_quality_scores_length_less_than_sequence_length() {
+        let mut data = Record::default().to_vec();
+        data[16..20].copy_from_slice(&4u32.to_le_bytes()); // l_seq = 4
+        data.extend([0x12, 0x48].iter()); // seq = ACGT
+        data.extend([0x2d, 0x23].iter()); // qual (2 of 4 scores)
+        let record = Record::from(data);
+
+        let mut buf = Vec::new();
+        assert!(matches!(
+            write_record(&mut buf, &record, false).await,
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+        assert!(buf.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_write_header_with_invalid_read_group() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::header::ReadGroup;
+
+        let header = sam::Header::builder()
+            .add_read_group(
+                ReadGroup::builder()
+                    .set_id("rg0")
+                    .set_description("a\tb")
+                    .build()?,
+            )
+            .build();
+
+        let mut buf = Vec::new();
+
+        assert!(matches!(
+            write_header(&mut buf, &header).await,
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+        assert!(buf.is_empty());
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_write_reference_sequence() -> Result<(), Box<dyn std::error::Error>> {
         let mut buf = Vec::new();
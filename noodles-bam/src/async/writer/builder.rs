@@ -9,6 +9,7 @@ pub struct Builder<W> {
     inner: W,
     compression_level: Option<Compression>,
     worker_count: Option<usize>,
+    drop_quality_scores: bool,
 }
 
 impl<W> Builder<W>
@@ -20,6 +21,7 @@ where
             inner,
             compression_level: None,
             worker_count: None,
+            drop_quality_scores: false,
         }
     }
 
@@ -56,6 +58,25 @@ where
         self
     }
 
+    /// Sets whether to drop quality scores when writing records.
+    ///
+    /// When enabled, the quality scores of each written BAM or SAM record are replaced with the
+    /// missing quality scores sentinel (`0xff` for each base), i.e., they are stored as `*`,
+    /// regardless of whether the record has quality scores.
+    ///
+    /// By default, quality scores are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let builder = bam::AsyncWriter::builder(Vec::new()).set_drop_quality_scores(true);
+    /// ```
+    pub fn set_drop_quality_scores(mut self, drop_quality_scores: bool) -> Self {
+        self.drop_quality_scores = drop_quality_scores;
+        self
+    }
+
     /// Builds an async BAM writer.
     ///
     /// # Examples
@@ -77,6 +98,7 @@ where
 
         Writer {
             inner: builder.build(),
+            drop_quality_scores: self.drop_quality_scores,
         }
     }
 }
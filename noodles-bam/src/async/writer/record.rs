@@ -3,6 +3,10 @@ use std::{convert::TryFrom, ffi::CString, num};
 use noodles_sam::{self as sam, header::ReferenceSequences};
 use tokio::io::{self, AsyncWrite, AsyncWriteExt};
 
+use crate::writer::record::{
+    get_record_cigar, get_reference_sequence_id, write_cigar_data_field, MAX_CIGAR_OP_COUNT,
+};
+
 // § 1.4 "The alignment section: mandatory fields" (2021-06-03): "A `QNAME` '*' indicates the
 // information is unavailable."
 const MISSING_READ_NAME: &str = "*";
@@ -11,12 +15,11 @@ pub async fn write_sam_record<W>(
     writer: &mut W,
     reference_sequences: &ReferenceSequences,
     record: &sam::Record,
+    drop_quality_scores: bool,
 ) -> io::Result<()>
 where
     W: AsyncWrite + Unpin,
 {
-    use crate::writer::record::calculate_data_len;
-
     let name = record
         .read_name()
         .map(|name| name.as_str())
@@ -28,15 +31,50 @@ where
     let l_read_name = u8::try_from(read_name.len())
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
 
-    let n_cigar_op = u16::try_from(record.cigar().len())
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    let cigar = get_record_cigar(record);
+
+    let n_cigar_op =
+        u16::try_from(cigar.len()).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
 
     let l_seq = u32::try_from(record.sequence().len())
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
 
-    let data_len = calculate_data_len(record.data()).and_then(|len| {
-        u32::try_from(len).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
-    })?;
+    let mut data = Vec::new();
+    write_data(&mut data, record.data()).await?;
+
+    if record.cigar().len() > MAX_CIGAR_OP_COUNT {
+        write_cigar_data_field(&mut data, record)?;
+    }
+
+    // The record is validated before anything is written so that an invalid field does not leave a
+    // partial record in the stream. Like the data, the CIGAR is encoded up front.
+    let mut raw_cigar = Vec::with_capacity(4 * cigar.len());
+    write_cigar(&mut raw_cigar, &cigar).await?;
+
+    let ref_id = get_reference_sequence_id(reference_sequences, record.reference_sequence_name())?;
+    let next_ref_id =
+        get_reference_sequence_id(reference_sequences, record.mate_reference_sequence_name())?;
+
+    let sequence = record.sequence();
+    let quality_scores = record.quality_scores();
+
+    if !sequence.is_empty()
+        && !drop_quality_scores
+        && !quality_scores.is_empty()
+        && sequence.len() != quality_scores.len()
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "quality scores length mismatch: expected {}, got {}",
+                sequence.len(),
+                quality_scores.len()
+            ),
+        ));
+    }
+
+    let data_len =
+        u32::try_from(data.len()).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
 
     let block_size = 32
         + u32::from(l_read_name)
@@ -47,12 +85,7 @@ where
     writer.write_u32_le(block_size).await?;
 
     // ref_id
-    write_reference_sequence_id(
-        writer,
-        reference_sequences,
-        record.reference_sequence_name(),
-    )
-    .await?;
+    writer.write_i32_le(ref_id).await?;
 
     // pos
     write_position(writer, record.position()).await?;
@@ -76,12 +109,7 @@ where
     writer.write_u32_le(l_seq).await?;
 
     // next_ref_id
-    write_reference_sequence_id(
-        writer,
-        reference_sequences,
-        record.mate_reference_sequence_name(),
-    )
-    .await?;
+    writer.write_i32_le(next_ref_id).await?;
 
     // next_pos
     write_position(writer, record.mate_position()).await?;
@@ -93,66 +121,25 @@ where
     writer.write_all(read_name).await?;
 
     // cigar
-    write_cigar(writer, record.cigar()).await?;
-
-    let sequence = record.sequence();
+    writer.write_all(&raw_cigar).await?;
 
     if !sequence.is_empty() {
         // seq
-        write_sequence(writer, record.sequence()).await?;
-
-        let quality_scores = record.quality_scores();
+        write_sequence(writer, sequence).await?;
 
         // qual
-        if sequence.len() == quality_scores.len() {
-            write_quality_scores(writer, record.quality_scores()).await?;
-        } else if quality_scores.is_empty() {
+        if drop_quality_scores || quality_scores.is_empty() {
             write_missing_filled_quality_scores(writer, sequence.len()).await?;
         } else {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidInput,
-                format!(
-                    "quality scores length mismatch: expected {}, got {}",
-                    sequence.len(),
-                    quality_scores.len()
-                ),
-            ));
+            write_quality_scores(writer, quality_scores).await?;
         }
     }
 
-    write_data(writer, record.data()).await?;
+    writer.write_all(&data).await?;
 
     Ok(())
 }
 
-async fn write_reference_sequence_id<W>(
-    writer: &mut W,
-    reference_sequences: &ReferenceSequences,
-    reference_sequence_name: Option<&sam::record::ReferenceSequenceName>,
-) -> io::Result<()>
-where
-    W: AsyncWrite + Unpin,
-{
-    use crate::record::reference_sequence_id;
-
-    let id = match reference_sequence_name {
-        Some(name) => reference_sequences
-            .get_index_of(name.as_str())
-            .ok_or_else(|| {
-                io::Error::new(
-                    io::ErrorKind::InvalidInput,
-                    format!("invalid reference sequence name: {}", name),
-                )
-            })
-            .and_then(|i| {
-                i32::try_from(i).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
-            })?,
-        None => reference_sequence_id::UNMAPPED,
-    };
-
-    writer.write_i32_le(id).await
-}
-
 async fn write_position<W>(
     writer: &mut W,
     position: Option<sam::record::Position>,
@@ -163,7 +150,7 @@ where
     use crate::record::UNMAPPED_POSITION;
 
     let pos = position
-        .map(|p| i32::from(p) - 1)
+        .map(|position| position.to_zero_based())
         .unwrap_or(UNMAPPED_POSITION);
 
     writer.write_i32_le(pos).await
@@ -184,22 +171,9 @@ async fn write_bin<W>(writer: &mut W, record: &sam::Record) -> io::Result<()>
 where
     W: AsyncWrite + Unpin,
 {
-    use crate::writer::record::region_to_bin;
-
-    // § 4.2.1 "BIN field calculation" (2021-06-03): "Note unmapped reads with `POS` 0 (which
-    // becomes -1 in BAM) therefore use `reg2bin(-1, 0)` which is computed as 4680."
-    const UNMAPPED_BIN: u16 = 4680;
-
-    let bin = record
-        .position()
-        .map(|p| i32::from(p) - 1)
-        .map(|start| {
-            let reference_len = record.cigar().reference_len() as i32;
-            let end = start + reference_len;
-            region_to_bin(start, end) as u16
-        })
-        .unwrap_or(UNMAPPED_BIN);
+    use crate::writer::record::calculate_record_bin;
 
+    let bin = calculate_record_bin(record);
     writer.write_u16_le(bin).await
 }
 
@@ -222,8 +196,21 @@ async fn write_cigar<W>(writer: &mut W, cigar: &sam::record::Cigar) -> io::Resul
 where
     W: AsyncWrite + Unpin,
 {
+    use crate::writer::record::MAX_CIGAR_OP_LEN;
+
     for op in cigar.iter() {
         let len = op.len();
+
+        if len > MAX_CIGAR_OP_LEN {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "invalid CIGAR op: {}: length exceeds {}",
+                    op, MAX_CIGAR_OP_LEN
+                ),
+            ));
+        }
+
         let kind = op.kind() as u32;
         let value = len << 4 | kind;
         writer.write_u32_le(value).await?;
@@ -369,12 +356,17 @@ where
 {
     use sam::record::data::field::Value;
 
+    use crate::record::data::field::value::char_to_u8;
+
     fn invalid_array_len(e: num::TryFromIntError) -> io::Error {
         io::Error::new(io::ErrorKind::InvalidInput, e)
     }
 
     match value {
-        Value::Char(c) => writer.write_u8(*c as u8).await?,
+        Value::Char(c) => {
+            let b = char_to_u8(*c)?;
+            writer.write_u8(b).await?;
+        }
         Value::Int(_) => {
             // Integers are handled by `write_data_field_int_value`.
             unreachable!();
@@ -451,36 +443,112 @@ mod tests {
     use super::*;
 
     #[tokio::test]
-    async fn test_write_reference_sequence_id() -> Result<(), Box<dyn std::error::Error>> {
-        use sam::header::ReferenceSequence;
+    async fn test_write_sam_record_with_invalid_fields() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::record::{
+            cigar::{op::Kind, Op},
+            Cigar, Position, QualityScores,
+        };
 
-        let reference_sequences = vec![("sq0", 8), ("sq1", 13)]
+        use crate::writer::record::MAX_CIGAR_OP_LEN;
+
+        let reference_sequences = vec![("sq0", 8)]
             .into_iter()
-            .map(|(name, len)| ReferenceSequence::new(name, len).map(|rs| (name.into(), rs)))
+            .map(|(name, len)| {
+                sam::header::ReferenceSequence::new(name, len).map(|rs| (name.into(), rs))
+            })
             .collect::<Result<_, _>>()?;
 
-        let mut buf = Vec::new();
+        let mut record = sam::Record::default();
+        *record.cigar_mut() = Cigar::from(vec![Op::new(Kind::Match, MAX_CIGAR_OP_LEN + 1)]);
+
+        let record_with_invalid_reference_sequence_name = sam::Record::builder()
+            .set_reference_sequence_name("sq1".parse()?)
+            .set_position(Position::try_from(1)?)
+            .build()?;
+
+        let mut record_with_invalid_quality_scores = sam::Record::builder()
+            .set_sequence("ACGT".parse()?)
+            .build()?;
+        *record_with_invalid_quality_scores.quality_scores_mut() =
+            "NDLS!".parse::<QualityScores>()?;
+
+        for record in &[
+            record,
+            record_with_invalid_reference_sequence_name,
+            record_with_invalid_quality_scores,
+        ] {
+            let mut buf = Vec::new();
+            let result = write_sam_record(&mut buf, &reference_sequences, record, false).await;
+            assert!(matches!(result, Err(ref e) if e.kind() == io::ErrorKind::InvalidInput));
+            // Nothing is written for an invalid record.
+            assert!(buf.is_empty());
+        }
 
-        buf.clear();
-        let reference_sequence_name = "sq0".parse()?;
-        write_reference_sequence_id(
-            &mut buf,
-            &reference_sequences,
-            Some(&reference_sequence_name),
-        )
-        .await?;
-        assert_eq!(buf, [0x00, 0x00, 0x00, 0x00]);
+        Ok(())
+    }
 
-        buf.clear();
-        write_reference_sequence_id(&mut buf, &reference_sequences, None).await?;
-        assert_eq!(buf, [0xff, 0xff, 0xff, 0xff]);
+    #[tokio::test]
+    async fn test_write_sam_record_with_more_than_65535_cigar_ops(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use sam::record::{
+            cigar::{op::Kind, Op},
+            data::field::Tag,
+            Cigar, Position,
+        };
 
-        buf.clear();
-        let reference_sequence_name = "sq2".parse()?;
-        assert!(matches!(
-            write_reference_sequence_id(&mut buf, &reference_sequences, Some(&reference_sequence_name)).await,
-            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput,
-        ));
+        use crate::Record;
+
+        let reference_sequences = vec![("sq0", 65536)]
+            .into_iter()
+            .map(|(name, len)| {
+                sam::header::ReferenceSequence::new(name, len).map(|rs| (name.into(), rs))
+            })
+            .collect::<Result<_, _>>()?;
+
+        // 70000 ops (35000 × 1M1I)
+        let ops: Vec<_> = (0..35000)
+            .flat_map(|_| vec![Op::new(Kind::Match, 1), Op::new(Kind::Insertion, 1)])
+            .collect();
+        let cigar = Cigar::from(ops);
+
+        let sam_record = sam::Record::builder()
+            .set_reference_sequence_name("sq0".parse()?)
+            .set_position(Position::try_from(8)?)
+            .set_cigar(cigar.clone())
+            .set_sequence("A".repeat(70000).parse()?)
+            .set_quality_scores("N".repeat(70000).parse()?)
+            .set_data("NH:i:1".parse()?)
+            .build()?;
+
+        let mut buf = Vec::new();
+        write_sam_record(&mut buf, &reference_sequences, &sam_record, false).await?;
+
+        // The sync writer produces the same record.
+        let mut expected = Vec::new();
+        crate::writer::record::write_sam_record(
+            &mut expected,
+            &reference_sequences,
+            &sam_record,
+            false,
+        )?;
+        assert_eq!(buf, expected);
+
+        // block_size
+        let record = Record::from(buf[4..].to_vec());
+
+        let placeholder_cigar = Cigar::try_from(record.cigar())?;
+        assert_eq!(
+            placeholder_cigar,
+            Cigar::from(vec![
+                Op::new(Kind::SoftClip, 70000),
+                Op::new(Kind::Skip, 35000),
+            ])
+        );
+
+        let actual = record.try_into_sam_record(&reference_sequences)?;
+        assert_eq!(actual.cigar(), &cigar);
+        assert!(!actual.data().contains_key(&Tag::Cigar));
+        assert_eq!(actual, sam_record);
 
         Ok(())
     }
@@ -501,6 +569,31 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_write_bin() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::record::Position;
+
+        let mut buf = Vec::new();
+
+        let record = sam::Record::builder()
+            .set_position(Position::try_from(8)?)
+            .set_cigar("5M".parse()?)
+            .build()?;
+        write_bin(&mut buf, &record).await?;
+        assert_eq!(buf, [0x49, 0x12]); // bin = 4681
+
+        // The end position is past the BAI binning limit, so the placeholder bin is used.
+        buf.clear();
+        let record = sam::Record::builder()
+            .set_position(Position::try_from(1 << 29)?)
+            .set_cigar("2M".parse()?)
+            .build()?;
+        write_bin(&mut buf, &record).await?;
+        assert_eq!(buf, [0x48, 0x12]); // bin = 4680
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_write_missing_filled_quality_scores() -> io::Result<()> {
         let mut buf = Vec::new();
@@ -642,6 +735,15 @@ mod tests {
         let mut buf = Vec::new();
 
         t(&mut buf, &Value::Char('n'), &[b'n']).await?;
+        t(&mut buf, &Value::Char('!'), b"!").await?;
+        t(&mut buf, &Value::Char('~'), b"~").await?;
+
+        buf.clear();
+        assert!(matches!(
+            write_data_field_value(&mut buf, &Value::Char('é')).await,
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
         t(&mut buf, &Value::Float(8.0), &[0x00, 0x00, 0x00, 0x41]).await?;
 
         t(
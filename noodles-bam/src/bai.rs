@@ -41,6 +41,14 @@ use self::index::reference_sequence::Bin;
 
 static MAGIC_NUMBER: &[u8] = b"BAI\x01";
 
+const MAX_BINNABLE_POSITION: i32 = 1 << 29;
+
+/// The bin of an unmapped record without a position.
+///
+/// § 4.2.1 "BIN field calculation" (2021-06-03): "Note unmapped reads with `POS` 0 (which becomes
+/// -1 in BAM) therefore use `reg2bin(-1, 0)` which is computed as 4680."
+pub const UNMAPPED_BIN: u16 = 4680;
+
 /// Reads the entire contents of a BAM index.
 ///
 /// This is a convenience function and is equivalent to opening the file at the given path, reading
@@ -85,3 +93,91 @@ where
     writer.write_header()?;
     writer.write_index(index)
 }
+
+/// Calculates the bin of a region.
+///
+/// The region is a 0-based, half-open interval, i.e., `[start, end)`. This is the calculation used
+/// to set the bin of a BAM record and to assign records to bins when building a BAM index
+/// (§ 5.3 "C source code for computing bin number and overlapping bins" (2021-06-03)).
+///
+/// An unmapped record without a position uses `[-1, 0)`, which calculates to [`UNMAPPED_BIN`].
+///
+/// This returns an error if the end position is greater than 2^29, the maximum position that can
+/// be indexed with a BAM index.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bam::bai;
+/// assert_eq!(bai::region_to_bin(7, 13)?, 4681);
+/// assert_eq!(bai::region_to_bin(-1, 0)?, bai::UNMAPPED_BIN);
+/// # Ok::<(), io::Error>(())
+/// ```
+#[allow(clippy::eq_op)]
+pub fn region_to_bin(start: i32, mut end: i32) -> io::Result<u16> {
+    if end > MAX_BINNABLE_POSITION {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "invalid end position: expected <= {}, got {}; positions beyond this limit cannot be indexed with a BAM index (BAI), use a coordinate-sorted index (CSI) instead",
+                MAX_BINNABLE_POSITION, end
+            ),
+        ));
+    }
+
+    end -= 1;
+
+    let bin = if start >> 14 == end >> 14 {
+        ((1 << 15) - 1) / 7 + (start >> 14)
+    } else if start >> 17 == end >> 17 {
+        ((1 << 12) - 1) / 7 + (start >> 17)
+    } else if start >> 20 == end >> 20 {
+        ((1 << 9) - 1) / 7 + (start >> 20)
+    } else if start >> 23 == end >> 23 {
+        ((1 << 6) - 1) / 7 + (start >> 23)
+    } else if start >> 26 == end >> 26 {
+        ((1 << 3) - 1) / 7 + (start >> 26)
+    } else {
+        0
+    };
+
+    Ok(bin as u16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_region_to_bin() -> io::Result<()> {
+        // § 5.3 C source code for computing bin number and overlapping bins (2021-01-07)
+        // [-1, 0]
+        assert_eq!(region_to_bin(-1, 0)?, 4680);
+        // [8, 13]
+        assert_eq!(region_to_bin(7, 13)?, 4681);
+        // [63245986, 63245986]
+        assert_eq!(region_to_bin(63245985, 63255986)?, 8541);
+        // [536870912, 536870912]
+        assert_eq!(region_to_bin(536870911, 536870912)?, 37448);
+
+        // [536870913, 536870913]
+        assert!(matches!(
+            region_to_bin(536870912, 536870913),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_region_to_bin_at_unmapped_bin_boundary() -> io::Result<()> {
+        // [-1, 0)
+        assert_eq!(region_to_bin(-1, 0)?, UNMAPPED_BIN);
+        // [0, 1)
+        assert_eq!(region_to_bin(0, 1)?, UNMAPPED_BIN + 1);
+        // [-1, 1)
+        assert_eq!(region_to_bin(-1, 1)?, 0);
+        Ok(())
+    }
+}
@@ -1,7 +1,10 @@
 use std::convert::TryFrom;
 
 use noodles_bgzf as bgzf;
-use noodles_csi::index::reference_sequence::{bin::Chunk, Metadata};
+use noodles_csi::{
+    self as csi,
+    index::reference_sequence::{bin::Chunk, Metadata},
+};
 use tokio::io::{self, AsyncRead, AsyncReadExt};
 
 use crate::bai::{
@@ -86,7 +89,7 @@ where
         let reference_sequences = read_reference_sequences(&mut self.inner).await?;
 
         let unplaced_unmapped_record_count =
-            read_unplaced_unmapped_record_count(&mut self.inner).await?;
+            csi::r#async::read_unplaced_unmapped_record_count(&mut self.inner).await?;
 
         Ok(Index::new(
             reference_sequences,
@@ -258,17 +261,6 @@ where
     Ok(Metadata::new(ref_beg, ref_end, n_mapped, n_unmapped))
 }
 
-async fn read_unplaced_unmapped_record_count<R>(reader: &mut R) -> io::Result<Option<u64>>
-where
-    R: AsyncRead + Unpin,
-{
-    match reader.read_u64_le().await {
-        Ok(n_no_coor) => Ok(Some(n_no_coor)),
-        Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => Ok(None),
-        Err(e) => Err(e),
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -371,23 +363,4 @@ mod tests {
 
         Ok(())
     }
-
-    #[tokio::test]
-    async fn test_read_unplaced_unmapped_record_count() -> io::Result<()> {
-        let data = [];
-        let mut reader = &data[..];
-        assert_eq!(
-            read_unplaced_unmapped_record_count(&mut reader).await?,
-            None
-        );
-
-        let data = [0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
-        let mut reader = &data[..];
-        assert_eq!(
-            read_unplaced_unmapped_record_count(&mut reader).await?,
-            Some(8)
-        );
-
-        Ok(())
-    }
 }
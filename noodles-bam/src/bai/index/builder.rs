@@ -105,7 +105,7 @@ mod tests {
     use std::convert::TryFrom;
 
     use noodles_bgzf as bgzf;
-    use noodles_csi::BinningIndex;
+    use noodles_csi::{BinningIndex, BinningIndexReferenceSequence};
     use noodles_sam::{
         self as sam,
         record::{Flags, Position},
@@ -142,10 +142,28 @@ mod tests {
             ),
         )?;
 
+        // placed unmapped
+        let record = Record::try_from_sam_record(
+            &reference_sequences,
+            &sam::Record::builder()
+                .set_flags(Flags::UNMAPPED)
+                .set_reference_sequence_name("sq0".parse()?)
+                .set_position(Position::try_from(2)?)
+                .build()?,
+        )?;
+
         builder.add_record(
-            &Record::default(),
+            &record,
             Chunk::new(
                 bgzf::VirtualPosition::from(89),
+                bgzf::VirtualPosition::from(110),
+            ),
+        )?;
+
+        builder.add_record(
+            &Record::default(),
+            Chunk::new(
+                bgzf::VirtualPosition::from(110),
                 bgzf::VirtualPosition::from(144),
             ),
         )?;
@@ -154,6 +172,10 @@ mod tests {
         assert_eq!(index.reference_sequences().len(), 2);
         assert_eq!(index.unplaced_unmapped_record_count(), Some(1));
 
+        let metadata = index.reference_sequences()[0].metadata();
+        assert_eq!(metadata.map(|m| m.mapped_record_count()), Some(1));
+        assert_eq!(metadata.map(|m| m.unmapped_record_count()), Some(1));
+
         Ok(())
     }
 }
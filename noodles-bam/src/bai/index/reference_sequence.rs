@@ -102,6 +102,9 @@ impl ReferenceSequence {
 
     /// Returns a list of 16 kbp intervals that make up the linear index.
     ///
+    /// The _i_-th interval is the offset of the first record that overlaps the 1-based positions
+    /// `[i * 16384 + 1, (i + 1) * 16384]`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -18,6 +18,9 @@ pub(crate) const METADATA_CHUNK_COUNT: u32 = 2;
 /// Bin numbers have an effective range between 0 and 37449, inclusive. An optional pseudo-bin at
 /// bin number 37450 holds two pairs of metadata: virtual positions of the start and end of the
 /// reference sequence and the number of mapped and unmapped reads in the reference sequence.
+///
+/// A bin covers a 0-based, half-open interval of the reference sequence, as calculated by
+/// `reg2bin`. Query intervals are 1-based and are converted when finding the overlapping bins.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Bin {
     id: u32,
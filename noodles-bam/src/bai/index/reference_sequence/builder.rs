@@ -70,7 +70,9 @@ impl Builder {
     fn update_linear_index(&mut self, record: &Record, chunk: Chunk) -> io::Result<()> {
         let start = record.position().map(i32::from).expect("missing position");
         let reference_len = record.cigar().reference_len().map(|len| len as i32)?;
-        let end = start + reference_len - 1;
+        // A record that does not consume the reference sequence, e.g., a placed unmapped record,
+        // covers its start position.
+        let end = start + cmp::max(reference_len, 1) - 1;
 
         let linear_index_start_offset = ((start - 1) / WINDOW_SIZE) as usize;
         let linear_index_end_offset = ((end - 1) / WINDOW_SIZE) as usize;
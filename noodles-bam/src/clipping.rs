@@ -0,0 +1,135 @@
+use std::io;
+
+use noodles_sam::record::cigar::op::Kind;
+
+use super::Record;
+
+/// Sums the soft-clipped and hard-clipped bases of records.
+///
+/// Clips (`S`, `H`) may only be at the start and end of a CIGAR, so this is the total length of
+/// the leading and trailing clips. Records without a CIGAR, e.g., unmapped records, add no
+/// clipped bases but are still counted.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io};
+/// use noodles_bam as bam;
+///
+/// let mut reader = File::open("sample.bam").map(bam::Reader::new)?;
+/// reader.read_header()?;
+/// reader.read_reference_sequences()?;
+///
+/// let summary = bam::clipped_bases(reader.records())?;
+/// println!("soft clipped bases = {}", summary.soft_clipped_bases());
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn clipped_bases<I>(records: I) -> io::Result<ClippingSummary>
+where
+    I: IntoIterator<Item = io::Result<Record>>,
+{
+    let mut summary = ClippingSummary::default();
+
+    for result in records {
+        let record = result?;
+
+        for op in record.cigar().ops() {
+            let op = op?;
+
+            match op.kind() {
+                Kind::SoftClip => summary.soft_clipped_bases += u64::from(op.len()),
+                Kind::HardClip => summary.hard_clipped_bases += u64::from(op.len()),
+                _ => {}
+            }
+        }
+
+        summary.record_count += 1;
+    }
+
+    Ok(summary)
+}
+
+/// A summary of clipped bases.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ClippingSummary {
+    record_count: u64,
+    soft_clipped_bases: u64,
+    hard_clipped_bases: u64,
+}
+
+impl ClippingSummary {
+    /// Returns the number of records.
+    pub fn record_count(&self) -> u64 {
+        self.record_count
+    }
+
+    /// Returns the total number of soft-clipped bases.
+    pub fn soft_clipped_bases(&self) -> u64 {
+        self.soft_clipped_bases
+    }
+
+    /// Returns the total number of hard-clipped bases.
+    pub fn hard_clipped_bases(&self) -> u64 {
+        self.hard_clipped_bases
+    }
+
+    /// Returns the mean number of soft-clipped bases per record.
+    ///
+    /// This returns `None` if there are no records.
+    pub fn mean_soft_clipped_bases(&self) -> Option<f64> {
+        mean(self.soft_clipped_bases, self.record_count)
+    }
+
+    /// Returns the mean number of hard-clipped bases per record.
+    ///
+    /// This returns `None` if there are no records.
+    pub fn mean_hard_clipped_bases(&self) -> Option<f64> {
+        mean(self.hard_clipped_bases, self.record_count)
+    }
+}
+
+fn mean(sum: u64, n: u64) -> Option<f64> {
+    if n == 0 {
+        None
+    } else {
+        Some(sum as f64 / n as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::{self as sam, header::ReferenceSequences, record::Flags};
+
+    use super::*;
+
+    #[test]
+    fn test_clipped_bases() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequences = ReferenceSequences::default();
+        let mut records = Vec::new();
+
+        for cigar in &["2H3S10M2S", "10M", "4S6M1H", "*"] {
+            let sam_record = sam::Record::builder()
+                .set_flags(Flags::empty())
+                .set_cigar(cigar.parse()?)
+                .build()?;
+
+            records.push(Record::try_from_sam_record(
+                &reference_sequences,
+                &sam_record,
+            )?);
+        }
+
+        let summary = clipped_bases(records.into_iter().map(Ok))?;
+        assert_eq!(summary.record_count(), 4);
+        assert_eq!(summary.soft_clipped_bases(), 9);
+        assert_eq!(summary.hard_clipped_bases(), 3);
+        assert_eq!(summary.mean_soft_clipped_bases(), Some(2.25));
+        assert_eq!(summary.mean_hard_clipped_bases(), Some(0.75));
+
+        let summary = clipped_bases(Vec::new())?;
+        assert_eq!(summary, ClippingSummary::default());
+        assert!(summary.mean_soft_clipped_bases().is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,380 @@
+use std::{
+    io,
+    ops::{Bound, RangeBounds},
+};
+
+use noodles_core::Region;
+use noodles_fasta as fasta;
+use noodles_sam::{header::ReferenceSequences, record::cigar::op::Kind};
+
+use super::Record;
+
+// 4-bit encoded bases (§ 4.2.3 SEQ and QUAL encoding (2021-06-03)).
+const BASES: [(u8, u8); 4] = [(1, b'A'), (2, b'C'), (4, b'G'), (8, b'T')];
+
+const MISSING_QUALITY_SCORE: u8 = 0xff;
+
+/// Builds a consensus sequence from the records that overlap a region.
+///
+/// For each reference position in the region, the bases aligned to the position, i.e., those of
+/// alignment matches (`M`), sequence matches (`=`), and sequence mismatches (`X`), are tallied.
+/// Each base is weighted by its quality score plus one, so that bases with a quality score of 0
+/// still count. If a record has no quality scores, each of its bases has a weight of 1. Only `A`,
+/// `C`, `G`, and `T` are tallied; other bases, e.g., `N`, are ignored.
+///
+/// The consensus base at each position is the base with the greatest total weight. When bases
+/// tie, the reference base is used if it is one of the tied bases; otherwise, the first tied base
+/// in the order `A`, `C`, `G`, `T` is used. Positions with no tallied bases (a depth of 0) fall back
+/// to the reference base.
+///
+/// Records that are unmapped, secondary, QC failed, or duplicates are skipped, as are records
+/// without a sequence (`*`). Records that are not on the region's reference sequence, as resolved
+/// by the given reference sequences, are also skipped.
+///
+/// The region must be on the given reference sequence. Unbounded region ends are clamped to the
+/// reference sequence.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io};
+/// use noodles_bam::{self as bam, bai};
+/// use noodles_core::Region;
+/// use noodles_fasta as fasta;
+///
+/// let mut reader = File::open("sample.bam").map(bam::Reader::new)?;
+/// reader.read_header()?;
+/// let reference_sequences = reader.read_reference_sequences()?;
+///
+/// let index = bai::read("sample.bam.bai")?;
+/// let region = Region::mapped("sq0", 8..=13);
+/// let query = reader.query(&reference_sequences, &index, &region)?;
+///
+/// let reference_sequence = fasta::Record::new(
+///     fasta::record::Definition::new(String::from("sq0"), None),
+///     b"ACGTACGTACGTACGT".to_vec(),
+/// );
+///
+/// let sequence = bam::consensus(query, &reference_sequences, &region, &reference_sequence)?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn consensus<I>(
+    records: I,
+    reference_sequences: &ReferenceSequences,
+    region: &Region,
+    reference_sequence: &fasta::Record,
+) -> io::Result<Vec<u8>>
+where
+    I: IntoIterator<Item = io::Result<Record>>,
+{
+    let (start, end) = resolve_interval(region, reference_sequence)?;
+
+    let reference_sequence_id = reference_sequences
+        .get_index_of(reference_sequence.name())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "invalid reference sequence name: {}",
+                    reference_sequence.name()
+                ),
+            )
+        })?;
+
+    let len = end.saturating_sub(start) + 1;
+    let mut weights = vec![[0u64; 4]; len];
+
+    for result in records {
+        let record = result?;
+
+        let record_reference_sequence_id = record
+            .reference_sequence_id()
+            .map(|id| i32::from(id) as usize);
+
+        if record_reference_sequence_id != Some(reference_sequence_id) {
+            continue;
+        }
+
+        add_record(&mut weights, start, end, &record)?;
+    }
+
+    let sequence = reference_sequence.sequence()[start - 1..end]
+        .iter()
+        .zip(weights.iter())
+        .map(|(&reference_base, position_weights)| call_base(reference_base, position_weights))
+        .collect();
+
+    Ok(sequence)
+}
+
+// Returns the 1-based, closed interval of the region.
+fn resolve_interval(
+    region: &Region,
+    reference_sequence: &fasta::Record,
+) -> io::Result<(usize, usize)> {
+    let mapped = region
+        .as_mapped()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "region is not mapped"))?;
+
+    if mapped.name() != reference_sequence.name() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "region reference sequence name mismatch: expected {}, got {}",
+                reference_sequence.name(),
+                mapped.name()
+            ),
+        ));
+    }
+
+    let interval = mapped.interval();
+    let reference_sequence_len = reference_sequence.sequence().len() as i64;
+
+    let start = match interval.start_bound() {
+        Bound::Included(&n) => i64::from(n),
+        Bound::Excluded(&n) => i64::from(n) + 1,
+        Bound::Unbounded => 1,
+    };
+
+    let end = match interval.end_bound() {
+        Bound::Included(&n) => i64::from(n),
+        Bound::Excluded(&n) => i64::from(n) - 1,
+        Bound::Unbounded => reference_sequence_len,
+    };
+
+    if start < 1 || end < start || end > reference_sequence_len {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "region is out of reference sequence bounds",
+        ));
+    }
+
+    Ok((start as usize, end as usize))
+}
+
+fn add_record(
+    weights: &mut [[u64; 4]],
+    start: usize,
+    end: usize,
+    record: &Record,
+) -> io::Result<()> {
+    let flags = record.flags();
+
+    if flags.is_unmapped() || flags.is_secondary() || flags.is_qc_fail() || flags.is_duplicate() {
+        return Ok(());
+    }
+
+    // § 1.4 "The alignment section: mandatory fields" (2021-06-03): A SEQ of `*` is valid even
+    // when the record has a CIGAR, but it has no bases to tally.
+    if record.l_seq() == 0 {
+        return Ok(());
+    }
+
+    let mut reference_position = match record.position() {
+        Some(position) => i32::from(position) as usize,
+        None => return Ok(()),
+    };
+
+    let quality_scores = record.quality_scores();
+    let mut read_position = 0;
+
+    for result in record.cigar().ops() {
+        let op = result?;
+        let len = op.len() as usize;
+
+        match op.kind() {
+            Kind::Match | Kind::SeqMatch | Kind::SeqMismatch => {
+                for i in 0..len {
+                    let position = reference_position + i;
+
+                    if position < start || position > end {
+                        continue;
+                    }
+
+                    let j = read_position + i;
+
+                    let base = record.base_at(j).ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            "CIGAR read length exceeds sequence length",
+                        )
+                    })?;
+
+                    let weight = match quality_scores.get(j).copied() {
+                        Some(MISSING_QUALITY_SCORE) | None => 1,
+                        Some(score) => u64::from(score) + 1,
+                    };
+
+                    if let Some(k) = BASES.iter().position(|&(code, _)| code == base) {
+                        weights[position - start][k] += weight;
+                    }
+                }
+
+                reference_position += len;
+                read_position += len;
+            }
+            Kind::Insertion | Kind::SoftClip => read_position += len,
+            Kind::Deletion | Kind::Skip => reference_position += len,
+            Kind::HardClip | Kind::Pad => {}
+        }
+
+        if reference_position > end {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+fn call_base(reference_base: u8, weights: &[u64; 4]) -> u8 {
+    let max_weight = weights.iter().copied().max().unwrap_or_default();
+
+    if max_weight == 0 {
+        return reference_base;
+    }
+
+    let mut tied_bases = BASES
+        .iter()
+        .zip(weights.iter())
+        .filter(|(_, &weight)| weight == max_weight)
+        .map(|(&(_, base), _)| base);
+
+    let first_base = tied_bases.next().unwrap_or(reference_base);
+    let upper_reference_base = reference_base.to_ascii_uppercase();
+
+    if first_base == upper_reference_base || tied_bases.any(|base| base == upper_reference_base) {
+        upper_reference_base
+    } else {
+        first_base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use noodles_sam::{
+        self as sam,
+        header::{ReferenceSequence, ReferenceSequences},
+        record::Position,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_consensus() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequence = fasta::Record::new(
+            fasta::record::Definition::new(String::from("sq0"), None),
+            b"ACGTACGTACGTACGT".to_vec(),
+        );
+
+        let reference_sequences: ReferenceSequences = vec![
+            ReferenceSequence::new("sq0", 16)?,
+            ReferenceSequence::new("sq1", 8)?,
+        ]
+        .into_iter()
+        .map(|rs| (rs.name().into(), rs))
+        .collect();
+
+        // All reads have a T at position 5, where the reference has an A.
+        let records = [
+            (1, "1S8M", "NACGTTCGT"),
+            (3, "8M", "GTTCGTAC"),
+            (5, "4M2I4M", "TCGTGGACGT"),
+        ]
+        .iter()
+        .map(|&(position, cigar, sequence)| {
+            let record = sam::Record::builder()
+                .set_flags(sam::record::Flags::empty())
+                .set_reference_sequence_name("sq0".parse()?)
+                .set_position(Position::try_from(position)?)
+                .set_cigar(cigar.parse()?)
+                .set_sequence(sequence.parse()?)
+                .build()?;
+
+            Record::try_from_sam_record(&reference_sequences, &record).map_err(|e| e.into())
+        })
+        .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+
+        let region = Region::mapped("sq0", 1..=14);
+        let actual = consensus(
+            records.iter().cloned().map(Ok),
+            &reference_sequences,
+            &region,
+            &reference_sequence,
+        )?;
+        // Positions 13 and 14 are not covered and fall back to the reference.
+        assert_eq!(actual, b"ACGTTCGTACGTAC");
+
+        let region = Region::mapped("sq0", 4..=6);
+        let actual = consensus(
+            records.iter().cloned().map(Ok),
+            &reference_sequences,
+            &region,
+            &reference_sequence,
+        )?;
+        assert_eq!(actual, b"TTC");
+
+        // Records on other reference sequences are skipped.
+        let other_record = sam::Record::builder()
+            .set_flags(sam::record::Flags::empty())
+            .set_reference_sequence_name("sq1".parse()?)
+            .set_position(Position::try_from(1)?)
+            .set_cigar("8M".parse()?)
+            .set_sequence("GGGGGGGG".parse()?)
+            .build()?;
+        let other_record = Record::try_from_sam_record(&reference_sequences, &other_record)?;
+
+        let region = Region::mapped("sq0", 1..=4);
+        let actual = consensus(
+            vec![Ok(other_record)],
+            &reference_sequences,
+            &region,
+            &reference_sequence,
+        )?;
+        assert_eq!(actual, b"ACGT");
+
+        // Records without a sequence are skipped.
+        let record_without_sequence = sam::Record::builder()
+            .set_flags(sam::record::Flags::empty())
+            .set_reference_sequence_name("sq0".parse()?)
+            .set_position(Position::try_from(1)?)
+            .set_cigar("4M".parse()?)
+            .build()?;
+        let record_without_sequence =
+            Record::try_from_sam_record(&reference_sequences, &record_without_sequence)?;
+
+        let actual = consensus(
+            vec![Ok(record_without_sequence)],
+            &reference_sequences,
+            &region,
+            &reference_sequence,
+        )?;
+        assert_eq!(actual, b"ACGT");
+
+        let region = Region::mapped("sq1", 1..=4);
+        assert!(matches!(
+            consensus(Vec::new(), &reference_sequences, &region, &reference_sequence),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let region = Region::mapped("sq0", 1..=17);
+        assert!(matches!(
+            consensus(Vec::new(), &reference_sequences, &region, &reference_sequence),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_call_base() {
+        assert_eq!(call_base(b'A', &[0, 0, 0, 0]), b'A');
+        assert_eq!(call_base(b'a', &[0, 0, 0, 0]), b'a');
+        assert_eq!(call_base(b'A', &[0, 0, 0, 31]), b'T');
+        // ties
+        assert_eq!(call_base(b'T', &[31, 0, 0, 31]), b'T');
+        assert_eq!(call_base(b't', &[31, 0, 0, 31]), b'T');
+        assert_eq!(call_base(b'N', &[0, 31, 31, 0]), b'C');
+    }
+}
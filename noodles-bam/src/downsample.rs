@@ -0,0 +1,175 @@
+use std::io;
+
+use super::Record;
+
+/// Downsamples BAM records by read name.
+///
+/// This returns an iterator adaptor that keeps a reproducible fraction of the given records. Each
+/// read name is hashed, along with the seed, to a value in [0, 1), and a record is kept when that
+/// value is less than `fraction`. Records with the same name, e.g., the mates of a pair, are
+/// therefore always either all kept or all dropped, and the same input, fraction, and seed always
+/// produce the same output.
+///
+/// A fraction of 0 drops all records, and a fraction of 1 keeps all records.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io};
+/// use noodles_bam as bam;
+///
+/// let mut reader = File::open("sample.bam").map(bam::Reader::new)?;
+/// reader.read_header()?;
+/// reader.read_reference_sequences()?;
+///
+/// for result in bam::downsample(reader.records(), 0.1, 0) {
+///     let record = result?;
+///     println!("{:?}", record);
+/// }
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn downsample<I>(records: I, fraction: f64, seed: u64) -> Downsample<I::IntoIter>
+where
+    I: IntoIterator<Item = io::Result<Record>>,
+{
+    Downsample {
+        records: records.into_iter(),
+        fraction,
+        seed,
+    }
+}
+
+/// An iterator over a downsampled set of BAM records.
+///
+/// This is created by calling [`downsample`].
+pub struct Downsample<I> {
+    records: I,
+    fraction: f64,
+    seed: u64,
+}
+
+impl<I> Iterator for Downsample<I>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let record = match self.records.next()? {
+                Ok(record) => record,
+                Err(e) => return Some(Err(e)),
+            };
+
+            let read_name = match record.read_name() {
+                Ok(read_name) => read_name,
+                Err(e) => return Some(Err(io::Error::new(io::ErrorKind::InvalidData, e))),
+            };
+
+            if to_unit_interval(hash(self.seed, read_name.to_bytes())) < self.fraction {
+                return Some(Ok(record));
+            }
+        }
+    }
+}
+
+// 64-bit FNV-1a, seeded and finalized with the SplitMix64 mixer. Unlike the standard library's
+// hashers, this is guaranteed to be stable across platforms and compiler versions.
+fn hash(seed: u64, buf: &[u8]) -> u64 {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const FNV_PRIME: u64 = 0x100000001b3;
+
+    let mut h = FNV_OFFSET_BASIS ^ mix(seed);
+
+    for &b in buf {
+        h ^= u64::from(b);
+        h = h.wrapping_mul(FNV_PRIME);
+    }
+
+    mix(h)
+}
+
+fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+// Maps the upper 53 bits of a hash to [0, 1).
+fn to_unit_interval(n: u64) -> f64 {
+    (n >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use noodles_sam::{self as sam, header::ReferenceSequences, record::Flags};
+
+    use super::*;
+
+    fn build_records() -> Result<Vec<Record>, Box<dyn std::error::Error>> {
+        let reference_sequences = ReferenceSequences::default();
+        let mut records = Vec::new();
+
+        for i in 0..100 {
+            let read_name = format!("r{}", i);
+
+            for flags in [Flags::PAIRED | Flags::READ_1, Flags::PAIRED | Flags::READ_2].iter() {
+                let sam_record = sam::Record::builder()
+                    .set_read_name(read_name.parse()?)
+                    .set_flags(*flags | Flags::UNMAPPED | Flags::MATE_UNMAPPED)
+                    .build()?;
+
+                records.push(Record::try_from_sam_record(
+                    &reference_sequences,
+                    &sam_record,
+                )?);
+            }
+        }
+
+        Ok(records)
+    }
+
+    fn read_names(records: Vec<Record>, fraction: f64, seed: u64) -> io::Result<Vec<String>> {
+        downsample(records.into_iter().map(Ok), fraction, seed)
+            .map(|result| {
+                result.and_then(|record| {
+                    record
+                        .read_name()
+                        .map(|name| name.to_string_lossy().into_owned())
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_downsample() -> Result<(), Box<dyn std::error::Error>> {
+        let records = build_records()?;
+
+        let actual = read_names(records.clone(), 0.5, 8)?;
+        assert!(!actual.is_empty());
+        assert!(actual.len() < records.len());
+
+        // deterministic
+        let expected = read_names(records.clone(), 0.5, 8)?;
+        assert_eq!(actual, expected);
+
+        // mates are kept together
+        let mut counts: HashMap<_, usize> = HashMap::new();
+
+        for read_name in &actual {
+            *counts.entry(read_name).or_default() += 1;
+        }
+
+        assert!(counts.values().all(|&n| n == 2));
+
+        assert_ne!(read_names(records.clone(), 0.5, 13)?, actual);
+
+        assert!(read_names(records.clone(), 0.0, 8)?.is_empty());
+        assert_eq!(read_names(records.clone(), 1.0, 8)?.len(), records.len());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,242 @@
+use std::io;
+
+use super::Record;
+
+/// Collects the absolute template lengths (TLEN) of properly-aligned pairs.
+///
+/// Each pair is counted once, using the first segment in the template. A record is only counted
+/// when it is
+///
+///   * paired and in a proper pair,
+///   * the first segment,
+///   * neither secondary nor supplementary,
+///   * mapped and its mate is mapped,
+///   * on the same reference sequence as its mate, and
+///   * has a nonzero template length.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io};
+/// use noodles_bam as bam;
+///
+/// let mut reader = File::open("sample.bam").map(bam::Reader::new)?;
+/// reader.read_header()?;
+/// reader.read_reference_sequences()?;
+///
+/// let insert_sizes = bam::insert_sizes(reader.records())?;
+///
+/// if let Some(summary) = bam::InsertSizeSummary::new(&insert_sizes) {
+///     println!("mean = {}", summary.mean());
+/// }
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn insert_sizes<I>(records: I) -> io::Result<Vec<u32>>
+where
+    I: IntoIterator<Item = io::Result<Record>>,
+{
+    let mut insert_sizes = Vec::new();
+
+    for result in records {
+        let record = result?;
+
+        if let Some(insert_size) = insert_size(&record) {
+            insert_sizes.push(insert_size);
+        }
+    }
+
+    Ok(insert_sizes)
+}
+
+fn insert_size(record: &Record) -> Option<u32> {
+    let flags = record.flags();
+
+    if !flags.is_paired()
+        || !flags.is_proper_pair()
+        || !flags.is_read_1()
+        || flags.is_secondary()
+        || flags.is_supplementary()
+        || flags.is_unmapped()
+        || flags.is_mate_unmapped()
+    {
+        return None;
+    }
+
+    let reference_sequence_id = record.reference_sequence_id()?;
+    let mate_reference_sequence_id = record.mate_reference_sequence_id()?;
+
+    if reference_sequence_id != mate_reference_sequence_id {
+        return None;
+    }
+
+    match record.template_length().unsigned_abs() {
+        0 => None,
+        n => Some(n),
+    }
+}
+
+/// A summary of an insert size distribution.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InsertSizeSummary {
+    mean: f64,
+    median: f64,
+    median_absolute_deviation: f64,
+}
+
+impl InsertSizeSummary {
+    /// Summarizes a list of insert sizes.
+    ///
+    /// This returns `None` if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam::InsertSizeSummary;
+    ///
+    /// let summary = InsertSizeSummary::new(&[8, 13, 21]).unwrap();
+    /// assert_eq!(summary.median(), 13.0);
+    ///
+    /// assert!(InsertSizeSummary::new(&[]).is_none());
+    /// ```
+    pub fn new(insert_sizes: &[u32]) -> Option<Self> {
+        if insert_sizes.is_empty() {
+            return None;
+        }
+
+        let sum: f64 = insert_sizes.iter().map(|&n| f64::from(n)).sum();
+        let mean = sum / insert_sizes.len() as f64;
+
+        let mut values: Vec<_> = insert_sizes.iter().map(|&n| f64::from(n)).collect();
+        let median = median(&mut values);
+
+        let mut deviations: Vec<_> = values.iter().map(|n| (n - median).abs()).collect();
+        let median_absolute_deviation = self::median(&mut deviations);
+
+        Some(Self {
+            mean,
+            median,
+            median_absolute_deviation,
+        })
+    }
+
+    /// Returns the mean insert size.
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Returns the median insert size.
+    pub fn median(&self) -> f64 {
+        self.median
+    }
+
+    /// Returns the median absolute deviation (MAD) of the insert sizes.
+    pub fn median_absolute_deviation(&self) -> f64 {
+        self.median_absolute_deviation
+    }
+}
+
+// `values` must not be empty and must not contain NaN.
+fn median(values: &mut [f64]) -> f64 {
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mid = values.len() / 2;
+
+    if values.len() % 2 == 1 {
+        values[mid]
+    } else {
+        (values[mid - 1] + values[mid]) / 2.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use noodles_sam::{
+        self as sam,
+        header::{ReferenceSequence, ReferenceSequences},
+        record::{Flags, Position},
+    };
+
+    use super::*;
+
+    fn build_reference_sequences() -> Result<ReferenceSequences, Box<dyn std::error::Error>> {
+        vec![("sq0", 1000), ("sq1", 1000)]
+            .into_iter()
+            .map(|(name, len)| ReferenceSequence::new(name, len).map(|rs| (name.into(), rs)))
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.into())
+    }
+
+    fn build_record(
+        reference_sequences: &ReferenceSequences,
+        flags: Flags,
+        mate_reference_sequence_name: &str,
+        template_length: i32,
+    ) -> Result<Record, Box<dyn std::error::Error>> {
+        let sam_record = sam::Record::builder()
+            .set_flags(flags)
+            .set_reference_sequence_name("sq0".parse()?)
+            .set_position(Position::try_from(8)?)
+            .set_mate_reference_sequence_name(mate_reference_sequence_name.parse()?)
+            .set_mate_position(Position::try_from(13)?)
+            .set_template_length(template_length)
+            .build()?;
+
+        Record::try_from_sam_record(reference_sequences, &sam_record).map_err(|e| e.into())
+    }
+
+    #[test]
+    fn test_insert_sizes() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequences = build_reference_sequences()?;
+
+        let proper_pair = Flags::PAIRED | Flags::PROPER_PAIR;
+        let read_1 = proper_pair | Flags::READ_1;
+        let read_2 = proper_pair | Flags::READ_2;
+
+        let records = vec![
+            build_record(&reference_sequences, read_1, "sq0", 300)?,
+            build_record(&reference_sequences, read_2, "sq0", -300)?,
+            build_record(
+                &reference_sequences,
+                read_1 | Flags::REVERSE_COMPLEMENTED,
+                "sq0",
+                -250,
+            )?,
+            build_record(&reference_sequences, read_2, "sq0", 250)?,
+            // unpaired
+            build_record(&reference_sequences, Flags::empty(), "sq0", 0)?,
+            // secondary
+            build_record(&reference_sequences, read_1 | Flags::SECONDARY, "sq0", 400)?,
+            // supplementary
+            build_record(
+                &reference_sequences,
+                read_1 | Flags::SUPPLEMENTARY,
+                "sq0",
+                400,
+            )?,
+            // mate on another reference sequence
+            build_record(&reference_sequences, read_1, "sq1", 0)?,
+        ];
+
+        let actual = insert_sizes(records.into_iter().map(Ok))?;
+        assert_eq!(actual, [300, 250]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_size_summary() {
+        assert!(InsertSizeSummary::new(&[]).is_none());
+
+        let summary = InsertSizeSummary::new(&[300, 250, 320, 280]).unwrap();
+        assert_eq!(summary.mean(), 287.5);
+        assert_eq!(summary.median(), 290.0);
+        // deviations: 10, 40, 30, 10
+        assert_eq!(summary.median_absolute_deviation(), 20.0);
+
+        let summary = InsertSizeSummary::new(&[300, 250, 320]).unwrap();
+        assert_eq!(summary.median(), 300.0);
+        assert_eq!(summary.median_absolute_deviation(), 20.0);
+    }
+}
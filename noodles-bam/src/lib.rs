@@ -53,13 +53,39 @@
 mod r#async;
 
 pub mod bai;
+mod clipping;
+#[cfg(feature = "fasta")]
+mod consensus;
+mod downsample;
+mod insert_sizes;
+mod mapping_quality_histogram;
+mod merge;
 pub mod reader;
 pub mod record;
+#[cfg(feature = "fastq")]
+mod unaligned;
+mod validate;
 mod writer;
 
-pub use self::{reader::Reader, record::Record, writer::Writer};
+pub use self::{
+    clipping::{clipped_bases, ClippingSummary},
+    downsample::{downsample, Downsample},
+    insert_sizes::{insert_sizes, InsertSizeSummary},
+    mapping_quality_histogram::mapping_quality_histogram,
+    merge::merge,
+    reader::Reader,
+    record::Record,
+    validate::{validate, ValidationIssue},
+    writer::Writer,
+};
 
 #[cfg(feature = "async")]
 pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
 
+#[cfg(feature = "fasta")]
+pub use self::consensus::consensus;
+
+#[cfg(feature = "fastq")]
+pub use self::unaligned::{write_unaligned_pairs, write_unaligned_records};
+
 static MAGIC_NUMBER: &[u8] = b"BAM\x01";
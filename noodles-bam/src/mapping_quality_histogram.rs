@@ -0,0 +1,104 @@
+use std::io;
+
+use super::Record;
+
+/// Counts the records for each mapping quality (MAPQ).
+///
+/// The returned histogram is indexed by the raw mapping quality, i.e., the count at index `n` is
+/// the number of records with a mapping quality of `n`. Index 255 counts the records with a
+/// missing mapping quality.
+///
+/// If `exclude_secondary_and_supplementary` is set, secondary and supplementary records are not
+/// counted.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io};
+/// use noodles_bam as bam;
+///
+/// let mut reader = File::open("sample.bam").map(bam::Reader::new)?;
+/// reader.read_header()?;
+/// reader.read_reference_sequences()?;
+///
+/// let histogram = bam::mapping_quality_histogram(reader.records(), true)?;
+///
+/// for (mapping_quality, count) in histogram.iter().enumerate() {
+///     println!("{}\t{}", mapping_quality, count);
+/// }
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn mapping_quality_histogram<I>(
+    records: I,
+    exclude_secondary_and_supplementary: bool,
+) -> io::Result<[u64; 256]>
+where
+    I: IntoIterator<Item = io::Result<Record>>,
+{
+    let mut histogram = [0; 256];
+
+    for result in records {
+        let record = result?;
+        let flags = record.flags();
+
+        if exclude_secondary_and_supplementary && (flags.is_secondary() || flags.is_supplementary())
+        {
+            continue;
+        }
+
+        let mapping_quality = u8::from(record.mapping_quality());
+        histogram[usize::from(mapping_quality)] += 1;
+    }
+
+    Ok(histogram)
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::{
+        self as sam,
+        header::ReferenceSequences,
+        record::{Flags, MappingQuality},
+    };
+
+    use super::*;
+
+    fn build_record(
+        mapping_quality: u8,
+        flags: Flags,
+    ) -> Result<Record, Box<dyn std::error::Error>> {
+        let sam_record = sam::Record::builder()
+            .set_flags(flags)
+            .set_mapping_quality(MappingQuality::from(mapping_quality))
+            .build()?;
+
+        Record::try_from_sam_record(&ReferenceSequences::default(), &sam_record)
+            .map_err(|e| e.into())
+    }
+
+    #[test]
+    fn test_mapping_quality_histogram() -> Result<(), Box<dyn std::error::Error>> {
+        let records = vec![
+            build_record(0, Flags::empty())?,
+            build_record(60, Flags::empty())?,
+            build_record(60, Flags::empty())?,
+            build_record(255, Flags::empty())?,
+            build_record(60, Flags::SECONDARY)?,
+            build_record(0, Flags::SUPPLEMENTARY)?,
+        ];
+
+        let histogram = mapping_quality_histogram(records.clone().into_iter().map(Ok), false)?;
+        assert_eq!(histogram[0], 2);
+        assert_eq!(histogram[60], 3);
+        assert_eq!(histogram[255], 1);
+        assert_eq!(histogram.iter().sum::<u64>(), 6);
+
+        let histogram = mapping_quality_histogram(records.into_iter().map(Ok), true)?;
+        assert_eq!(histogram[0], 1);
+        assert_eq!(histogram[60], 2);
+        assert_eq!(histogram[255], 1);
+        assert_eq!(histogram.iter().sum::<u64>(), 4);
+
+        Ok(())
+    }
+}
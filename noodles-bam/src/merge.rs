@@ -0,0 +1,289 @@
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    io::{self, Read, Write},
+};
+
+use noodles_sam::{
+    self as sam,
+    header::{ReadGroups, ReferenceSequences},
+};
+
+use super::{record::coordinate_sort_key, Reader, Record, Writer};
+
+/// Merges coordinate-sorted BAM inputs into a single coordinate-sorted BAM.
+///
+/// Each input is expected to be at the start of the stream, i.e., its header and reference
+/// sequences are read by this function. The given header, with the read groups of all inputs
+/// merged into it, and its reference sequences are written to the output, followed by the records
+/// of all inputs in coordinate order. Unmapped records without a reference sequence ID are written
+/// last.
+///
+/// All inputs must be compatible with the given header: their reference sequences must have the
+/// same names and lengths in the same order, and read groups with the same ID must be identical.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io};
+/// use noodles_bam as bam;
+/// use noodles_sam as sam;
+///
+/// let inputs = vec![
+///     File::open("sample1.bam").map(bam::Reader::new)?,
+///     File::open("sample2.bam").map(bam::Reader::new)?,
+/// ];
+///
+/// let header = sam::Header::default();
+/// let mut writer = File::create("merged.bam").map(bam::Writer::new)?;
+///
+/// bam::merge(inputs, &header, &mut writer)?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn merge<R, W>(
+    inputs: Vec<Reader<R>>,
+    header: &sam::Header,
+    writer: &mut Writer<W>,
+) -> io::Result<()>
+where
+    R: Read,
+    W: Write,
+{
+    let mut readers = Vec::with_capacity(inputs.len());
+    let mut merged_header = header.clone();
+
+    for (i, mut reader) in inputs.into_iter().enumerate() {
+        let input_header: sam::Header = reader
+            .read_header()?
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let reference_sequences = reader.read_reference_sequences()?;
+
+        if !has_same_reference_sequences(header.reference_sequences(), &reference_sequences) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("input {}: reference sequences do not match the header", i),
+            ));
+        }
+
+        merge_read_groups(merged_header.read_groups_mut(), input_header.read_groups()).map_err(
+            |id| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("input {}: conflicting read group: {}", i, id),
+                )
+            },
+        )?;
+
+        readers.push(reader.peekable());
+    }
+
+    writer.write_header(&merged_header)?;
+    writer.write_reference_sequences(merged_header.reference_sequences())?;
+
+    let mut heap = BinaryHeap::with_capacity(readers.len());
+
+    for (i, reader) in readers.iter_mut().enumerate() {
+        if let Some(record) = reader.peek()? {
+            heap.push(Reverse((coordinate_sort_key(record), i)));
+        }
+    }
+
+    let mut record = Record::default();
+
+    while let Some(Reverse((_, i))) = heap.pop() {
+        let reader = &mut readers[i];
+
+        reader.read_record(&mut record)?;
+        writer.write_record(&record)?;
+
+        if let Some(next_record) = reader.peek()? {
+            heap.push(Reverse((coordinate_sort_key(next_record), i)));
+        }
+    }
+
+    Ok(())
+}
+
+fn has_same_reference_sequences(a: &ReferenceSequences, b: &ReferenceSequences) -> bool {
+    a.len() == b.len()
+        && a.values()
+            .zip(b.values())
+            .all(|(a, b)| a.name() == b.name() && a.len() == b.len())
+}
+
+fn merge_read_groups(dst: &mut ReadGroups, src: &ReadGroups) -> Result<(), String> {
+    for (id, read_group) in src {
+        match dst.get(id) {
+            Some(other) if other != read_group => return Err(id.into()),
+            Some(_) => {}
+            None => {
+                dst.insert(id.into(), read_group.clone());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use noodles_sam::header::ReferenceSequence;
+
+    use super::*;
+
+    const HEADER: &str = "@HD\tVN:1.6\tSO:coordinate\n@SQ\tSN:sq0\tLN:100\n@SQ\tSN:sq1\tLN:100\n";
+
+    fn build_header() -> Result<sam::Header, Box<dyn std::error::Error>> {
+        HEADER
+            .parse()
+            .map_err(|e: sam::header::ParseError| e.into())
+    }
+
+    fn build_bam(
+        header: &sam::Header,
+        records: &[(Option<&str>, Option<i32>)],
+    ) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(header)?;
+        writer.write_reference_sequences(header.reference_sequences())?;
+
+        for &(reference_sequence_name, position) in records {
+            let mut builder = sam::Record::builder();
+
+            if let Some(name) = reference_sequence_name {
+                builder = builder.set_reference_sequence_name(name.parse()?);
+            }
+
+            if let Some(position) = position {
+                builder = builder.set_position(sam::record::Position::try_from(position)?);
+            }
+
+            let record = builder.build()?;
+            writer.write_sam_record(header.reference_sequences(), &record)?;
+        }
+
+        writer.try_finish()?;
+
+        Ok(writer.get_ref().clone())
+    }
+
+    #[test]
+    fn test_merge() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let a = build_bam(
+            &header,
+            &[
+                (Some("sq0"), Some(5)),
+                (Some("sq0"), Some(21)),
+                (Some("sq1"), Some(3)),
+                (None, None),
+            ],
+        )?;
+
+        let b = build_bam(
+            &header,
+            &[
+                (Some("sq0"), Some(8)),
+                (Some("sq1"), Some(1)),
+                (Some("sq1"), Some(13)),
+            ],
+        )?;
+
+        let inputs = vec![Reader::new(&a[..]), Reader::new(&b[..])];
+        let mut writer = Writer::new(Vec::new());
+        merge(inputs, &header, &mut writer)?;
+        writer.try_finish()?;
+
+        let mut reader = Reader::new(writer.get_ref().as_slice());
+        reader.read_header()?;
+        reader.read_reference_sequences()?;
+
+        let actual: Vec<_> = reader
+            .records()
+            .map(|result| result.map(|record| coordinate_sort_key(&record)))
+            .collect::<io::Result<_>>()?;
+
+        let expected = [
+            (0, 5),
+            (0, 8),
+            (0, 21),
+            (1, 1),
+            (1, 3),
+            (1, 13),
+            (i32::MAX, i32::MAX),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_read_groups() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let header_with_read_group =
+            |id: &str| -> Result<sam::Header, _> { format!("{}@RG\tID:{}\n", HEADER, id).parse() };
+
+        let a = build_bam(&header_with_read_group("rg0")?, &[])?;
+        let b = build_bam(&header_with_read_group("rg1")?, &[])?;
+
+        let inputs = vec![Reader::new(&a[..]), Reader::new(&b[..])];
+        let mut writer = Writer::new(Vec::new());
+        merge(inputs, &header, &mut writer)?;
+        writer.try_finish()?;
+
+        let mut reader = Reader::new(writer.get_ref().as_slice());
+        let actual: sam::Header = reader.read_header()?.parse()?;
+
+        let read_groups = actual.read_groups();
+        assert_eq!(read_groups.len(), 2);
+        assert!(read_groups.contains_key("rg0"));
+        assert!(read_groups.contains_key("rg1"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_incompatible_inputs() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let other_header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq1", 100)?)
+            .add_reference_sequence(ReferenceSequence::new("sq0", 100)?)
+            .build();
+
+        let a = build_bam(&header, &[])?;
+        let b = build_bam(&other_header, &[])?;
+
+        let inputs = vec![Reader::new(&a[..]), Reader::new(&b[..])];
+        let mut writer = Writer::new(Vec::new());
+
+        assert!(matches!(
+            merge(inputs, &header, &mut writer),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let header_with_read_group = |description: &str| -> Result<sam::Header, _> {
+            format!("{}@RG\tID:rg0\tDS:{}\n", HEADER, description).parse()
+        };
+
+        let a = build_bam(&header_with_read_group("a")?, &[])?;
+        let b = build_bam(&header_with_read_group("b")?, &[])?;
+
+        let inputs = vec![Reader::new(&a[..]), Reader::new(&b[..])];
+        let mut writer = Writer::new(Vec::new());
+
+        assert!(matches!(
+            merge(inputs, &header, &mut writer),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -1,11 +1,19 @@
 //! BAM reader and iterators.
 
+mod binned_records;
+mod peekable;
 mod query;
+mod read_group_records;
 pub mod record;
 mod records;
+mod sam_records;
 mod unmapped_records;
 
-pub use self::{query::Query, records::Records, unmapped_records::UnmappedRecords};
+pub use self::{
+    binned_records::BinnedRecords, peekable::Peekable, query::Query,
+    read_group_records::ReadGroupRecords, records::Records, sam_records::SamRecords,
+    unmapped_records::UnmappedRecords,
+};
 
 use std::{
     convert::TryFrom,
@@ -13,11 +21,14 @@ use std::{
     io::{self, Read, Seek},
 };
 
-use byteorder::{LittleEndian, ReadBytesExt};
+use byteorder::{ByteOrder, LittleEndian, ReadBytesExt};
 use noodles_bgzf as bgzf;
 use noodles_core::{region::Interval, Region};
 use noodles_csi::{BinningIndex, BinningIndexReferenceSequence};
-use noodles_sam::header::{ReferenceSequence, ReferenceSequences};
+use noodles_sam::{
+    self as sam,
+    header::{ReferenceSequence, ReferenceSequences},
+};
 
 use super::{bai, Record, MAGIC_NUMBER};
 
@@ -85,8 +96,12 @@ where
     ///
     /// The position of the stream is expected to be at the start.
     ///
-    /// This returns the raw SAM header as a [`String`]. It can subsequently be parsed as a
-    /// [`noodles_sam::Header`].
+    /// This returns the raw SAM header as a [`String`]. The text is returned as written, excluding
+    /// any NUL padding, and is not parsed, e.g., it can be edited and rewritten as is. It can
+    /// subsequently be parsed as a [`noodles_sam::Header`].
+    ///
+    /// Reading the header does not read the binary reference sequences that follow it. See
+    /// [`Self::read_reference_sequences`].
     ///
     /// # Examples
     ///
@@ -139,8 +154,15 @@ where
     /// [`Self::query`]), but using this method directly allows the reuse of a single [`Record`]
     /// buffer.
     ///
+    /// A [`Record`] is the raw record block, and its fields are decoded lazily by its accessors.
+    /// Reading into the same record resizes its buffer to the new block size without releasing its
+    /// capacity, so a scan over many records only allocates when a record is larger than any
+    /// previously read one.
+    ///
     /// If successful, the record block size is returned. If a block size of 0 is returned, the
-    /// stream reached EOF.
+    /// stream reached EOF, e.g., immediately after the reference sequences of a BAM file with no
+    /// records. A stream that ends within a record returns an [`io::ErrorKind::UnexpectedEof`]
+    /// error.
     ///
     /// # Examples
     ///
@@ -186,6 +208,130 @@ where
         Records::new(self)
     }
 
+    /// Returns an iterator over records and their calculated bins starting from the current stream
+    /// position.
+    ///
+    /// The bin of each record is recalculated from its position and alignment end, using the same
+    /// binning scheme as the index (§ 5.3 "C source code for computing bin number and overlapping
+    /// bins"). This is useful as a diagnostic to cross-check bin assignments with an index.
+    /// Unmapped records are assigned bin 4680.
+    ///
+    /// The stream is expected to be directly after the reference sequences or at the start of
+    /// another record.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bam as bam;
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::Reader::new)?;
+    /// reader.read_header()?;
+    /// reader.read_reference_sequences()?;
+    ///
+    /// for result in reader.binned_records() {
+    ///     let (record, bin) = result?;
+    ///     println!("{:?}\t{}", record.read_name(), bin);
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn binned_records(&mut self) -> BinnedRecords<'_, R> {
+        BinnedRecords::new(self)
+    }
+
+    /// Returns an iterator over records converted to SAM records.
+    ///
+    /// Reference sequence IDs are resolved to names using the reference sequences of the given
+    /// header.
+    ///
+    /// The stream is expected to be directly after the reference sequences or at the start of
+    /// another record.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bam as bam;
+    /// use noodles_sam as sam;
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::Reader::new)?;
+    /// let header: sam::Header = reader.read_header()?.parse()?;
+    /// reader.read_reference_sequences()?;
+    ///
+    /// for result in reader.sam_records(&header) {
+    ///     let record = result?;
+    ///     println!("{}", record);
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn sam_records<'a>(&'a mut self, header: &'a sam::Header) -> SamRecords<'a, R> {
+        SamRecords::new(self, header)
+    }
+
+    /// Returns an iterator over records in the given read group.
+    ///
+    /// A record is in a read group when its read group (`RG`) data field value is equal to the
+    /// given read group ID. Records in other read groups or without a read group are skipped.
+    ///
+    /// This returns an error if the read group is not declared in the header.
+    ///
+    /// The stream is expected to be directly after the reference sequences or at the start of
+    /// another record.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bam as bam;
+    /// use noodles_sam as sam;
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::Reader::new)?;
+    /// let header: sam::Header = reader.read_header()?.parse()?;
+    /// reader.read_reference_sequences()?;
+    ///
+    /// for result in reader.filter_read_group(&header, "rg0")? {
+    ///     let record = result?;
+    ///     println!("{:?}", record);
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn filter_read_group(
+        &mut self,
+        header: &sam::Header,
+        id: &str,
+    ) -> io::Result<ReadGroupRecords<'_, R>> {
+        if !header.read_groups().contains_key(id) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("read group '{}' is not declared in the header", id),
+            ));
+        }
+
+        Ok(ReadGroupRecords::new(self, id))
+    }
+
+    /// Creates a reader that can peek at the next record.
+    ///
+    /// This is typically called after reading the header and reference sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bam as bam;
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::Reader::new)?;
+    /// reader.read_header()?;
+    /// reader.read_reference_sequences()?;
+    ///
+    /// let mut reader = reader.peekable();
+    /// let record = reader.peek()?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn peekable(self) -> Peekable<R> {
+        Peekable::new(self)
+    }
+
     /// Returns the current virtual position of the underlying BGZF reader.
     ///
     /// # Examples
@@ -280,6 +426,11 @@ where
 
     /// Returns an iterator of unmapped records after querying for the unmapped region.
     ///
+    /// This seeks to the start of the last linear bin in the index, i.e., the closest indexed
+    /// position to the unplaced, unmapped records at the end of a coordinate-sorted file, rather
+    /// than scanning the whole file. Only unplaced, unmapped records, which have no reference
+    /// sequence ID, are returned.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -336,10 +487,13 @@ where
     reader.read_exact(&mut text)?;
 
     // § 4.2 The BAM format (2021-06-03): "Plain header text in SAM; not necessarily
-    // NUL-terminated".
-    bytes_with_nul_to_string(&text).or_else(|_| {
-        String::from_utf8(text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-    })
+    // NUL-terminated". The text may also be padded with NULs up to `l_text`, so only the bytes
+    // before the first NUL are used.
+    if let Some(i) = text.iter().position(|&b| b == 0) {
+        text.truncate(i);
+    }
+
+    String::from_utf8(text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
 }
 
 fn read_reference_sequences<R>(reader: &mut R) -> io::Result<ReferenceSequences>
@@ -383,10 +537,11 @@ fn read_record<R>(reader: &mut R, record: &mut Record) -> io::Result<usize>
 where
     R: Read,
 {
-    let block_size = match reader.read_u32::<LittleEndian>() {
-        Ok(bs) => usize::try_from(bs).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
-        Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(0),
-        Err(e) => return Err(e),
+    let block_size = match read_block_size(reader)? {
+        Some(bs) => {
+            usize::try_from(bs).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+        }
+        None => return Ok(0),
     };
 
     record.resize(block_size);
@@ -395,6 +550,36 @@ where
     Ok(block_size)
 }
 
+// Reads the block size of the next record.
+//
+// This returns `None` if the stream is at EOF. Unlike `read_u32`, a partially read block size is
+// an unexpected EOF rather than a clean end of the stream.
+fn read_block_size<R>(reader: &mut R) -> io::Result<Option<u32>>
+where
+    R: Read,
+{
+    let mut buf = [0; 4];
+    let mut len = 0;
+
+    while len < buf.len() {
+        match reader.read(&mut buf[len..]) {
+            Ok(0) => break,
+            Ok(n) => len += n,
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    match len {
+        0 => Ok(None),
+        4 => Ok(Some(LittleEndian::read_u32(&buf))),
+        _ => Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "unexpected EOF in record block size",
+        )),
+    }
+}
+
 pub(crate) fn bytes_with_nul_to_string(buf: &[u8]) -> io::Result<String> {
     CStr::from_bytes_with_nul(buf)
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
@@ -473,6 +658,37 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_header_with_padding() -> Result<(), Box<dyn std::error::Error>> {
+        let expected = "@HD\tVN:1.6\n";
+
+        let mut data = 16u32.to_le_bytes().to_vec(); // l_text = 16
+        data.extend(expected.as_bytes()); // text
+        data.extend([0x00; 5].iter()); // padding
+        data.extend(
+            [
+                0x01, 0x00, 0x00, 0x00, // n_ref = 1
+                0x04, 0x00, 0x00, 0x00, // ref[0].l_name = 4
+                0x73, 0x71, 0x30, 0x00, // ref[0].name = "sq0\x00"
+                0x08, 0x00, 0x00, 0x00, // ref[0].l_ref = 8
+            ]
+            .iter(),
+        );
+
+        let mut reader = &data[..];
+
+        let actual = read_header(&mut reader)?;
+        assert_eq!(actual, expected);
+
+        let reference_sequences = read_reference_sequences(&mut reader)?;
+        assert_eq!(reference_sequences.len(), 1);
+        assert_eq!(reference_sequences.get("sq0").map(|rs| rs.len()), Some(8));
+
+        assert!(reader.is_empty());
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_reference_sequences() -> Result<(), Box<dyn std::error::Error>> {
         let data = [
@@ -495,6 +711,33 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_header_and_reference_sequences() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::Writer;
+
+        let header: sam::Header =
+            "@HD\tVN:1.6\tSO:coordinate\n@SQ\tSN:sq0\tLN:8\n@SQ\tSN:sq1\tLN:13\n@CO\tnoodles\n"
+                .parse()?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_reference_sequences(header.reference_sequences())?;
+        writer.try_finish()?;
+
+        let mut reader = Reader::new(&writer.get_ref()[..]);
+
+        let text = reader.read_header()?;
+        assert_eq!(text, header.to_string());
+
+        let reference_sequences = reader.read_reference_sequences()?;
+        assert_eq!(&reference_sequences, header.reference_sequences());
+
+        let parsed_header: sam::Header = text.parse()?;
+        assert_eq!(parsed_header.reference_sequences(), &reference_sequences);
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_record() -> io::Result<()> {
         let data = [
@@ -511,4 +754,80 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_record_with_truncated_record() {
+        let data = [0x04, 0x00]; // block_size[..2]
+        let mut reader = &data[..];
+        let mut record = Record::default();
+        assert!(matches!(
+            read_record(&mut reader, &mut record),
+            Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        let data = [
+            0x04, 0x00, 0x00, 0x00, // block_size = 4
+            0x6e, 0x64, // ...
+        ];
+        let mut reader = &data[..];
+        let mut record = Record::default();
+        assert!(matches!(
+            read_record(&mut reader, &mut record),
+            Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+    }
+
+    #[test]
+    fn test_read_record_with_no_records() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::Writer;
+
+        let header: sam::Header = "@HD\tVN:1.6\tSO:coordinate\n@SQ\tSN:sq0\tLN:8\n".parse()?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_reference_sequences(header.reference_sequences())?;
+        writer.try_finish()?;
+
+        let mut reader = Reader::new(&writer.get_ref()[..]);
+        reader.read_header()?;
+        reader.read_reference_sequences()?;
+
+        let mut record = Record::default();
+        assert_eq!(reader.read_record(&mut record)?, 0);
+
+        let mut reader = Reader::new(&writer.get_ref()[..]);
+        reader.read_header()?;
+        reader.read_reference_sequences()?;
+        assert_eq!(reader.records().count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_reused_record() -> io::Result<()> {
+        let data = [
+            0x04, 0x00, 0x00, 0x00, // block_size = 4
+            0x6e, 0x64, 0x6c, 0x73, // ...
+            0x02, 0x00, 0x00, 0x00, // block_size = 2
+            0x62, 0x61, // ...
+            0x03, 0x00, 0x00, 0x00, // block_size = 3
+            0x6d, 0x61, 0x6d, // ...
+        ];
+
+        let mut reader = &data[..];
+        let mut record = Record::default();
+
+        assert_eq!(read_record(&mut reader, &mut record)?, 4);
+        assert_eq!(&record[..], &data[4..8]);
+
+        assert_eq!(read_record(&mut reader, &mut record)?, 2);
+        assert_eq!(&record[..], &data[12..14]);
+
+        assert_eq!(read_record(&mut reader, &mut record)?, 3);
+        assert_eq!(&record[..], &data[18..21]);
+
+        assert_eq!(read_record(&mut reader, &mut record)?, 0);
+
+        Ok(())
+    }
 }
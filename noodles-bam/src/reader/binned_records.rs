@@ -0,0 +1,112 @@
+use std::io::{self, Read};
+
+use crate::{bai::UNMAPPED_BIN, writer::record, Record};
+
+use super::Reader;
+
+/// An iterator over records of a BAM reader and their calculated bins.
+///
+/// This is created by calling [`Reader::binned_records`].
+pub struct BinnedRecords<'a, R>
+where
+    R: Read,
+{
+    reader: &'a mut Reader<R>,
+    record: Record,
+}
+
+impl<'a, R> BinnedRecords<'a, R>
+where
+    R: Read,
+{
+    pub(crate) fn new(reader: &'a mut Reader<R>) -> Self {
+        Self {
+            reader,
+            record: Record::default(),
+        }
+    }
+}
+
+impl<'a, R> Iterator for BinnedRecords<'a, R>
+where
+    R: Read,
+{
+    type Item = io::Result<(Record, u16)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.reader.read_record(&mut self.record) {
+            Ok(0) => None,
+            Ok(_) => Some(calculate_bin(&self.record).map(|bin| (self.record.clone(), bin))),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+fn calculate_bin(record: &Record) -> io::Result<u16> {
+    match record.position() {
+        Some(position) => {
+            let start = position.to_zero_based();
+            let reference_len = record.cigar().reference_len()? as i32;
+            record::calculate_bin(start, reference_len)
+        }
+        None => Ok(UNMAPPED_BIN),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use noodles_sam::{self as sam, header::ReferenceSequence};
+
+    use crate::Writer;
+
+    use super::*;
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0", 13)?)
+            .build();
+
+        let records = [
+            sam::Record::builder()
+                .set_reference_sequence_name("sq0".parse()?)
+                .set_position(sam::record::Position::try_from(8)?)
+                .set_cigar("5M".parse()?)
+                .set_sequence("ACGTA".parse()?)
+                .build()?,
+            sam::Record::default(),
+        ];
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_reference_sequences(header.reference_sequences())?;
+
+        for record in &records {
+            writer.write_sam_record(header.reference_sequences(), record)?;
+        }
+
+        writer.try_finish()?;
+
+        let mut reader = Reader::new(writer.get_ref().as_slice());
+        reader.read_header()?;
+        reader.read_reference_sequences()?;
+
+        let bins: Vec<_> = reader
+            .binned_records()
+            .map(|result| result.map(|(record, bin)| (record.position(), bin)))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(
+            bins,
+            [
+                // [8, 13) => [7, 12]
+                (Some(sam::record::Position::try_from(8)?), 4681),
+                (None, UNMAPPED_BIN),
+            ]
+        );
+
+        Ok(())
+    }
+}
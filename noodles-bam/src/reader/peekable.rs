@@ -0,0 +1,164 @@
+use std::io::{self, Read};
+
+use crate::Record;
+
+use super::Reader;
+
+/// A BAM reader that can peek at the next record.
+///
+/// This is created by calling [`Reader::peekable`].
+pub struct Peekable<R>
+where
+    R: Read,
+{
+    inner: Reader<R>,
+    peeked: Option<Record>,
+}
+
+impl<R> Peekable<R>
+where
+    R: Read,
+{
+    pub(crate) fn new(inner: Reader<R>) -> Self {
+        Self {
+            inner,
+            peeked: None,
+        }
+    }
+
+    /// Returns a reference to the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let reader = bam::Reader::new(&[][..]).peekable();
+    /// let _ = reader.get_ref();
+    /// ```
+    pub fn get_ref(&self) -> &Reader<R> {
+        &self.inner
+    }
+
+    /// Returns a reference to the next record without consuming it.
+    ///
+    /// The next record is read and buffered if it has not already been peeked. This returns `None`
+    /// at the end of the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bam as bam;
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::Reader::new)?;
+    /// reader.read_header()?;
+    /// reader.read_reference_sequences()?;
+    ///
+    /// let mut reader = reader.peekable();
+    ///
+    /// if let Some(record) = reader.peek()? {
+    ///     println!("{:?}", record.reference_sequence_id());
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn peek(&mut self) -> io::Result<Option<&Record>> {
+        if self.peeked.is_none() {
+            let mut record = Record::default();
+
+            if self.inner.read_record(&mut record)? != 0 {
+                self.peeked = Some(record);
+            }
+        }
+
+        Ok(self.peeked.as_ref())
+    }
+
+    /// Reads a single record.
+    ///
+    /// If a record was peeked, it is consumed and returned. Otherwise, this is the same as
+    /// [`Reader::read_record`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bam as bam;
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::Reader::new)?;
+    /// reader.read_header()?;
+    /// reader.read_reference_sequences()?;
+    ///
+    /// let mut reader = reader.peekable();
+    ///
+    /// let mut record = bam::Record::default();
+    /// reader.read_record(&mut record)?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
+        match self.peeked.take() {
+            Some(peeked_record) => {
+                *record = peeked_record;
+                Ok(record.block_size() as usize)
+            }
+            None => self.inner.read_record(record),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use noodles_sam::{self as sam, header::ReferenceSequence};
+
+    use crate::Writer;
+
+    use super::*;
+
+    #[test]
+    fn test_peek() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0", 13)?)
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_reference_sequences(header.reference_sequences())?;
+
+        for position in &[2, 5] {
+            let record = sam::Record::builder()
+                .set_reference_sequence_name("sq0".parse()?)
+                .set_position(sam::record::Position::try_from(*position)?)
+                .build()?;
+
+            writer.write_sam_record(header.reference_sequences(), &record)?;
+        }
+
+        writer.try_finish()?;
+
+        let mut reader = Reader::new(writer.get_ref().as_slice());
+        reader.read_header()?;
+        reader.read_reference_sequences()?;
+
+        let mut reader = reader.peekable();
+
+        let first = reader.peek()?.cloned();
+        assert_eq!(
+            first.as_ref().and_then(|record| record.position()),
+            Some(sam::record::Position::try_from(2)?)
+        );
+        assert_eq!(reader.peek()?.cloned(), first);
+
+        let mut record = Record::default();
+        reader.read_record(&mut record)?;
+        assert_eq!(Some(&record), first.as_ref());
+
+        reader.read_record(&mut record)?;
+        assert_eq!(record.position(), Some(sam::record::Position::try_from(5)?));
+
+        assert!(reader.peek()?.is_none());
+        assert_eq!(reader.read_record(&mut record)?, 0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+use std::io::{self, Read};
+
+use noodles_sam::record::data::field::Tag;
+
+use crate::Record;
+
+use super::Reader;
+
+/// An iterator over records of a BAM reader that are in a given read group.
+///
+/// This is created by calling [`Reader::filter_read_group`].
+pub struct ReadGroupRecords<'a, R>
+where
+    R: Read,
+{
+    reader: &'a mut Reader<R>,
+    id: String,
+    record: Record,
+}
+
+impl<'a, R> ReadGroupRecords<'a, R>
+where
+    R: Read,
+{
+    pub(crate) fn new(reader: &'a mut Reader<R>, id: &str) -> Self {
+        Self {
+            reader,
+            id: id.into(),
+            record: Record::default(),
+        }
+    }
+}
+
+impl<'a, R> Iterator for ReadGroupRecords<'a, R>
+where
+    R: Read,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.reader.read_record(&mut self.record) {
+                Ok(0) => return None,
+                Ok(_) => match is_in_read_group(&self.record, &self.id) {
+                    Ok(true) => return Some(Ok(self.record.clone())),
+                    Ok(false) => {}
+                    Err(e) => return Some(Err(e)),
+                },
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+fn is_in_read_group(record: &Record, id: &str) -> io::Result<bool> {
+    for result in record.data().fields() {
+        let field = result?;
+
+        if field.tag() == &Tag::ReadGroup {
+            return Ok(field.value().as_str() == Some(id));
+        }
+    }
+
+    Ok(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam as sam;
+
+    use crate::Writer;
+
+    use super::*;
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let header: sam::Header = "@HD\tVN:1.6\n@RG\tID:rg0\n@RG\tID:rg1\n".parse()?;
+
+        let records = [
+            sam::Record::builder()
+                .set_read_name("r0".parse()?)
+                .set_data("RG:Z:rg0".parse()?)
+                .build()?,
+            sam::Record::builder()
+                .set_read_name("r1".parse()?)
+                .set_data("NH:i:1\tRG:Z:rg1".parse()?)
+                .build()?,
+            sam::Record::builder()
+                .set_read_name("r2".parse()?)
+                .set_data("RG:Z:rg0\tNH:i:1".parse()?)
+                .build()?,
+            sam::Record::builder()
+                .set_read_name("r3".parse()?)
+                .build()?,
+        ];
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_reference_sequences(header.reference_sequences())?;
+
+        for record in &records {
+            writer.write_sam_record(header.reference_sequences(), record)?;
+        }
+
+        writer.try_finish()?;
+
+        let mut reader = Reader::new(writer.get_ref().as_slice());
+        reader.read_header()?;
+        reader.read_reference_sequences()?;
+
+        let mut read_names = Vec::new();
+
+        for result in reader.filter_read_group(&header, "rg0")? {
+            let record = result?;
+            read_names.push(record.read_name()?.to_str()?.to_string());
+        }
+
+        assert_eq!(read_names, ["r0", "r2"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_undeclared_read_group() -> Result<(), Box<dyn std::error::Error>> {
+        let header: sam::Header = "@HD\tVN:1.6\n@RG\tID:rg0\n".parse()?;
+
+        let mut reader = Reader::new(&[][..]);
+
+        assert!(matches!(
+            reader.filter_read_group(&header, "rg1"),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
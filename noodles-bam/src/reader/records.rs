@@ -7,6 +7,9 @@ use super::Reader;
 /// An iterator over records of a BAM reader.
 ///
 /// This is created by calling [`Reader::records`].
+///
+/// Each item is a copy of the record buffer the iterator reads into. To avoid the per-record
+/// allocation, read into a single record using [`Reader::read_record`].
 pub struct Records<'a, R>
 where
     R: Read,
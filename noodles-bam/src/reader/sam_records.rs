@@ -0,0 +1,109 @@
+use std::io::{self, Read};
+
+use noodles_sam as sam;
+
+use crate::Record;
+
+use super::Reader;
+
+/// An iterator over records of a BAM reader converted to SAM records.
+///
+/// This is created by calling [`Reader::sam_records`].
+pub struct SamRecords<'a, R>
+where
+    R: Read,
+{
+    reader: &'a mut Reader<R>,
+    header: &'a sam::Header,
+    record: Record,
+}
+
+impl<'a, R> SamRecords<'a, R>
+where
+    R: Read,
+{
+    pub(crate) fn new(reader: &'a mut Reader<R>, header: &'a sam::Header) -> Self {
+        Self {
+            reader,
+            header,
+            record: Record::default(),
+        }
+    }
+}
+
+impl<'a, R> Iterator for SamRecords<'a, R>
+where
+    R: Read,
+{
+    type Item = io::Result<sam::Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.reader.read_record(&mut self.record) {
+            Ok(0) => None,
+            Ok(_) => Some(
+                self.record
+                    .try_into_sam_record(self.header.reference_sequences()),
+            ),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use sam::{
+        header::ReferenceSequence,
+        record::{Flags, MappingQuality, Position},
+    };
+
+    use crate::Writer;
+
+    use super::*;
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0", 8)?)
+            .add_reference_sequence(ReferenceSequence::new("sq1", 13)?)
+            .build();
+
+        let records = vec![
+            sam::Record::builder()
+                .set_read_name("r0".parse()?)
+                .set_flags(Flags::PAIRED | Flags::READ_1)
+                .set_reference_sequence_name("sq1".parse()?)
+                .set_position(Position::try_from(2)?)
+                .set_mapping_quality(MappingQuality::from(12))
+                .set_cigar("1S3M".parse()?)
+                .set_mate_reference_sequence_name("sq1".parse()?)
+                .set_mate_position(Position::try_from(8)?)
+                .set_template_length(10)
+                .set_sequence("ACGT".parse()?)
+                .set_quality_scores("NDLS".parse()?)
+                .set_data("NM:i:1\tRG:Z:rg0".parse()?)
+                .build()?,
+            sam::Record::default(),
+        ];
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_reference_sequences(header.reference_sequences())?;
+
+        for record in &records {
+            writer.write_sam_record(header.reference_sequences(), record)?;
+        }
+
+        writer.try_finish()?;
+
+        let mut reader = Reader::new(&writer.get_ref()[..]);
+        reader.read_header()?;
+        reader.read_reference_sequences()?;
+
+        let actual: Vec<_> = reader.sam_records(&header).collect::<Result<_, _>>()?;
+        assert_eq!(actual, records);
+
+        Ok(())
+    }
+}
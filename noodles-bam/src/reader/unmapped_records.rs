@@ -4,9 +4,12 @@ use crate::Record;
 
 use super::Reader;
 
-/// An iterator over unmapped records of a BAM reader.
+/// An iterator over unplaced, unmapped records of a BAM reader.
 ///
 /// This is created by calling [`Reader::query_unmapped`].
+///
+/// Unplaced, unmapped records have no reference sequence ID. Records that are placed, i.e., have
+/// a reference sequence ID and position, are skipped, even if they are flagged as unmapped.
 pub struct UnmappedRecords<'a, R>
 where
     R: Read,
@@ -38,7 +41,7 @@ where
             match self.reader.read_record(&mut self.record) {
                 Ok(0) => return None,
                 Ok(_) => {
-                    if self.record.flags().is_unmapped() {
+                    if self.record.reference_sequence_id().is_none() {
                         return Some(Ok(self.record.clone()));
                     }
                 }
@@ -47,3 +50,90 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{convert::TryFrom, io::Cursor};
+
+    use noodles_csi::index::reference_sequence::bin::Chunk;
+    use noodles_sam::{self as sam, record::Flags};
+
+    use crate::{bai, Writer};
+
+    use super::*;
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::record::Position;
+
+        let header: sam::Header = "@HD\tVN:1.6\tSO:coordinate\n@SQ\tSN:sq0\tLN:100000\n".parse()?;
+
+        let records = [
+            sam::Record::builder()
+                .set_read_name("r0".parse()?)
+                .set_flags(Flags::empty())
+                .set_reference_sequence_name("sq0".parse()?)
+                .set_position(Position::try_from(1)?)
+                .set_cigar("4M".parse()?)
+                .build()?,
+            sam::Record::builder()
+                .set_read_name("r1".parse()?)
+                .set_flags(Flags::empty())
+                .set_reference_sequence_name("sq0".parse()?)
+                .set_position(Position::try_from(50000)?)
+                .set_cigar("4M".parse()?)
+                .build()?,
+            // placed, unmapped
+            sam::Record::builder()
+                .set_read_name("r2".parse()?)
+                .set_flags(Flags::UNMAPPED)
+                .set_reference_sequence_name("sq0".parse()?)
+                .set_position(Position::try_from(50000)?)
+                .build()?,
+            sam::Record::builder()
+                .set_read_name("r3".parse()?)
+                .build()?,
+            sam::Record::builder()
+                .set_read_name("r4".parse()?)
+                .build()?,
+        ];
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_reference_sequences(header.reference_sequences())?;
+
+        for record in &records {
+            writer.write_sam_record(header.reference_sequences(), record)?;
+        }
+
+        writer.try_finish()?;
+        let data = writer.get_ref().clone();
+
+        let mut reader = Reader::new(Cursor::new(&data));
+        reader.read_header()?;
+        reader.read_reference_sequences()?;
+
+        let mut builder = bai::Index::builder();
+        let mut record = Record::default();
+        let mut start = reader.virtual_position();
+
+        while reader.read_record(&mut record)? != 0 {
+            let end = reader.virtual_position();
+            builder.add_record(&record, Chunk::new(start, end))?;
+            start = end;
+        }
+
+        let index = builder.build(header.reference_sequences().len());
+
+        let mut read_names = Vec::new();
+
+        for result in reader.query_unmapped(&index)? {
+            let record = result?;
+            read_names.push(record.read_name()?.to_str()?.to_string());
+        }
+
+        assert_eq!(read_names, ["r3", "r4"]);
+
+        Ok(())
+    }
+}
@@ -5,6 +5,8 @@ mod convert;
 pub mod data;
 pub mod quality_scores;
 pub mod reference_sequence_id;
+#[cfg(feature = "fasta")]
+mod reference_span;
 pub mod sequence;
 
 pub use self::{
@@ -12,6 +14,9 @@ pub use self::{
     reference_sequence_id::ReferenceSequenceId, sequence::Sequence,
 };
 
+#[cfg(feature = "fasta")]
+pub use self::reference_span::reference_span;
+
 use std::{
     convert::TryFrom,
     ffi::{self, CStr},
@@ -19,7 +24,12 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
+#[cfg(feature = "fasta")]
+use std::io;
+
 use byteorder::{ByteOrder, LittleEndian};
+#[cfg(feature = "fasta")]
+use noodles_fasta as fasta;
 use noodles_sam as sam;
 
 pub(crate) const UNMAPPED_POSITION: i32 = -1;
@@ -98,11 +108,11 @@ impl Record {
         if pos == UNMAPPED_POSITION {
             None
         } else {
-            sam::record::Position::try_from(pos + 1).ok()
+            sam::record::Position::from_zero_based(pos).ok()
         }
     }
 
-    fn l_read_name(&self) -> u8 {
+    pub(crate) fn l_read_name(&self) -> u8 {
         const OFFSET: usize = 8;
         self.0[OFFSET]
     }
@@ -124,6 +134,9 @@ impl Record {
 
     /// Returns the index bin that includes this record.
     ///
+    /// Bins are calculated using 0-based, half-open intervals, i.e., this is the bin of
+    /// `[start - 1, end)` for the 1-based, inclusive alignment interval `[start, end]`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -136,7 +149,7 @@ impl Record {
         LittleEndian::read_u16(&self.0[OFFSET..])
     }
 
-    fn n_cigar_op(&self) -> u16 {
+    pub(crate) fn n_cigar_op(&self) -> u16 {
         const OFFSET: usize = 12;
         LittleEndian::read_u16(&self.0[OFFSET..])
     }
@@ -157,7 +170,7 @@ impl Record {
         sam::record::Flags::from(value)
     }
 
-    fn l_seq(&self) -> u32 {
+    pub(crate) fn l_seq(&self) -> u32 {
         const OFFSET: usize = 16;
         LittleEndian::read_u32(&self.0[OFFSET..])
     }
@@ -206,7 +219,7 @@ impl Record {
         if pos == UNMAPPED_POSITION {
             None
         } else {
-            sam::record::Position::try_from(pos + 1).ok()
+            sam::record::Position::from_zero_based(pos).ok()
         }
     }
 
@@ -272,14 +285,60 @@ impl Record {
     /// assert!(record.sequence().is_empty());
     /// ```
     pub fn sequence(&self) -> Sequence<'_> {
+        let bytes = self.sequence_nibbles();
+        let base_count = self.l_seq() as usize;
+        Sequence::new(bytes, base_count)
+    }
+
+    /// Returns the raw, packed bases in the sequence of this record.
+    ///
+    /// Each byte holds two bases, 4 bits per base, with the first base in the high nibble. A base
+    /// is encoded as its index in `=ACMGRSVTWYHKDBN`, e.g., `A` = 1, `C` = 2, `G` = 4, `T` = 8,
+    /// and `N` = 15. If the sequence length is odd, the low nibble of the last byte is 0.
+    ///
+    /// This avoids decoding each base and is useful for processing the compact representation
+    /// directly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let record = bam::Record::default();
+    /// assert!(record.sequence_nibbles().is_empty());
+    /// ```
+    pub fn sequence_nibbles(&self) -> &[u8] {
         let offset = 32
             + (self.l_read_name() as usize)
             + mem::size_of::<u32>() * (self.n_cigar_op() as usize);
         let len = ((self.l_seq() + 1) / 2) as usize;
 
-        let bytes = &self.0[offset..offset + len];
-        let base_count = self.l_seq() as usize;
-        Sequence::new(bytes, base_count)
+        &self.0[offset..offset + len]
+    }
+
+    /// Returns the raw, 4-bit encoded base at the given index in the sequence.
+    ///
+    /// See [`Self::sequence_nibbles`] for the encoding. If the index is out of bounds, this
+    /// returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let record = bam::Record::default();
+    /// assert!(record.base_at(0).is_none());
+    /// ```
+    pub fn base_at(&self, i: usize) -> Option<u8> {
+        if i >= self.l_seq() as usize {
+            return None;
+        }
+
+        let b = self.sequence_nibbles()[i / 2];
+
+        if i % 2 == 1 {
+            Some(b & 0x0f)
+        } else {
+            Some(b >> 4)
+        }
     }
 
     /// Returns the quality score for each base in the sequence.
@@ -326,6 +385,148 @@ impl Record {
         let bytes = &self.0[offset..len];
         Data::new(bytes)
     }
+
+    /// Calculates the alignment identity of the record against its reference sequence.
+    ///
+    /// This is the BLAST-style identity, i.e., the number of matching bases divided by the
+    /// alignment length:
+    ///
+    /// ```text
+    /// matches / (matches + mismatches + inserted bases + deleted bases)
+    /// ```
+    ///
+    /// Each inserted (`I`) and deleted (`D`) base counts toward the alignment length, rather than
+    /// each indel counting once (gap-compressed identity). Clips, skips (`N`), and padding are
+    /// excluded. The bases of alignment matches (`M`), sequence matches (`=`), and sequence
+    /// mismatches (`X`) are compared to the given reference sequence, ignoring case; the edit
+    /// distance (`NM`) is not used. An `N` in either sequence is a mismatch.
+    ///
+    /// The reference sequence is expected to be the one the record is mapped to. An error is
+    /// returned if the record is unmapped, the alignment extends past the reference sequence, or
+    /// the alignment has no matches, mismatches, or indels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// use noodles_bam as bam;
+    /// use noodles_fasta as fasta;
+    /// use noodles_sam::{self as sam, header::{ReferenceSequence, ReferenceSequences}, record::Position};
+    ///
+    /// let reference_sequences: ReferenceSequences = vec![(
+    ///     String::from("sq0"),
+    ///     ReferenceSequence::new(String::from("sq0"), 8)?,
+    /// )]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let sam_record = sam::Record::builder()
+    ///     .set_reference_sequence_name("sq0".parse()?)
+    ///     .set_position(Position::try_from(1)?)
+    ///     .set_cigar("4M".parse()?)
+    ///     .set_sequence("ACGA".parse()?)
+    ///     .build()?;
+    /// let record = bam::Record::try_from_sam_record(&reference_sequences, &sam_record)?;
+    ///
+    /// let reference_sequence = fasta::Record::new(
+    ///     fasta::record::Definition::new(String::from("sq0"), None),
+    ///     b"ACGTACGT".to_vec(),
+    /// );
+    ///
+    /// assert_eq!(record.identity(&reference_sequence)?, 0.75);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "fasta")]
+    pub fn identity(&self, reference_sequence: &fasta::Record) -> io::Result<f64> {
+        use sam::record::cigar::op::Kind;
+
+        let mut reference_position = self
+            .position()
+            .map(|position| (i32::from(position) - 1) as usize)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing position"))?;
+
+        let reference_bases = reference_sequence.sequence();
+
+        let mut read_position = 0;
+        let mut matches = 0u64;
+        let mut alignment_len = 0u64;
+
+        for result in self.cigar().ops() {
+            let op = result?;
+            let len = op.len() as usize;
+
+            match op.kind() {
+                Kind::Match | Kind::SeqMatch | Kind::SeqMismatch => {
+                    for i in 0..len {
+                        let reference_base = reference_bases
+                            .get(reference_position + i)
+                            .copied()
+                            .ok_or_else(|| {
+                            io::Error::new(
+                                io::ErrorKind::InvalidInput,
+                                "alignment extends past the reference sequence",
+                            )
+                        })?;
+
+                        let read_base = self.base_at(read_position + i).ok_or_else(|| {
+                            io::Error::new(
+                                io::ErrorKind::InvalidData,
+                                "CIGAR read length exceeds sequence length",
+                            )
+                        })?;
+
+                        if is_base_match(read_base, reference_base) {
+                            matches += 1;
+                        }
+                    }
+
+                    reference_position += len;
+                    read_position += len;
+                    alignment_len += len as u64;
+                }
+                Kind::Insertion => {
+                    read_position += len;
+                    alignment_len += len as u64;
+                }
+                Kind::Deletion => {
+                    reference_position += len;
+                    alignment_len += len as u64;
+                }
+                Kind::SoftClip => read_position += len,
+                Kind::Skip => reference_position += len,
+                Kind::HardClip | Kind::Pad => {}
+            }
+        }
+
+        if alignment_len == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "alignment has no aligned bases",
+            ));
+        }
+
+        Ok(matches as f64 / alignment_len as f64)
+    }
+}
+
+// Returns whether a 4-bit encoded read base matches a reference base.
+//
+// The read base `=` matches any reference base, and `N` in either sequence never matches.
+#[cfg(feature = "fasta")]
+fn is_base_match(read_base: u8, reference_base: u8) -> bool {
+    const CODES: &[u8] = b"=ACMGRSVTWYHKDBN";
+    const N: u8 = 15;
+
+    let reference_code = CODES
+        .iter()
+        .position(|&b| b == reference_base.to_ascii_uppercase())
+        .map(|i| i as u8);
+
+    match (read_base, reference_code) {
+        (N, _) | (_, Some(N)) | (_, None) => false,
+        (0, _) => true,
+        (code, Some(reference_code)) => code == reference_code,
+    }
 }
 
 impl Default for Record {
@@ -390,6 +591,20 @@ impl From<Vec<u8>> for Record {
     }
 }
 
+// Returns the key used to compare records in coordinate order.
+//
+// Unmapped records without a reference sequence ID are placed at the end.
+pub(crate) fn coordinate_sort_key(record: &Record) -> (i32, i32) {
+    let reference_sequence_id = record
+        .reference_sequence_id()
+        .map(i32::from)
+        .unwrap_or(i32::MAX);
+
+    let position = record.position().map(i32::from).unwrap_or(i32::MAX);
+
+    (reference_sequence_id, position)
+}
+
 #[cfg(test)]
 mod tests {
     use std::{
@@ -563,6 +778,29 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_sequence_nibbles() -> io::Result<()> {
+        let record = build_record()?;
+        assert_eq!(record.sequence_nibbles(), [0x18, 0x42]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_base_at() -> io::Result<()> {
+        let record = build_record()?;
+
+        let sequence = record.sequence();
+        let expected: Vec<_> = sequence.bases().map(u8::from).collect();
+        let actual: Vec<_> = (0..sequence.base_count())
+            .filter_map(|i| record.base_at(i))
+            .collect();
+
+        assert_eq!(actual, expected);
+        assert!(record.base_at(sequence.base_count()).is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn test_quality_scores() -> io::Result<()> {
         let record = build_record()?;
@@ -571,6 +809,65 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "fasta")]
+    #[test]
+    fn test_identity() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::{
+            header::{ReferenceSequence, ReferenceSequences},
+            record::Position,
+        };
+
+        let reference_sequences: ReferenceSequences = vec![(
+            String::from("sq0"),
+            ReferenceSequence::new(String::from("sq0"), 16)?,
+        )]
+        .into_iter()
+        .collect();
+
+        let reference_sequence = fasta::Record::new(
+            fasta::record::Definition::new(String::from("sq0"), None),
+            b"ACGTACGTACgtacgt".to_vec(),
+        );
+
+        let build_record = |position, cigar: &str, sequence: &str| {
+            sam::Record::builder()
+                .set_reference_sequence_name("sq0".parse()?)
+                .set_position(Position::try_from(position)?)
+                .set_cigar(cigar.parse()?)
+                .set_sequence(sequence.parse()?)
+                .build()
+                .map_err(Box::<dyn std::error::Error>::from)
+                .and_then(|sam_record| {
+                    Record::try_from_sam_record(&reference_sequences, &sam_record)
+                        .map_err(|e| e.into())
+                })
+        };
+
+        // 1 mismatch in 10 aligned bases
+        let record = build_record(3, "10M", "GTACGAACGT")?;
+        assert_eq!(record.identity(&reference_sequence)?, 0.9);
+
+        // 8 matches, 1 inserted base, and 2 deleted bases
+        let record = build_record(1, "2S4M1I2D4M", "NNACGTTGTAC")?;
+        assert_eq!(record.identity(&reference_sequence)?, 8.0 / 11.0);
+
+        let record = build_record(1, "4=", "====")?;
+        assert_eq!(record.identity(&reference_sequence)?, 1.0);
+
+        let record = build_record(13, "8M", "ACGTACGT")?;
+        assert!(matches!(
+            record.identity(&reference_sequence),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            Record::default().identity(&reference_sequence),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_data() -> io::Result<()> {
         let record = build_record()?;
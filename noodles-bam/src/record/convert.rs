@@ -1,4 +1,7 @@
-use std::{convert::TryInto, io, mem};
+use std::{
+    convert::{TryFrom, TryInto},
+    io, mem,
+};
 
 use noodles_sam as sam;
 
@@ -28,7 +31,7 @@ impl Record {
         record: &sam::Record,
     ) -> io::Result<Self> {
         let mut buf = Vec::new();
-        writer::record::write_sam_record(&mut buf, reference_sequences, record)?;
+        writer::record::write_sam_record(&mut buf, reference_sequences, record, false)?;
         // Remove the prepending block size.
         let start = mem::size_of::<u32>();
         Ok(Self::from(buf[start..].to_vec()))
@@ -86,9 +89,20 @@ impl Record {
             builder = builder.set_position(position);
         }
 
+        let mut data: sam::record::Data = self
+            .data()
+            .try_into()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let mut cigar = self.cigar().try_into()?;
+
+        if let Some(long_cigar) = take_cigar_data_field(&cigar, &mut data)? {
+            cigar = long_cigar;
+        }
+
         builder = builder
             .set_mapping_quality(self.mapping_quality())
-            .set_cigar(self.cigar().try_into()?);
+            .set_cigar(cigar);
 
         if let Some(mate_reference_sequence_name) =
             get_reference_sequence_name(reference_sequences, self.mate_reference_sequence_id())?
@@ -115,11 +129,6 @@ impl Record {
             builder = builder.set_quality_scores(quality_scores);
         }
 
-        let data = self
-            .data()
-            .try_into()
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-
         builder = builder.set_data(data);
 
         builder
@@ -128,6 +137,50 @@ impl Record {
     }
 }
 
+// Returns the CIGAR stored in the `CG` data field, if the given CIGAR is a placeholder.
+//
+// § 4.2.2 "N_CIGAR_OP field" (2021-06-03): A CIGAR with more than 65535 operations is stored in
+// the `CG` data field, and a placeholder CIGAR, `<read_len>S<ref_len>N`, is written in its place.
+// The `CG` field is removed from the data when the CIGAR is restored.
+fn take_cigar_data_field(
+    cigar: &sam::record::Cigar,
+    data: &mut sam::record::Data,
+) -> io::Result<Option<sam::record::Cigar>> {
+    use sam::record::{
+        cigar::op::Kind,
+        data::field::{Tag, Value},
+    };
+
+    use super::cigar::Op;
+
+    let is_placeholder = matches!(
+        &cigar[..],
+        [op_0, op_1] if op_0.kind() == Kind::SoftClip && op_1.kind() == Kind::Skip
+    );
+
+    if !is_placeholder {
+        return Ok(None);
+    }
+
+    let values = match data.get(&Tag::Cigar).map(|field| field.value()) {
+        Some(Value::UInt32Array(values)) => values,
+        _ => return Ok(None),
+    };
+
+    let ops = values
+        .iter()
+        .map(|&n| {
+            Op::try_from(n)
+                .map(|op| sam::record::cigar::Op::new(op.kind(), op.len()))
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+
+    data.shift_remove(&Tag::Cigar);
+
+    Ok(Some(sam::record::Cigar::from(ops)))
+}
+
 fn get_reference_sequence_name(
     reference_sequences: &sam::header::ReferenceSequences,
     reference_sequence_id: Option<ReferenceSequenceId>,
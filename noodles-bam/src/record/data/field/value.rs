@@ -3,6 +3,8 @@
 pub mod subtype;
 pub mod ty;
 
+use std::io;
+
 use noodles_sam as sam;
 
 pub use self::{subtype::Subtype, ty::Type};
@@ -693,6 +695,31 @@ impl From<Value> for sam::record::data::field::Value {
     }
 }
 
+/// Converts a character value (`A`) to its encoded byte.
+///
+/// A character value must be a printable ASCII character (`[!-~]`) (§ 1.5 "The alignment
+/// section: optional fields" (2021-06-03)).
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bam::record::data::field::value::char_to_u8;
+/// assert_eq!(char_to_u8('n')?, b'n');
+/// assert!(char_to_u8(' ').is_err());
+/// assert!(char_to_u8('é').is_err());
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn char_to_u8(c: char) -> io::Result<u8> {
+    if c.is_ascii_graphic() {
+        Ok(c as u8)
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid char value: {:?}", c),
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,150 @@
+use std::io::{self, BufRead, Seek};
+
+use noodles_core::Region;
+use noodles_fasta as fasta;
+use noodles_sam as sam;
+
+use super::Record;
+
+/// Reads the reference sequence bases covered by the alignment of a record.
+///
+/// The span is the 1-based, inclusive interval `[alignment_start, alignment_end]`, where the end is calculated using the CIGAR
+/// operations that consume the reference sequence. This includes skipped regions (`N`).
+///
+/// The reference sequence name is resolved using the given reference sequences, and the bases are
+/// queried from the given indexed FASTA reader. An error is returned if the record is
+/// unmapped or the reference sequence is missing from the index.
+///
+/// # Examples
+///
+/// ```
+/// # use std::{convert::TryFrom, io::Cursor};
+/// use noodles_bam as bam;
+/// use noodles_fasta::{self as fasta, fai};
+/// use noodles_sam::{self as sam, header::{ReferenceSequence, ReferenceSequences}, record::Position};
+///
+/// let reference_sequences: ReferenceSequences = vec![(
+///     String::from("sq0"),
+///     ReferenceSequence::new(String::from("sq0"), 10)?,
+/// )]
+/// .into_iter()
+/// .collect();
+///
+/// let data = b">sq0\nACGTACGTAC\n";
+/// let index = vec![fai::Record::new(String::from("sq0"), 10, 5, 10, 11)];
+/// let mut reader = fasta::IndexedReader::new(Cursor::new(&data[..]), index);
+///
+/// let sam_record = sam::Record::builder()
+///     .set_reference_sequence_name("sq0".parse()?)
+///     .set_position(Position::try_from(2)?)
+///     .set_cigar("2M3N2M".parse()?)
+///     .set_sequence("ACGT".parse()?)
+///     .build()?;
+/// let record = bam::Record::try_from_sam_record(&reference_sequences, &sam_record)?;
+///
+/// let bases = bam::record::reference_span(&record, &reference_sequences, &mut reader)?;
+/// assert_eq!(bases, b"CGTACGT");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn reference_span<R>(
+    record: &Record,
+    reference_sequences: &sam::header::ReferenceSequences,
+    reader: &mut fasta::IndexedReader<R>,
+) -> io::Result<Vec<u8>>
+where
+    R: BufRead + Seek,
+{
+    let reference_sequence_name = record
+        .reference_sequence_id()
+        .map(i32::from)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing reference sequence ID"))
+        .and_then(|id| {
+            reference_sequences
+                .get_index(id as usize)
+                .map(|(name, _)| name)
+                .ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidInput, "invalid reference sequence ID")
+                })
+        })?;
+
+    let start = record
+        .position()
+        .map(i32::from)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing position"))?;
+
+    let reference_len = record.cigar().reference_len()? as i32;
+
+    if reference_len == 0 {
+        return Ok(Vec::new());
+    }
+
+    let end = start + reference_len - 1;
+
+    let region = Region::mapped(reference_sequence_name, start..=end);
+    let reference_record = reader.query(&region)?;
+
+    Ok(reference_record.sequence().to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{convert::TryFrom, io::Cursor};
+
+    use noodles_fasta::fai;
+    use sam::header::{ReferenceSequence, ReferenceSequences};
+
+    use super::*;
+
+    fn build_reference_sequences() -> Result<ReferenceSequences, Box<dyn std::error::Error>> {
+        Ok(vec![(
+            String::from("sq0"),
+            ReferenceSequence::new(String::from("sq0"), 20)?,
+        )]
+        .into_iter()
+        .collect())
+    }
+
+    #[test]
+    fn test_reference_span_with_skip() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequences = build_reference_sequences()?;
+
+        let data = b">sq0\nNNNNNNNN\nACGTACGT\nTTTT\n";
+        let index = vec![fai::Record::new(String::from("sq0"), 20, 5, 8, 9)];
+        let mut reader = fasta::IndexedReader::new(Cursor::new(&data[..]), index);
+
+        let sam_record = sam::Record::builder()
+            .set_reference_sequence_name("sq0".parse()?)
+            .set_position(sam::record::Position::try_from(7)?)
+            .set_cigar("2S3M5N2M1I2M".parse()?)
+            .set_sequence("NNACGTACGT".parse()?)
+            .build()?;
+        let record = Record::try_from_sam_record(&reference_sequences, &sam_record)?;
+
+        let bases = reference_span(&record, &reference_sequences, &mut reader)?;
+
+        let alignment_span = record.cigar().reference_len()? as usize;
+        assert_eq!(alignment_span, 12);
+        assert_eq!(bases.len(), alignment_span);
+        assert_eq!(bases, b"NNACGTACGTTT");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reference_span_with_unmapped_record() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequences = build_reference_sequences()?;
+
+        let data = b">sq0\nACGT\n";
+        let index = vec![fai::Record::new(String::from("sq0"), 4, 5, 4, 5)];
+        let mut reader = fasta::IndexedReader::new(Cursor::new(&data[..]), index);
+
+        let record = Record::default();
+
+        assert!(matches!(
+            reference_span(&record, &reference_sequences, &mut reader),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
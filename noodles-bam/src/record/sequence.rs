@@ -3,7 +3,10 @@
 mod base;
 mod bases;
 
-pub use self::{base::Base, bases::Bases};
+pub use self::{
+    base::{Base, TryFromUByteError},
+    bases::Bases,
+};
 
 use std::{fmt, ops::Deref};
 
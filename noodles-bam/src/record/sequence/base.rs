@@ -1,4 +1,4 @@
-use std::fmt;
+use std::{convert::TryFrom, error, fmt};
 
 use noodles_sam as sam;
 
@@ -107,6 +107,44 @@ impl From<sam::record::sequence::Base> for Base {
     }
 }
 
+/// An error returned when a raw 4-bit encoded base fails to convert.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TryFromUByteError(u8);
+
+impl error::Error for TryFromUByteError {}
+
+impl fmt::Display for TryFromUByteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid base: expected {{0..=15}}, got {}", self.0)
+    }
+}
+
+impl TryFrom<u8> for Base {
+    type Error = TryFromUByteError;
+
+    fn try_from(n: u8) -> Result<Self, Self::Error> {
+        match n {
+            0 => Ok(Self::Eq),
+            1 => Ok(Self::A),
+            2 => Ok(Self::C),
+            3 => Ok(Self::M),
+            4 => Ok(Self::G),
+            5 => Ok(Self::R),
+            6 => Ok(Self::S),
+            7 => Ok(Self::V),
+            8 => Ok(Self::T),
+            9 => Ok(Self::W),
+            10 => Ok(Self::Y),
+            11 => Ok(Self::H),
+            12 => Ok(Self::K),
+            13 => Ok(Self::D),
+            14 => Ok(Self::B),
+            15 => Ok(Self::N),
+            _ => Err(TryFromUByteError(n)),
+        }
+    }
+}
+
 impl From<Base> for char {
     fn from(base: Base) -> Self {
         match base {
@@ -244,6 +282,41 @@ mod tests {
         assert_eq!(Base::from(SamBase::Z), Base::N);
     }
 
+    #[test]
+    fn test_try_from_u8_for_base() {
+        assert_eq!(Base::try_from(0), Ok(Base::Eq));
+        assert_eq!(Base::try_from(1), Ok(Base::A));
+        assert_eq!(Base::try_from(2), Ok(Base::C));
+        assert_eq!(Base::try_from(3), Ok(Base::M));
+        assert_eq!(Base::try_from(4), Ok(Base::G));
+        assert_eq!(Base::try_from(5), Ok(Base::R));
+        assert_eq!(Base::try_from(6), Ok(Base::S));
+        assert_eq!(Base::try_from(7), Ok(Base::V));
+        assert_eq!(Base::try_from(8), Ok(Base::T));
+        assert_eq!(Base::try_from(9), Ok(Base::W));
+        assert_eq!(Base::try_from(10), Ok(Base::Y));
+        assert_eq!(Base::try_from(11), Ok(Base::H));
+        assert_eq!(Base::try_from(12), Ok(Base::K));
+        assert_eq!(Base::try_from(13), Ok(Base::D));
+        assert_eq!(Base::try_from(14), Ok(Base::B));
+        assert_eq!(Base::try_from(15), Ok(Base::N));
+
+        assert_eq!(Base::try_from(16), Err(TryFromUByteError(16)));
+    }
+
+    #[test]
+    fn test_round_trip() -> Result<(), TryFromUByteError> {
+        use sam::record::sequence::Base as SamBase;
+
+        for n in 0..=15 {
+            let base = Base::try_from(n)?;
+            assert_eq!(u8::from(base), n);
+            assert_eq!(Base::from(SamBase::from(base)), base);
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_from_base_for_char() {
         assert_eq!(char::from(Base::Eq), '=');
@@ -0,0 +1,298 @@
+use std::{
+    convert::TryFrom,
+    io::{self, Write},
+    str,
+};
+
+use noodles_fastq as fastq;
+use noodles_sam::{
+    self as sam,
+    record::{
+        data::{
+            field::{Tag, Value},
+            Field,
+        },
+        Data, Flags, QualityScores, ReadName, Sequence,
+    },
+};
+
+use super::Writer;
+
+/// Converts FASTQ records to unaligned BAM records.
+///
+/// The given header and its reference sequences are written to the output, followed by an
+/// unmapped record for each FASTQ record. The read name is the FASTQ record name up to the first
+/// whitespace; the sequence and the (Phred+33 encoded) quality scores are copied as is.
+///
+/// If a read group ID is given, each record is assigned to it using the read group (`RG`) data
+/// field. The read group must be declared in the header.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io::{self, BufReader}};
+/// use noodles_bam as bam;
+/// use noodles_fastq as fastq;
+/// use noodles_sam as sam;
+///
+/// let mut reader = File::open("sample.fq").map(BufReader::new).map(fastq::Reader::new)?;
+/// let header = sam::Header::default();
+/// let mut writer = File::create("sample.bam").map(bam::Writer::new)?;
+///
+/// bam::write_unaligned_records(reader.records(), &header, None, &mut writer)?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn write_unaligned_records<I, W>(
+    records: I,
+    header: &sam::Header,
+    read_group_id: Option<&str>,
+    writer: &mut Writer<W>,
+) -> io::Result<()>
+where
+    I: IntoIterator<Item = io::Result<fastq::Record>>,
+    W: Write,
+{
+    let data = build_data(header, read_group_id)?;
+
+    writer.write_header(header)?;
+    writer.write_reference_sequences(header.reference_sequences())?;
+
+    for result in records {
+        let record = result?;
+        let sam_record = build_record(&record, Flags::UNMAPPED, &data, false)?;
+        writer.write_sam_record(header.reference_sequences(), &sam_record)?;
+    }
+
+    Ok(())
+}
+
+/// Converts paired FASTQ records to unaligned BAM records.
+///
+/// This is the same as [`write_unaligned_records`] but for paired-end reads, where `records_1`
+/// and `records_2` are the first and last segments, respectively. The records of each pair are
+/// written one after the other and are flagged as paired, unmapped, mate unmapped, and either the
+/// first or last segment. A `/1` or `/2` suffix is removed from the read name.
+///
+/// This returns an error if the inputs have a different number of records.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io::{self, BufReader}};
+/// use noodles_bam as bam;
+/// use noodles_fastq as fastq;
+/// use noodles_sam as sam;
+///
+/// let mut reader_1 = File::open("sample_1.fq").map(BufReader::new).map(fastq::Reader::new)?;
+/// let mut reader_2 = File::open("sample_2.fq").map(BufReader::new).map(fastq::Reader::new)?;
+/// let header = sam::Header::default();
+/// let mut writer = File::create("sample.bam").map(bam::Writer::new)?;
+///
+/// bam::write_unaligned_pairs(
+///     reader_1.records(),
+///     reader_2.records(),
+///     &header,
+///     None,
+///     &mut writer,
+/// )?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn write_unaligned_pairs<I, J, W>(
+    records_1: I,
+    records_2: J,
+    header: &sam::Header,
+    read_group_id: Option<&str>,
+    writer: &mut Writer<W>,
+) -> io::Result<()>
+where
+    I: IntoIterator<Item = io::Result<fastq::Record>>,
+    J: IntoIterator<Item = io::Result<fastq::Record>>,
+    W: Write,
+{
+    let data = build_data(header, read_group_id)?;
+
+    writer.write_header(header)?;
+    writer.write_reference_sequences(header.reference_sequences())?;
+
+    let flags = Flags::PAIRED | Flags::UNMAPPED | Flags::MATE_UNMAPPED;
+
+    let mut records_1 = records_1.into_iter();
+    let mut records_2 = records_2.into_iter();
+
+    loop {
+        let (record_1, record_2) = match (records_1.next(), records_2.next()) {
+            (Some(r1), Some(r2)) => (r1?, r2?),
+            (None, None) => break,
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "paired inputs have a different number of records",
+                ))
+            }
+        };
+
+        let sam_record = build_record(&record_1, flags | Flags::READ_1, &data, true)?;
+        writer.write_sam_record(header.reference_sequences(), &sam_record)?;
+
+        let sam_record = build_record(&record_2, flags | Flags::READ_2, &data, true)?;
+        writer.write_sam_record(header.reference_sequences(), &sam_record)?;
+    }
+
+    Ok(())
+}
+
+fn build_data(header: &sam::Header, read_group_id: Option<&str>) -> io::Result<Data> {
+    let id = match read_group_id {
+        Some(id) => id,
+        None => return Ok(Data::default()),
+    };
+
+    if !header.read_groups().contains_key(id) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("read group '{}' is not declared in the header", id),
+        ));
+    }
+
+    let field = Field::new(Tag::ReadGroup, Value::String(id.into()));
+    Data::try_from(vec![field]).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+}
+
+fn build_record(
+    record: &fastq::Record,
+    flags: Flags,
+    data: &Data,
+    strip_segment_suffix: bool,
+) -> io::Result<sam::Record> {
+    let mut name = record
+        .name()
+        .split(|b| b.is_ascii_whitespace())
+        .next()
+        .unwrap_or_default();
+
+    if strip_segment_suffix && (name.ends_with(b"/1") || name.ends_with(b"/2")) {
+        name = &name[..name.len() - 2];
+    }
+
+    let read_name: ReadName = parse(name)?;
+    let sequence: Sequence = parse(record.sequence())?;
+    let quality_scores: QualityScores = parse(record.quality_scores())?;
+
+    sam::Record::builder()
+        .set_read_name(read_name)
+        .set_flags(flags)
+        .set_sequence(sequence)
+        .set_quality_scores(quality_scores)
+        .set_data(data.clone())
+        .build()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+fn parse<T>(buf: &[u8]) -> io::Result<T>
+where
+    T: str::FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    str::from_utf8(buf)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        .and_then(|s| {
+            s.parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reader;
+
+    use super::*;
+
+    #[test]
+    fn test_write_unaligned_pairs() -> Result<(), Box<dyn std::error::Error>> {
+        let header: sam::Header = "@HD\tVN:1.6\tSO:unsorted\n@RG\tID:rg0\n".parse()?;
+
+        let records_1 = vec![fastq::Record::new("r0/1 sample", "ACGT", "NDLS")];
+        let records_2 = vec![fastq::Record::new("r0/2 sample", "TTG", "#ND")];
+
+        let mut writer = Writer::new(Vec::new());
+        write_unaligned_pairs(
+            records_1.into_iter().map(Ok),
+            records_2.into_iter().map(Ok),
+            &header,
+            Some("rg0"),
+            &mut writer,
+        )?;
+        writer.try_finish()?;
+
+        let mut reader = Reader::new(writer.get_ref().as_slice());
+        reader.read_header()?;
+        let reference_sequences = reader.read_reference_sequences()?;
+
+        let records: Vec<_> = reader
+            .records()
+            .map(|result| {
+                result.and_then(|record| record.try_into_sam_record(&reference_sequences))
+            })
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(records.len(), 2);
+
+        let flags = Flags::PAIRED | Flags::UNMAPPED | Flags::MATE_UNMAPPED;
+
+        for (record, expected_flags, sequence, quality_scores) in [
+            (&records[0], flags | Flags::READ_1, "ACGT", "NDLS"),
+            (&records[1], flags | Flags::READ_2, "TTG", "#ND"),
+        ] {
+            assert_eq!(record.read_name().map(|name| name.as_str()), Some("r0"));
+            assert_eq!(record.flags(), expected_flags);
+            assert!(record.reference_sequence_name().is_none());
+            assert!(record.position().is_none());
+            assert_eq!(record.sequence().to_string(), sequence);
+            assert_eq!(record.quality_scores().to_string(), quality_scores);
+            assert_eq!(
+                record
+                    .data()
+                    .get(&Tag::ReadGroup)
+                    .map(|field| field.value().as_str()),
+                Some(Some("rg0"))
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_unaligned_records() -> io::Result<()> {
+        let header = sam::Header::default();
+        let records = vec![fastq::Record::new("r0", "ACGT", "NDLS")];
+
+        let mut writer = Writer::new(Vec::new());
+        assert!(matches!(
+            write_unaligned_records(records.iter().cloned().map(Ok), &header, Some("rg0"), &mut writer),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let mut writer = Writer::new(Vec::new());
+        write_unaligned_records(records.into_iter().map(Ok), &header, None, &mut writer)?;
+        writer.try_finish()?;
+
+        let mut reader = Reader::new(writer.get_ref().as_slice());
+        reader.read_header()?;
+        reader.read_reference_sequences()?;
+
+        let record = reader
+            .records()
+            .next()
+            .transpose()?
+            .expect("missing record");
+        assert_eq!(
+            record.read_name().map(|name| name.to_bytes()).ok(),
+            Some(&b"r0"[..])
+        );
+        assert_eq!(record.flags(), Flags::UNMAPPED);
+        assert!(record.reference_sequence_id().is_none());
+        assert!(record.data().is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,407 @@
+use std::{
+    error, fmt,
+    io::{self, Read},
+};
+
+use noodles_sam::{self as sam, header::header::SortOrder};
+
+use super::{record::coordinate_sort_key, Reader, Record};
+
+/// An issue found when validating a BAM file.
+///
+/// Record indices are 0-based and count records in the order they appear in the file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidationIssue {
+    /// The BAM header is missing or invalid.
+    InvalidHeader(String),
+    /// The binary reference sequences are missing or invalid.
+    InvalidReferenceSequences(String),
+    /// The record at the given index could not be read.
+    InvalidRecord(usize, String),
+    /// The record at the given index is mapped, but no reference sequences are defined.
+    MissingReferenceSequences(usize),
+    /// The reference sequence ID of the record at the given index does not exist in the
+    /// reference sequences.
+    InvalidReferenceSequenceId(usize, i32),
+    /// The record at the given index has a reference sequence ID but no position.
+    MissingPosition(usize),
+    /// The record at the given index has a position but no reference sequence ID.
+    MissingReferenceSequenceId(usize),
+    /// The position of the record at the given index is outside of the reference sequence
+    /// (position, reference sequence length).
+    PositionOutOfBounds(usize, i32, i32),
+    /// The CIGAR read length of the record at the given index does not match the sequence
+    /// length (CIGAR read length, sequence length).
+    SequenceLengthMismatch(usize, u32, u32),
+    /// The record at the given index is out of order when the header declares the records as
+    /// being sorted by coordinate.
+    Unsorted(usize),
+}
+
+impl error::Error for ValidationIssue {}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidHeader(message) => write!(f, "invalid header: {}", message),
+            Self::InvalidReferenceSequences(message) => {
+                write!(f, "invalid reference sequences: {}", message)
+            }
+            Self::InvalidRecord(i, message) => {
+                write!(f, "record {}: invalid record: {}", i, message)
+            }
+            Self::MissingReferenceSequences(i) => write!(
+                f,
+                "record {}: record is mapped but no reference sequences are defined",
+                i
+            ),
+            Self::InvalidReferenceSequenceId(i, id) => {
+                write!(f, "record {}: invalid reference sequence ID: {}", i, id)
+            }
+            Self::MissingPosition(i) => write!(
+                f,
+                "record {}: record has a reference sequence ID but no position",
+                i
+            ),
+            Self::MissingReferenceSequenceId(i) => write!(
+                f,
+                "record {}: record has a position but no reference sequence ID",
+                i
+            ),
+            Self::PositionOutOfBounds(i, position, len) => write!(
+                f,
+                "record {}: position out of bounds: expected <= {}, got {}",
+                i, len, position
+            ),
+            Self::SequenceLengthMismatch(i, cigar_read_len, sequence_len) => write!(
+                f,
+                "record {}: sequence length mismatch: expected {}, got {}",
+                i, cigar_read_len, sequence_len
+            ),
+            Self::Unsorted(i) => write!(f, "record {}: record is not coordinate-sorted", i),
+        }
+    }
+}
+
+/// Validates a BAM file for conformance to the specification.
+///
+/// This reads the header, reference sequences, and all records from the start of the given reader
+/// and checks that
+///
+///   * the header is present and valid;
+///   * reference sequences are defined when records are mapped;
+///   * records with a reference sequence ID have a position and vice versa;
+///   * record positions are within the bounds of their reference sequences;
+///   * CIGAR read lengths match sequence lengths; and
+///   * records are sorted if the header declares a coordinate sort order.
+///
+/// Issues are accumulated rather than failing on the first one. Validation stops early if the
+/// header, reference sequences, or a record cannot be read.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bam as bam;
+/// use noodles_sam as sam;
+///
+/// let mut writer = bam::Writer::new(Vec::new());
+/// let header = sam::Header::default();
+/// writer.write_header(&header)?;
+/// writer.write_reference_sequences(header.reference_sequences())?;
+/// writer.write_sam_record(header.reference_sequences(), &sam::Record::default())?;
+/// writer.try_finish()?;
+///
+/// let mut reader = bam::Reader::new(&writer.get_ref()[..]);
+/// assert!(bam::validate(&mut reader).is_empty());
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn validate<R>(reader: &mut Reader<R>) -> Vec<ValidationIssue>
+where
+    R: Read,
+{
+    let mut issues = Vec::new();
+
+    let header: sam::Header = match reader.read_header().and_then(|s| {
+        s.parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }) {
+        Ok(header) => header,
+        Err(e) => {
+            issues.push(ValidationIssue::InvalidHeader(e.to_string()));
+            return issues;
+        }
+    };
+
+    let reference_sequences = match reader.read_reference_sequences() {
+        Ok(reference_sequences) => reference_sequences,
+        Err(e) => {
+            issues.push(ValidationIssue::InvalidReferenceSequences(e.to_string()));
+            return issues;
+        }
+    };
+
+    let is_coordinate_sorted = header
+        .header()
+        .and_then(|hd| hd.sort_order())
+        .map(|sort_order| sort_order == SortOrder::Coordinate)
+        .unwrap_or(false);
+
+    let mut record = Record::default();
+    let mut prev_key = None;
+    let mut i = 0;
+
+    loop {
+        match reader.read_record(&mut record) {
+            Ok(0) => break,
+            Ok(_) => {}
+            Err(e) => {
+                issues.push(ValidationIssue::InvalidRecord(i, e.to_string()));
+                break;
+            }
+        }
+
+        if let Err(e) = validate_record(&reference_sequences, &record, i, &mut issues) {
+            issues.push(ValidationIssue::InvalidRecord(i, e.to_string()));
+            break;
+        }
+
+        if is_coordinate_sorted {
+            let key = coordinate_sort_key(&record);
+
+            if let Some(prev_key) = prev_key {
+                if key < prev_key {
+                    issues.push(ValidationIssue::Unsorted(i));
+                }
+            }
+
+            prev_key = Some(key);
+        }
+
+        i += 1;
+    }
+
+    issues
+}
+
+fn validate_record(
+    reference_sequences: &sam::header::ReferenceSequences,
+    record: &Record,
+    i: usize,
+    issues: &mut Vec<ValidationIssue>,
+) -> io::Result<()> {
+    match (record.reference_sequence_id(), record.position()) {
+        (Some(_), None) => issues.push(ValidationIssue::MissingPosition(i)),
+        (None, Some(_)) => issues.push(ValidationIssue::MissingReferenceSequenceId(i)),
+        _ => {}
+    }
+
+    if let Some(reference_sequence_id) = record.reference_sequence_id().map(i32::from) {
+        if reference_sequences.is_empty() {
+            issues.push(ValidationIssue::MissingReferenceSequences(i));
+        } else {
+            match reference_sequences.get_index(reference_sequence_id as usize) {
+                Some((_, reference_sequence)) => {
+                    if let Some(position) = record.position().map(i32::from) {
+                        if position > reference_sequence.len() {
+                            issues.push(ValidationIssue::PositionOutOfBounds(
+                                i,
+                                position,
+                                reference_sequence.len(),
+                            ));
+                        }
+                    }
+                }
+                None => issues.push(ValidationIssue::InvalidReferenceSequenceId(
+                    i,
+                    reference_sequence_id,
+                )),
+            }
+        }
+    }
+
+    let cigar = record.cigar();
+    let sequence_len = record.sequence().base_count() as u32;
+
+    if !cigar.is_empty() && sequence_len > 0 {
+        let cigar_read_len = cigar.read_len()?;
+
+        if cigar_read_len != sequence_len {
+            issues.push(ValidationIssue::SequenceLengthMismatch(
+                i,
+                cigar_read_len,
+                sequence_len,
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use sam::header::ReferenceSequence;
+
+    use crate::Writer;
+
+    use super::*;
+
+    fn write_bam(header: &sam::Header, records: &[sam::Record]) -> io::Result<Vec<u8>> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(header)?;
+        writer.write_reference_sequences(header.reference_sequences())?;
+
+        for record in records {
+            writer.write_sam_record(header.reference_sequences(), record)?;
+        }
+
+        writer.try_finish()?;
+
+        Ok(writer.get_ref().clone())
+    }
+
+    fn build_header() -> Result<sam::Header, Box<dyn std::error::Error>> {
+        Ok(sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0", 8)?)
+            .build())
+    }
+
+    #[test]
+    fn test_validate() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let record = sam::Record::builder()
+            .set_reference_sequence_name("sq0".parse()?)
+            .set_position(sam::record::Position::try_from(2)?)
+            .set_cigar("4M".parse()?)
+            .set_sequence("ACGT".parse()?)
+            .build()?;
+
+        let data = write_bam(&header, &[record])?;
+        let mut reader = Reader::new(&data[..]);
+
+        assert!(validate(&mut reader).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_with_position_out_of_bounds() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let records = [
+            sam::Record::builder()
+                .set_reference_sequence_name("sq0".parse()?)
+                .set_position(sam::record::Position::try_from(2)?)
+                .build()?,
+            sam::Record::builder()
+                .set_reference_sequence_name("sq0".parse()?)
+                .set_position(sam::record::Position::try_from(13)?)
+                .build()?,
+        ];
+
+        let data = write_bam(&header, &records)?;
+        let mut reader = Reader::new(&data[..]);
+
+        assert_eq!(
+            validate(&mut reader),
+            [ValidationIssue::PositionOutOfBounds(1, 13, 8)]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_with_missing_position() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let records = [
+            sam::Record::builder()
+                .set_reference_sequence_name("sq0".parse()?)
+                .build()?,
+            sam::Record::builder()
+                .set_position(sam::record::Position::try_from(2)?)
+                .build()?,
+        ];
+
+        let data = write_bam(&header, &records)?;
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_header()?;
+        reader.read_reference_sequences()?;
+
+        let record = reader.records().next().transpose()?.unwrap();
+        assert!(record.reference_sequence_id().is_some());
+        assert!(record.position().is_none());
+
+        let mut reader = Reader::new(&data[..]);
+
+        assert_eq!(
+            validate(&mut reader),
+            [
+                ValidationIssue::MissingPosition(0),
+                ValidationIssue::MissingReferenceSequenceId(1)
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_with_sequence_length_mismatch() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let mut record = sam::Record::builder()
+            .set_reference_sequence_name("sq0".parse()?)
+            .set_position(sam::record::Position::try_from(1)?)
+            .set_sequence("ACGT".parse()?)
+            .build()?;
+        *record.cigar_mut() = "2M".parse()?;
+
+        let data = write_bam(&header, &[record])?;
+        let mut reader = Reader::new(&data[..]);
+
+        assert_eq!(
+            validate(&mut reader),
+            [ValidationIssue::SequenceLengthMismatch(0, 2, 4)]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_with_unsorted_records() -> Result<(), Box<dyn std::error::Error>> {
+        let header: sam::Header = "@HD\tVN:1.6\tSO:coordinate\n@SQ\tSN:sq0\tLN:8\n".parse()?;
+
+        let records = [
+            sam::Record::builder()
+                .set_reference_sequence_name("sq0".parse()?)
+                .set_position(sam::record::Position::try_from(5)?)
+                .build()?,
+            sam::Record::builder()
+                .set_reference_sequence_name("sq0".parse()?)
+                .set_position(sam::record::Position::try_from(3)?)
+                .build()?,
+        ];
+
+        let data = write_bam(&header, &records)?;
+        let mut reader = Reader::new(&data[..]);
+
+        assert_eq!(validate(&mut reader), [ValidationIssue::Unsorted(1)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_with_invalid_header() {
+        let data = [];
+        let mut reader = Reader::new(&data[..]);
+
+        assert!(matches!(
+            validate(&mut reader)[..],
+            [ValidationIssue::InvalidHeader(_)]
+        ));
+    }
+}
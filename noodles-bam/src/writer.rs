@@ -1,5 +1,8 @@
+mod builder;
 pub(crate) mod record;
 
+pub use self::builder::Builder;
+
 use std::{
     convert::TryFrom,
     ffi::CString,
@@ -15,6 +18,9 @@ use noodles_sam::{
 
 use super::Record;
 
+// § 4.2 The BAM format (2021-06-03): the fixed-length fields before `read_name`.
+const BLOCK_HEADER_SIZE: usize = 32;
+
 /// A BAM writer.
 ///
 /// Since the raw text header and `bam::Record` are immutable, BAM files are created by encoding a
@@ -42,12 +48,26 @@ where
     W: Write,
 {
     inner: bgzf::Writer<W>,
+    drop_quality_scores: bool,
 }
 
 impl<W> Writer<W>
 where
     W: Write,
 {
+    /// Creates a BAM writer builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let builder = bam::Writer::builder(Vec::new());
+    /// let writer = builder.build();
+    /// ```
+    pub fn builder(inner: W) -> Builder<W> {
+        Builder::new(inner)
+    }
+
     /// Creates a BAM writer with a default compression level.
     ///
     /// The given stream is wrapped in a BGZF encoder.
@@ -59,9 +79,7 @@ where
     /// let writer = bam::Writer::new(Vec::new());
     /// ```
     pub fn new(writer: W) -> Self {
-        Self {
-            inner: bgzf::Writer::new(writer),
-        }
+        Builder::new(writer).build()
     }
 
     /// Returns a reference to the underlying writer.
@@ -145,6 +163,9 @@ where
 
     /// Writes a BAM record.
     ///
+    /// This returns an error if the record is too short to hold the quality scores for each base
+    /// in its sequence.
+    ///
     /// # Examples
     ///
     /// ```
@@ -156,7 +177,7 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
-        write_record(&mut self.inner, record)
+        write_record(&mut self.inner, record, self.drop_quality_scores)
     }
 
     /// Writes a SAM record.
@@ -180,7 +201,48 @@ where
         reference_sequences: &ReferenceSequences,
         record: &sam::Record,
     ) -> io::Result<()> {
-        record::write_sam_record(&mut self.inner, reference_sequences, record)
+        record::write_sam_record(
+            &mut self.inner,
+            reference_sequences,
+            record,
+            self.drop_quality_scores,
+        )
+    }
+
+    /// Writes a SAM record with the given resolved bases.
+    ///
+    /// The bases are raw ASCII bases, e.g., those resolved from a CRAM record, and are used in
+    /// place of the sequence of the given record. They are encoded directly without first being
+    /// parsed into a [`sam::record::Sequence`]. If the record has quality scores, they must be the
+    /// same length as the bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam as bam;
+    /// use noodles_sam as sam;
+    ///
+    /// let mut writer = bam::Writer::new(Vec::new());
+    ///
+    /// let reference_sequences = sam::header::ReferenceSequences::new();
+    /// let record = sam::Record::default();
+    /// writer.write_resolved_record(&reference_sequences, &record, b"ACGT")?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn write_resolved_record(
+        &mut self,
+        reference_sequences: &ReferenceSequences,
+        record: &sam::Record,
+        bases: &[u8],
+    ) -> io::Result<()> {
+        record::write_resolved_record(
+            &mut self.inner,
+            reference_sequences,
+            record,
+            bases,
+            self.drop_quality_scores,
+        )
     }
 }
 
@@ -190,6 +252,10 @@ where
 {
     use super::MAGIC_NUMBER;
 
+    header
+        .validate()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
     writer.write_all(MAGIC_NUMBER)?;
 
     let text = header.to_string();
@@ -242,19 +308,79 @@ where
     Ok(())
 }
 
-fn write_record<W>(writer: &mut W, record: &Record) -> io::Result<()>
+fn write_record<W>(writer: &mut W, record: &Record, drop_quality_scores: bool) -> io::Result<()>
 where
     W: Write,
 {
+    use self::record::NULL_QUALITY_SCORE;
+
+    validate_record(record)?;
+
     let block_size =
         u32::try_from(record.len()).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
     writer.write_u32::<LittleEndian>(block_size)?;
 
-    writer.write_all(record)?;
+    if drop_quality_scores {
+        let (start, end) = quality_scores_bounds(record);
+
+        writer.write_all(&record[..start])?;
+
+        for _ in start..end {
+            writer.write_u8(NULL_QUALITY_SCORE)?;
+        }
+
+        writer.write_all(&record[end..])?;
+    } else {
+        writer.write_all(record)?;
+    }
+
+    Ok(())
+}
+
+// § 4.2.3 SEQ and QUAL encoding (2021-06-03): `seq` and `qual` are both sized by `l_seq`. A
+// record whose quality scores are shorter than its sequence is therefore truncated, and writing it
+// would corrupt the stream. Missing quality scores are expected to already be filled with 0xff.
+pub(crate) fn validate_record(record: &Record) -> io::Result<()> {
+    if record.len() < BLOCK_HEADER_SIZE {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "invalid record length: expected >= {}, got {}",
+                BLOCK_HEADER_SIZE,
+                record.len()
+            ),
+        ));
+    }
+
+    let (qual_offset, qual_end) = quality_scores_bounds(record);
+    let l_seq = qual_end - qual_offset;
+    let qual_len = record.len().saturating_sub(qual_offset);
+
+    if qual_len < l_seq {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "quality scores length mismatch: expected {}, got {}",
+                l_seq, qual_len
+            ),
+        ));
+    }
 
     Ok(())
 }
 
+// Returns the start and end offsets of the quality scores in the record.
+pub(crate) fn quality_scores_bounds(record: &Record) -> (usize, usize) {
+    let l_seq = record.l_seq() as usize;
+
+    let start = BLOCK_HEADER_SIZE
+        + usize::from(record.l_read_name())
+        + 4 * usize::from(record.n_cigar_op())
+        + (l_seq + 1) / 2;
+
+    (start, start + l_seq)
+}
+
 #[cfg(test)]
 mod tests {
     use std::convert::TryFrom;
@@ -287,6 +413,52 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_write_header_with_invalid_read_group() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::header::ReadGroup;
+
+        let header = sam::Header::builder()
+            .add_read_group(
+                ReadGroup::builder()
+                    .set_id("rg0")
+                    .set_description("a\tb")
+                    .build()?,
+            )
+            .build();
+
+        let mut buf = Vec::new();
+
+        assert!(matches!(
+            write_header(&mut buf, &header),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+        assert!(buf.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_quality_scores_length_less_than_sequence_length() {
+        let mut data = Record::default().to_vec();
+        data[16..20].copy_from_slice(&4u32.to_le_bytes()); // l_seq = 4
+        data.extend([0x12, 0x48].iter()); // seq = ACGT
+        data.extend([0x2d, 0x23].iter()); // qual (2 of 4 scores)
+        let record = Record::from(data);
+
+        let mut buf = Vec::new();
+        assert!(matches!(
+            write_record(&mut buf, &record, false),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+        assert!(buf.is_empty());
+
+        let record = Record::from(vec![0x00; 8]);
+        assert!(matches!(
+            write_record(&mut buf, &record, false),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+    }
+
     #[test]
     fn test_write_reference_sequences() -> Result<(), Box<dyn std::error::Error>> {
         let reference_sequences = vec![("sq0", 8)]
@@ -314,7 +486,7 @@ mod tests {
         let record = Record::default();
 
         let mut buf = Vec::new();
-        write_record(&mut buf, &record)?;
+        write_record(&mut buf, &record, false)?;
 
         let block_size = u32::try_from(record.len())?;
         let mut expected = block_size.to_le_bytes().to_vec();
@@ -453,6 +625,99 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_write_resolved_record() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = Writer::new(Vec::new());
+
+        let header = sam::Header::default();
+        let mut sam_record = sam::Record::default();
+        *sam_record.quality_scores_mut() = "NDLS#".parse()?;
+
+        writer.write_resolved_record(header.reference_sequences(), &sam_record, b"ACGTN")?;
+        writer.try_finish()?;
+
+        let mut reader = Reader::new(writer.get_ref().as_slice());
+
+        let mut record = Record::default();
+        reader.read_record(&mut record)?;
+
+        // ACGTN=
+        assert_eq!(record.sequence_nibbles(), [0x12, 0x48, 0xf0]);
+
+        let actual: Vec<_> = record.sequence().bases().collect();
+        let expected = [Base::A, Base::C, Base::G, Base::T, Base::N];
+        assert_eq!(actual, expected);
+
+        let actual = record.quality_scores();
+        let expected = [45, 35, 43, 50, 2];
+        assert_eq!(*actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_sam_record_with_drop_quality_scores() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = Writer::builder(Vec::new())
+            .set_drop_quality_scores(true)
+            .build();
+
+        let header = sam::Header::default();
+        let sam_record = sam::Record::builder()
+            .set_sequence("ATCG".parse()?)
+            .set_quality_scores("NDLS".parse()?)
+            .build()?;
+
+        writer.write_sam_record(header.reference_sequences(), &sam_record)?;
+        writer.try_finish()?;
+
+        let mut reader = Reader::new(writer.get_ref().as_slice());
+
+        let mut record = Record::default();
+        reader.read_record(&mut record)?;
+
+        let actual: Vec<_> = record.sequence().bases().collect();
+        let expected = [Base::A, Base::T, Base::C, Base::G];
+        assert_eq!(actual, expected);
+
+        let actual = record.quality_scores();
+        let expected = [255, 255, 255, 255];
+        assert_eq!(*actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_drop_quality_scores() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = Writer::builder(Vec::new())
+            .set_drop_quality_scores(true)
+            .build();
+
+        let header = sam::Header::default();
+        let sam_record = sam::Record::builder()
+            .set_sequence("ATCG".parse()?)
+            .set_quality_scores("NDLS".parse()?)
+            .set_data("NH:i:1".parse()?)
+            .build()?;
+        let record = Record::try_from_sam_record(header.reference_sequences(), &sam_record)?;
+
+        writer.write_record(&record)?;
+        writer.try_finish()?;
+
+        let mut reader = Reader::new(writer.get_ref().as_slice());
+
+        let mut actual = Record::default();
+        reader.read_record(&mut actual)?;
+
+        assert_eq!(*actual.quality_scores(), [255, 255, 255, 255]);
+
+        let actual_bases: Vec<_> = actual.sequence().bases().collect();
+        assert_eq!(actual_bases, [Base::A, Base::T, Base::C, Base::G]);
+
+        assert_eq!(Data::try_from(actual.data())?, *sam_record.data());
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_sam_record_with_data() -> Result<(), Box<dyn std::error::Error>> {
         use noodles_sam::record::data::{
@@ -0,0 +1,57 @@
+use std::io::Write;
+
+use noodles_bgzf as bgzf;
+
+use super::Writer;
+
+/// A BAM writer builder.
+pub struct Builder<W> {
+    inner: W,
+    drop_quality_scores: bool,
+}
+
+impl<W> Builder<W>
+where
+    W: Write,
+{
+    pub(crate) fn new(inner: W) -> Self {
+        Self {
+            inner,
+            drop_quality_scores: false,
+        }
+    }
+
+    /// Sets whether to drop quality scores when writing records.
+    ///
+    /// When enabled, the quality scores of each written BAM or SAM record are replaced with the missing
+    /// quality scores sentinel (`0xff` for each base), i.e., they are stored as `*`, regardless of
+    /// whether the record has quality scores.
+    ///
+    /// By default, quality scores are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let builder = bam::Writer::builder(Vec::new()).set_drop_quality_scores(true);
+    /// ```
+    pub fn set_drop_quality_scores(mut self, drop_quality_scores: bool) -> Self {
+        self.drop_quality_scores = drop_quality_scores;
+        self
+    }
+
+    /// Builds a BAM writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let writer = bam::Writer::builder(Vec::new()).build();
+    /// ```
+    pub fn build(self) -> Writer<W> {
+        Writer {
+            inner: bgzf::Writer::new(self.inner),
+            drop_quality_scores: self.drop_quality_scores,
+        }
+    }
+}
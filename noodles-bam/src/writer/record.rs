@@ -1,8 +1,9 @@
 use std::{
+    borrow::Cow,
+    cmp,
     convert::TryFrom,
     ffi::CString,
     io::{self, Write},
-    mem,
 };
 
 use byteorder::{LittleEndian, WriteBytesExt};
@@ -12,7 +13,16 @@ use noodles_sam::{
     record::{Cigar, Data, QualityScores, Sequence},
 };
 
-use crate::record::sequence::Base;
+use crate::{
+    bai::{region_to_bin, UNMAPPED_BIN},
+    record::sequence::Base,
+};
+
+// § 4.2.3 "CIGAR: CIGAR string" (2021-06-03): `op_len` is stored in the upper 28 bits.
+pub(crate) const MAX_CIGAR_OP_LEN: u32 = (1 << 28) - 1;
+
+// § 4.2.2 "N_CIGAR_OP field" (2021-06-03): `n_cigar_op` is a `uint16_t`.
+pub(crate) const MAX_CIGAR_OP_COUNT: usize = u16::MAX as usize;
 
 // § 4.2 The BAM format (2021-06-03)
 //
@@ -20,20 +30,68 @@ use crate::record::sequence::Base;
 // (4) + next_ref_id (4) + next_pos (4) + tlen (4)
 const BLOCK_HEADER_SIZE: u32 = 32;
 
-// § 4.2.1 BIN field calculation (2021-06-03)
-const UNMAPPED_BIN: u16 = 4680;
-
 // § 4.2.3 SEQ and QUAL encoding (2021-06-03)
-const NULL_QUALITY_SCORE: u8 = 255;
+pub(crate) const NULL_QUALITY_SCORE: u8 = 255;
 
 pub fn write_sam_record<W>(
     writer: &mut W,
     reference_sequences: &ReferenceSequences,
     record: &sam::Record,
+    drop_quality_scores: bool,
+) -> io::Result<()>
+where
+    W: Write,
+{
+    write_record_with_bases(
+        writer,
+        reference_sequences,
+        record,
+        None,
+        drop_quality_scores,
+    )
+}
+
+// Writes a SAM record using the given raw ASCII bases in place of the record sequence.
+pub fn write_resolved_record<W>(
+    writer: &mut W,
+    reference_sequences: &ReferenceSequences,
+    record: &sam::Record,
+    bases: &[u8],
+    drop_quality_scores: bool,
+) -> io::Result<()>
+where
+    W: Write,
+{
+    // The bases are validated before anything is written so that an invalid base does not leave a
+    // partial record in the stream.
+    for &b in bases {
+        sam::record::sequence::Base::try_from(char::from(b))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    }
+
+    write_record_with_bases(
+        writer,
+        reference_sequences,
+        record,
+        Some(bases),
+        drop_quality_scores,
+    )
+}
+
+fn write_record_with_bases<W>(
+    writer: &mut W,
+    reference_sequences: &ReferenceSequences,
+    record: &sam::Record,
+    resolved_bases: Option<&[u8]>,
+    drop_quality_scores: bool,
 ) -> io::Result<()>
 where
     W: Write,
 {
+    let sequence_len = resolved_bases
+        .map(|bases| bases.len())
+        .unwrap_or_else(|| record.sequence().len());
+
     let name = record.read_name().map(|name| name.as_str()).unwrap_or("*");
     let c_read_name =
         CString::new(name).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
@@ -42,15 +100,51 @@ where
     let l_read_name = u8::try_from(read_name.len())
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
 
-    let n_cigar_op = u16::try_from(record.cigar().len())
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    let l_seq =
+        u32::try_from(sequence_len).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
 
-    let l_seq = u32::try_from(record.sequence().len())
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    let cigar = get_record_cigar(record);
+
+    let n_cigar_op =
+        u16::try_from(cigar.len()).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    // The record is validated before anything is written so that an invalid field does not leave a
+    // partial record in the stream. Like the data, the CIGAR is encoded up front.
+    let mut raw_cigar = Vec::with_capacity(4 * cigar.len());
+    write_cigar(&mut raw_cigar, &cigar)?;
+
+    let ref_id = get_reference_sequence_id(reference_sequences, record.reference_sequence_name())?;
+    let next_ref_id =
+        get_reference_sequence_id(reference_sequences, record.mate_reference_sequence_name())?;
+
+    // § 4.2.3 SEQ and QUAL encoding (2021-06-03)
+    let quality_scores = record.quality_scores();
+
+    if sequence_len > 0
+        && !drop_quality_scores
+        && !quality_scores.is_empty()
+        && sequence_len != quality_scores.len()
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "quality scores length mismatch: expected {}, got {}",
+                sequence_len,
+                quality_scores.len()
+            ),
+        ));
+    }
 
-    let data_len = calculate_data_len(record.data()).and_then(|len| {
-        u32::try_from(len).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
-    })?;
+    // The data is serialized once up front so that its length is known for `block_size`.
+    let mut data = Vec::new();
+    write_data(&mut data, record.data())?;
+
+    if record.cigar().len() > MAX_CIGAR_OP_COUNT {
+        write_cigar_data_field(&mut data, record)?;
+    }
+
+    let data_len =
+        u32::try_from(data.len()).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
 
     let block_size = BLOCK_HEADER_SIZE
         + u32::from(l_read_name)
@@ -60,12 +154,7 @@ where
         + data_len;
     writer.write_u32::<LittleEndian>(block_size)?;
 
-    // ref_id
-    write_reference_sequence_id(
-        writer,
-        reference_sequences,
-        record.reference_sequence_name(),
-    )?;
+    writer.write_i32::<LittleEndian>(ref_id)?;
 
     // pos
     write_position(writer, record.position())?;
@@ -75,16 +164,7 @@ where
     let mapq = u8::from(record.mapping_quality());
     writer.write_u8(mapq)?;
 
-    let bin = record
-        .position()
-        .map(|v| i32::from(v) - 1)
-        .map(|start| {
-            // 0-based, [start, end)
-            let reference_len = record.cigar().reference_len() as i32;
-            let end = start + reference_len;
-            region_to_bin(start, end) as u16
-        })
-        .unwrap_or(UNMAPPED_BIN);
+    let bin = calculate_record_bin(record);
     writer.write_u16::<LittleEndian>(bin)?;
 
     writer.write_u16::<LittleEndian>(n_cigar_op)?;
@@ -94,12 +174,7 @@ where
 
     writer.write_u32::<LittleEndian>(l_seq)?;
 
-    // next_ref_id
-    write_reference_sequence_id(
-        writer,
-        reference_sequences,
-        record.mate_reference_sequence_name(),
-    )?;
+    writer.write_i32::<LittleEndian>(next_ref_id)?;
 
     // next_pos
     write_position(writer, record.mate_position())?;
@@ -109,49 +184,35 @@ where
 
     writer.write_all(read_name)?;
 
-    write_cigar(writer, record.cigar())?;
-
-    // § 4.2.3 SEQ and QUAL encoding (2021-06-03)
-    let sequence = record.sequence();
-    let quality_scores = record.quality_scores();
+    writer.write_all(&raw_cigar)?;
 
-    if !sequence.is_empty() {
-        write_seq(writer, sequence)?;
+    if sequence_len > 0 {
+        match resolved_bases {
+            Some(bases) => write_raw_seq(writer, bases)?,
+            None => write_seq(writer, record.sequence())?,
+        }
 
-        if sequence.len() == quality_scores.len() {
-            write_qual(writer, quality_scores)?;
-        } else if quality_scores.is_empty() {
-            for _ in 0..sequence.len() {
+        if drop_quality_scores || quality_scores.is_empty() {
+            for _ in 0..sequence_len {
                 writer.write_u8(NULL_QUALITY_SCORE)?;
             }
         } else {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidInput,
-                format!(
-                    "quality scores length mismatch: expected {}, got {}",
-                    sequence.len(),
-                    quality_scores.len()
-                ),
-            ));
+            write_qual(writer, quality_scores)?;
         }
     }
 
-    write_data(writer, record.data())?;
+    writer.write_all(&data)?;
 
     Ok(())
 }
 
-fn write_reference_sequence_id<W>(
-    writer: &mut W,
+pub(crate) fn get_reference_sequence_id(
     reference_sequences: &ReferenceSequences,
     reference_sequence_name: Option<&sam::record::ReferenceSequenceName>,
-) -> io::Result<()>
-where
-    W: Write,
-{
+) -> io::Result<i32> {
     use crate::record::reference_sequence_id;
 
-    let id = match reference_sequence_name {
+    match reference_sequence_name {
         Some(name) => reference_sequences
             .get_index_of(name.as_str())
             .ok_or_else(|| {
@@ -162,11 +223,9 @@ where
             })
             .and_then(|i| {
                 i32::try_from(i).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
-            })?,
-        None => reference_sequence_id::UNMAPPED,
-    };
-
-    writer.write_i32::<LittleEndian>(id)
+            }),
+        None => Ok(reference_sequence_id::UNMAPPED),
+    }
 }
 
 fn write_position<W>(writer: &mut W, position: Option<sam::record::Position>) -> io::Result<()>
@@ -176,7 +235,7 @@ where
     use crate::record::UNMAPPED_POSITION;
 
     let pos = position
-        .map(|p| i32::from(p) - 1)
+        .map(|position| position.to_zero_based())
         .unwrap_or(UNMAPPED_POSITION);
 
     writer.write_i32::<LittleEndian>(pos)
@@ -187,9 +246,82 @@ where
     W: Write,
 {
     for op in cigar.iter() {
-        let len = op.len();
-        let kind = op.kind() as u32;
-        let value = len << 4 | kind;
+        let value = encode_cigar_op(op)?;
+        writer.write_u32::<LittleEndian>(value)?;
+    }
+
+    Ok(())
+}
+
+fn encode_cigar_op(op: &sam::record::cigar::Op) -> io::Result<u32> {
+    let len = op.len();
+
+    if len > MAX_CIGAR_OP_LEN {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "invalid CIGAR op: {}: length exceeds {}",
+                op, MAX_CIGAR_OP_LEN
+            ),
+        ));
+    }
+
+    let kind = op.kind() as u32;
+    Ok(len << 4 | kind)
+}
+
+// Returns the CIGAR to write in the `cigar` field of the record.
+//
+// § 4.2.2 "N_CIGAR_OP field" (2021-06-03): A CIGAR with more than 65535 operations is stored in the
+// `CG` data field (see `write_cigar_data_field`), and a placeholder CIGAR, `<read_len>S<ref_len>N`,
+// is written in its place.
+pub(crate) fn get_record_cigar(record: &sam::Record) -> Cow<'_, Cigar> {
+    if record.cigar().len() > MAX_CIGAR_OP_COUNT {
+        Cow::Owned(build_placeholder_cigar(record.cigar()))
+    } else {
+        Cow::Borrowed(record.cigar())
+    }
+}
+
+fn build_placeholder_cigar(cigar: &Cigar) -> Cigar {
+    use sam::record::cigar::{op::Kind, Op};
+
+    Cigar::from(vec![
+        Op::new(Kind::SoftClip, cigar.read_len()),
+        Op::new(Kind::Skip, cigar.reference_len()),
+    ])
+}
+
+// Writes the CIGAR of the record as a `CG:B:I` data field.
+pub(crate) fn write_cigar_data_field<W>(writer: &mut W, record: &sam::Record) -> io::Result<()>
+where
+    W: Write,
+{
+    use noodles_sam::record::data::field::{
+        value::{Subtype, Type},
+        Tag,
+    };
+
+    if record.data().contains_key(&Tag::Cigar) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "CIGAR has more than {} ops, but the {} data field is already set",
+                MAX_CIGAR_OP_COUNT,
+                Tag::Cigar
+            ),
+        ));
+    }
+
+    let cigar = record.cigar();
+
+    writer.write_all(Tag::Cigar.as_ref().as_bytes())?;
+    writer.write_u8(char::from(Type::Array) as u8)?;
+    writer.write_u8(char::from(Subtype::UInt32) as u8)?;
+    writer.write_u32::<LittleEndian>(cigar.len() as u32)?;
+
+    for op in cigar.iter() {
+        let value = encode_cigar_op(op)?;
         writer.write_u32::<LittleEndian>(value)?;
     }
 
@@ -202,6 +334,9 @@ where
 {
     for chunk in sequence.chunks(2) {
         let l = Base::from(chunk[0]);
+
+        // § 4.2.3 "SEQ and QUAL encoding" (2021-06-03): "When `l_seq` is odd the bottom 4 bits of
+        // the last byte are undefined, but we recommend writing these are zero."
         let r = chunk.get(1).copied().map(Base::from).unwrap_or(Base::Eq);
         let value = u8::from(l) << 4 | u8::from(r);
         writer.write_u8(value)?;
@@ -210,100 +345,45 @@ where
     Ok(())
 }
 
-fn write_qual<W>(writer: &mut W, quality_scores: &QualityScores) -> io::Result<()>
+fn write_raw_seq<W>(writer: &mut W, bases: &[u8]) -> io::Result<()>
 where
     W: Write,
 {
-    for score in quality_scores.iter() {
-        let value = u8::from(*score);
-        writer.write_u8(value)?;
+    fn encode_base(b: u8) -> io::Result<u8> {
+        sam::record::sequence::Base::try_from(char::from(b))
+            .map(Base::from)
+            .map(u8::from)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
     }
 
-    Ok(())
-}
-
-pub(crate) fn calculate_data_len(data: &Data) -> io::Result<usize> {
-    use noodles_sam::record::data::field::Value;
+    for chunk in bases.chunks(2) {
+        let l = encode_base(chunk[0])?;
 
-    let mut len = 0;
+        // § 4.2.3 "SEQ and QUAL encoding" (2021-06-03): "When `l_seq` is odd the bottom 4 bits of
+        // the last byte are undefined, but we recommend writing these are zero."
+        let r = chunk
+            .get(1)
+            .copied()
+            .map(encode_base)
+            .transpose()?
+            .unwrap_or_else(|| u8::from(Base::Eq));
 
-    for field in data.values() {
-        // tag
-        len += 2;
-        // val_type
-        len += 1;
-
-        let value = field.value();
+        writer.write_u8(l << 4 | r)?;
+    }
 
-        if value.subtype().is_some() {
-            // subtype
-            len += 1;
-            // count
-            len += mem::size_of::<u32>();
-        }
+    Ok(())
+}
 
-        match value {
-            Value::Char(_) => {
-                len += mem::size_of::<u8>();
-            }
-            Value::Int(n) => {
-                if *n >= 0 {
-                    if *n <= i64::from(u8::MAX) {
-                        len += mem::size_of::<u8>();
-                    } else if *n <= i64::from(u16::MAX) {
-                        len += mem::size_of::<u16>();
-                    } else if *n <= i64::from(u32::MAX) {
-                        len += mem::size_of::<u32>();
-                    } else {
-                        return Err(io::Error::new(
-                            io::ErrorKind::InvalidInput,
-                            format!("invalid integer value: {}", n),
-                        ));
-                    }
-                } else if *n >= i64::from(i8::MIN) {
-                    len += mem::size_of::<i8>();
-                } else if *n >= i64::from(i16::MIN) {
-                    len += mem::size_of::<i16>();
-                } else if *n >= i64::from(i32::MIN) {
-                    len += mem::size_of::<i32>();
-                } else {
-                    return Err(io::Error::new(
-                        io::ErrorKind::InvalidInput,
-                        format!("invalid integer value: {}", n),
-                    ));
-                }
-            }
-            Value::Float(_) => {
-                len += mem::size_of::<f32>();
-            }
-            Value::String(s) | Value::Hex(s) => {
-                len += s.as_bytes().len() + 1;
-            }
-            Value::Int8Array(values) => {
-                len += values.len();
-            }
-            Value::UInt8Array(values) => {
-                len += values.len();
-            }
-            Value::Int16Array(values) => {
-                len += mem::size_of::<i16>() * values.len();
-            }
-            Value::UInt16Array(values) => {
-                len += mem::size_of::<u16>() * values.len();
-            }
-            Value::Int32Array(values) => {
-                len += mem::size_of::<i32>() * values.len();
-            }
-            Value::UInt32Array(values) => {
-                len += mem::size_of::<u32>() * values.len();
-            }
-            Value::FloatArray(values) => {
-                len += mem::size_of::<f32>() * values.len();
-            }
-        }
+fn write_qual<W>(writer: &mut W, quality_scores: &QualityScores) -> io::Result<()>
+where
+    W: Write,
+{
+    for score in quality_scores.iter() {
+        let value = u8::from(*score);
+        writer.write_u8(value)?;
     }
 
-    Ok(len)
+    Ok(())
 }
 
 fn write_data<W>(writer: &mut W, data: &Data) -> io::Result<()>
@@ -312,6 +392,8 @@ where
 {
     use noodles_sam::record::data::field::Value;
 
+    use crate::record::data::field::value::char_to_u8;
+
     for field in data.values() {
         writer.write_all(field.tag().as_ref().as_bytes())?;
 
@@ -330,7 +412,8 @@ where
 
         match value {
             Value::Char(c) => {
-                writer.write_u8(*c as u8)?;
+                let b = char_to_u8(*c)?;
+                writer.write_u8(b)?;
             }
             Value::Int(_) => unreachable!(),
             Value::Float(n) => {
@@ -435,31 +518,353 @@ where
     }
 }
 
-// § 5.3 C source code for computing bin number and overlapping bins (2021-06-03)
-// 0-based, [start, end)
-#[allow(clippy::eq_op)]
-pub(crate) fn region_to_bin(start: i32, mut end: i32) -> i32 {
-    end -= 1;
-
-    if start >> 14 == end >> 14 {
-        ((1 << 15) - 1) / 7 + (start >> 14)
-    } else if start >> 17 == end >> 17 {
-        ((1 << 12) - 1) / 7 + (start >> 17)
-    } else if start >> 20 == end >> 20 {
-        ((1 << 9) - 1) / 7 + (start >> 20)
-    } else if start >> 23 == end >> 23 {
-        ((1 << 6) - 1) / 7 + (start >> 23)
-    } else if start >> 26 == end >> 26 {
-        ((1 << 3) - 1) / 7 + (start >> 26)
-    } else {
-        0
-    }
+// Calculates the bin of a record from its 0-based start position and reference length.
+//
+// The bin is calculated from the position regardless of whether the record is flagged as
+// unmapped, i.e., a placed unmapped record is binned at its position. A record that does not
+// consume the reference sequence, e.g., a placed unmapped record, is treated as covering a single
+// base, i.e., `reg2bin(pos, pos + 1)`.
+pub(crate) fn calculate_bin(start: i32, reference_len: i32) -> io::Result<u16> {
+    // 0-based, [start, end)
+    let end = start + cmp::max(reference_len, 1);
+    region_to_bin(start, end)
+}
+
+// Calculates the bin of a record to be written.
+//
+// A record that ends past the BAI binning limit (2^29) cannot be binned. Like htslib, the bin is
+// then set to the placeholder 4680 ([`UNMAPPED_BIN`]) rather than failing; a coordinate-sorted
+// index (CSI) does not use the bin field.
+pub(crate) fn calculate_record_bin(record: &sam::Record) -> u16 {
+    record
+        .position()
+        .map(|position| position.to_zero_based())
+        .and_then(|start| {
+            let reference_len = record.cigar().reference_len() as i32;
+            calculate_bin(start, reference_len).ok()
+        })
+        .unwrap_or(UNMAPPED_BIN)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_write_sam_record_with_data() -> Result<(), Box<dyn std::error::Error>> {
+        let record = sam::Record::builder()
+            .set_data("NH:i:1\tRG:Z:rg0\tXF:f:1.5\tXB:B:s,-1,2\tXA:A:x\tXI:i:-70000".parse()?)
+            .build()?;
+
+        let mut buf = Vec::new();
+        write_sam_record(&mut buf, &ReferenceSequences::default(), &record, false)?;
+
+        let expected = [
+            0x4b, 0x00, 0x00, 0x00, // block_size = 75
+            0xff, 0xff, 0xff, 0xff, // ref_id = -1
+            0xff, 0xff, 0xff, 0xff, // pos = -1
+            0x02, // l_read_name = 2
+            0xff, // mapq = 255
+            0x48, 0x12, // bin = 4680
+            0x00, 0x00, // n_cigar_op = 0
+            0x04, 0x00, // flag = 4
+            0x00, 0x00, 0x00, 0x00, // l_seq = 0
+            0xff, 0xff, 0xff, 0xff, // next_ref_id = -1
+            0xff, 0xff, 0xff, 0xff, // next_pos = -1
+            0x00, 0x00, 0x00, 0x00, // tlen = 0
+            b'*', 0x00, // read_name = "*\x00"
+            b'N', b'H', b'C', 0x01, // data[0] = NH:C:1
+            b'R', b'G', b'Z', b'r', b'g', b'0', 0x00, // data[1] = RG:Z:rg0
+            b'X', b'F', b'f', 0x00, 0x00, 0xc0, 0x3f, // data[2] = XF:f:1.5
+            b'X', b'B', b'B', b's', 0x02, 0x00, 0x00, 0x00, 0xff, 0xff, 0x02,
+            0x00, // data[3] = XB:B:s,-1,2
+            b'X', b'A', b'A', b'x', // data[4] = XA:A:x
+            b'X', b'I', b'i', 0x90, 0xee, 0xfe, 0xff, // data[5] = XI:i:-70000
+        ];
+
+        assert_eq!(buf, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_cigar() -> io::Result<()> {
+        use sam::record::cigar::{op::Kind, Op};
+
+        let mut buf = Vec::new();
+        let cigar = Cigar::from(vec![Op::new(Kind::Match, MAX_CIGAR_OP_LEN)]);
+        write_cigar(&mut buf, &cigar)?;
+        assert_eq!(buf, [0xf0, 0xff, 0xff, 0xff]);
+
+        let mut buf = Vec::new();
+        let cigar = Cigar::from(vec![
+            Op::new(Kind::Match, 4),
+            Op::new(Kind::Deletion, MAX_CIGAR_OP_LEN + 1),
+        ]);
+        assert!(matches!(
+            write_cigar(&mut buf, &cigar),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_seq_with_lowercase_bases() -> Result<(), Box<dyn std::error::Error>> {
+        let mut buf = Vec::new();
+        write_seq(&mut buf, &"acgtN".parse()?)?;
+        // ACGTN=
+        assert_eq!(buf, [0x12, 0x48, 0xf0]);
+
+        let mut expected = Vec::new();
+        write_seq(&mut expected, &"ACGTN".parse()?)?;
+        assert_eq!(buf, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_raw_seq() -> Result<(), Box<dyn std::error::Error>> {
+        let mut buf = Vec::new();
+        write_raw_seq(&mut buf, b"ACGTN")?;
+        // ACGTN=
+        assert_eq!(buf, [0x12, 0x48, 0xf0]);
+
+        let mut expected = Vec::new();
+        write_seq(&mut expected, &"ACGTN".parse()?)?;
+        assert_eq!(buf, expected);
+
+        buf.clear();
+        assert!(matches!(
+            write_raw_seq(&mut buf, b"AC*T"),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_sam_record_with_placed_unmapped_record() -> Result<(), Box<dyn std::error::Error>>
+    {
+        use std::convert::TryFrom;
+
+        use sam::record::{Flags, Position};
+
+        let reference_sequences = vec![("sq0", 65536)]
+            .into_iter()
+            .map(|(name, len)| {
+                sam::header::ReferenceSequence::new(name, len).map(|rs| (name.into(), rs))
+            })
+            .collect::<Result<_, _>>()?;
+
+        // A placed unmapped record at the start of the second 16 KiB leaf bin.
+        let record = sam::Record::builder()
+            .set_flags(Flags::UNMAPPED)
+            .set_reference_sequence_name("sq0".parse()?)
+            .set_position(Position::try_from(16385)?)
+            .build()?;
+
+        let mut buf = Vec::new();
+        write_sam_record(&mut buf, &reference_sequences, &record, false)?;
+
+        // bin
+        assert_eq!(&buf[14..16], &4682u16.to_le_bytes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_sam_record_with_invalid_fields() -> Result<(), Box<dyn std::error::Error>> {
+        use std::convert::TryFrom;
+
+        use sam::record::{
+            cigar::{op::Kind, Op},
+            Position, QualityScores,
+        };
+
+        let reference_sequences = vec![("sq0", 8)]
+            .into_iter()
+            .map(|(name, len)| {
+                sam::header::ReferenceSequence::new(name, len).map(|rs| (name.into(), rs))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let t = |record: &sam::Record| {
+            let mut buf = Vec::new();
+            let result = write_sam_record(&mut buf, &reference_sequences, record, false);
+            assert!(matches!(result, Err(ref e) if e.kind() == io::ErrorKind::InvalidInput));
+            // Nothing is written for an invalid record.
+            assert!(buf.is_empty());
+        };
+
+        let mut record = sam::Record::default();
+        *record.cigar_mut() = Cigar::from(vec![Op::new(Kind::Match, MAX_CIGAR_OP_LEN + 1)]);
+        t(&record);
+
+        let record = sam::Record::builder()
+            .set_reference_sequence_name("sq1".parse()?)
+            .set_position(Position::try_from(1)?)
+            .build()?;
+        t(&record);
+
+        let mut record = sam::Record::builder()
+            .set_sequence("ACGT".parse()?)
+            .build()?;
+        *record.quality_scores_mut() = "NDLS!".parse::<QualityScores>()?;
+        t(&record);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_sam_record_with_position_past_binning_limit(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use std::convert::TryFrom;
+
+        use sam::record::Position;
+
+        let reference_sequences = vec![("sq0", 1 << 30)]
+            .into_iter()
+            .map(|(name, len)| {
+                sam::header::ReferenceSequence::new(name, len).map(|rs| (name.into(), rs))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let record = sam::Record::builder()
+            .set_reference_sequence_name("sq0".parse()?)
+            .set_position(Position::try_from((1 << 29) + 1)?)
+            .set_cigar("4M".parse()?)
+            .set_sequence("ACGT".parse()?)
+            .build()?;
+
+        let mut buf = Vec::new();
+        write_sam_record(&mut buf, &reference_sequences, &record, false)?;
+
+        // bin
+        assert_eq!(&buf[14..16], &UNMAPPED_BIN.to_le_bytes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_calculate_record_bin() -> Result<(), Box<dyn std::error::Error>> {
+        use std::convert::TryFrom;
+
+        use sam::record::Position;
+
+        let record = sam::Record::builder()
+            .set_position(Position::try_from(8)?)
+            .set_cigar("5M".parse()?)
+            .build()?;
+        assert_eq!(calculate_record_bin(&record), 4681);
+
+        assert_eq!(calculate_record_bin(&sam::Record::default()), UNMAPPED_BIN);
+
+        let record = sam::Record::builder()
+            .set_position(Position::try_from(1 << 29)?)
+            .set_cigar("2M".parse()?)
+            .build()?;
+        assert_eq!(calculate_record_bin(&record), UNMAPPED_BIN);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_sam_record_with_more_than_65535_cigar_ops(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use std::convert::TryFrom;
+
+        use sam::record::{
+            cigar::{op::Kind, Op},
+            data::field::Tag,
+            Position,
+        };
+
+        use crate::Record;
+
+        let reference_sequences = vec![("sq0", 65536)]
+            .into_iter()
+            .map(|(name, len)| {
+                sam::header::ReferenceSequence::new(name, len).map(|rs| (name.into(), rs))
+            })
+            .collect::<Result<_, _>>()?;
+
+        // 70000 ops (35000 × 1M1I)
+        let ops: Vec<_> = (0..35000)
+            .flat_map(|_| vec![Op::new(Kind::Match, 1), Op::new(Kind::Insertion, 1)])
+            .collect();
+        let cigar = Cigar::from(ops);
+
+        let sam_record = sam::Record::builder()
+            .set_reference_sequence_name("sq0".parse()?)
+            .set_position(Position::try_from(8)?)
+            .set_cigar(cigar.clone())
+            .set_sequence("A".repeat(70000).parse()?)
+            .set_quality_scores("N".repeat(70000).parse()?)
+            .set_data("NH:i:1".parse()?)
+            .build()?;
+
+        let record = Record::try_from_sam_record(&reference_sequences, &sam_record)?;
+
+        let placeholder_cigar = Cigar::try_from(record.cigar())?;
+        assert_eq!(
+            placeholder_cigar,
+            Cigar::from(vec![
+                Op::new(Kind::SoftClip, 70000),
+                Op::new(Kind::Skip, 35000),
+            ])
+        );
+
+        let data = sam::record::Data::try_from(record.data())?;
+        assert!(data.contains_key(&Tag::Cigar));
+
+        let actual = record.try_into_sam_record(&reference_sequences)?;
+        assert_eq!(actual.cigar(), &cigar);
+        assert!(!actual.data().contains_key(&Tag::Cigar));
+        assert_eq!(actual, sam_record);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_calculate_bin() -> io::Result<()> {
+        assert_eq!(calculate_bin(0, 10)?, 4681);
+        assert_eq!(calculate_bin(16383, 2)?, 585);
+
+        // placed unmapped records
+        assert_eq!(calculate_bin(7, 0)?, 4681);
+        assert_eq!(calculate_bin(16384, 0)?, 4682);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_data_with_char_value() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::record::data::{field::Value, Field};
+
+        fn t(buf: &mut Vec<u8>, data: &Data, expected: &[u8]) -> io::Result<()> {
+            buf.clear();
+            write_data(buf, data)?;
+            assert_eq!(&buf[..], expected);
+            Ok(())
+        }
+
+        let mut buf = Vec::new();
+
+        t(&mut buf, &"XA:A:!".parse()?, b"XAA!")?;
+        t(&mut buf, &"XA:A:~".parse()?, b"XAA~")?;
+
+        let data = Data::try_from(vec![Field::new("XA".parse()?, Value::Char('é'))])?;
+
+        buf.clear();
+        assert!(matches!(
+            write_data(&mut buf, &data),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_data_int_value() -> io::Result<()> {
         fn t(buf: &mut Vec<u8>, n: i64, expected: &[u8]) -> io::Result<()> {
@@ -551,7 +956,7 @@ mod tests {
     }
 
     #[test]
-    fn test_write_reference_sequence_id() -> Result<(), Box<dyn std::error::Error>> {
+    fn test_get_reference_sequence_id() -> Result<(), Box<dyn std::error::Error>> {
         use sam::header::ReferenceSequence;
 
         let reference_sequences = vec![("sq0", 8), ("sq1", 13)]
@@ -559,25 +964,17 @@ mod tests {
             .map(|(name, len)| ReferenceSequence::new(name, len).map(|rs| (name.into(), rs)))
             .collect::<Result<_, _>>()?;
 
-        let mut buf = Vec::new();
-
-        buf.clear();
         let reference_sequence_name = "sq0".parse()?;
-        write_reference_sequence_id(
-            &mut buf,
-            &reference_sequences,
-            Some(&reference_sequence_name),
-        )?;
-        assert_eq!(buf, [0x00, 0x00, 0x00, 0x00]);
+        assert_eq!(
+            get_reference_sequence_id(&reference_sequences, Some(&reference_sequence_name))?,
+            0
+        );
 
-        buf.clear();
-        write_reference_sequence_id(&mut buf, &reference_sequences, None)?;
-        assert_eq!(buf, [0xff, 0xff, 0xff, 0xff]);
+        assert_eq!(get_reference_sequence_id(&reference_sequences, None)?, -1);
 
-        buf.clear();
         let reference_sequence_name = "sq2".parse()?;
         assert!(matches!(
-            write_reference_sequence_id(&mut buf, &reference_sequences, Some(&reference_sequence_name)),
+            get_reference_sequence_id(&reference_sequences, Some(&reference_sequence_name)),
             Err(ref e) if e.kind() == io::ErrorKind::InvalidInput,
         ));
 
@@ -599,15 +996,4 @@ mod tests {
 
         Ok(())
     }
-
-    #[test]
-    fn test_region_to_bin() {
-        // § 5.3 C source code for computing bin number and overlapping bins (2021-01-07)
-        // [-1, 0]
-        assert_eq!(region_to_bin(-1, 0), 4680);
-        // [8, 13]
-        assert_eq!(region_to_bin(7, 13), 4681);
-        // [63245986, 63245986]
-        assert_eq!(region_to_bin(63245985, 63255986), 8541);
-    }
 }
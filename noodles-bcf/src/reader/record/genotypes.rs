@@ -306,12 +306,7 @@ where
 
     for _ in 0..sample_count {
         let value = reader.read_f32::<LittleEndian>().map(Float::from)?;
-
-        match value {
-            Float::Value(n) => values.push(Some(Value::Float(n))),
-            Float::Missing => values.push(None),
-            _ => todo!("unhandled f32 value: {:?}", value),
-        }
+        values.push(value.value().map(Value::Float));
     }
 
     Ok(values)
@@ -336,9 +331,9 @@ where
             .map(Float::from)
             .filter_map(|value| match value {
                 Float::Value(n) => Some(Some(n)),
-                Float::Missing => Some(None),
+                // Reserved values are treated as missing, as they are for a single value.
+                Float::Missing | Float::Reserved(_) => Some(None),
                 Float::EndOfVector => None,
-                _ => todo!("unhandled f32 array value: {:?}", value),
             })
             .collect();
 
@@ -582,12 +577,13 @@ mod tests {
             0x15, // Some(Type::Float(1))
             0x00, 0x00, 0x00, 0x00, // Some(0.0)
             0x00, 0x00, 0x80, 0x3f, // Some(1.0)
-            0x01, 0x00, 0x80, 0x7f, // None
+            0x01, 0x00, 0x80, 0x7f, // None (missing)
+            0x02, 0x00, 0x80, 0x7f, // None (end of vector)
         ];
         let mut reader = &data[..];
 
-        let actual = read_genotype_field_values(&mut reader, 3)?;
-        let expected = vec![Some(Value::Float(0.0)), Some(Value::Float(1.0)), None];
+        let actual = read_genotype_field_values(&mut reader, 4)?;
+        let expected = vec![Some(Value::Float(0.0)), Some(Value::Float(1.0)), None, None];
 
         assert_eq!(actual, expected);
 
@@ -602,15 +598,20 @@ mod tests {
             0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x80, 0x7f, // Some([Some(0.0), None])
             0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x80, 0x7f, // Some([Some(0.0)])
             0x01, 0x00, 0x80, 0x7f, 0x02, 0x00, 0x80, 0x7f, // None
+            0x00, 0x00, 0x80, 0x3f, 0x03, 0x00, 0x80,
+            0x7f, // Some([Some(1.0), None]) (reserved)
+            0x07, 0x00, 0x80, 0x7f, 0x02, 0x00, 0x80, 0x7f, // None (reserved)
         ];
         let mut reader = &data[..];
 
-        let actual = read_genotype_field_values(&mut reader, 4)?;
+        let actual = read_genotype_field_values(&mut reader, 6)?;
         let expected = vec![
             Some(Value::FloatArray(vec![Some(0.0), Some(1.0)])),
             Some(Value::FloatArray(vec![Some(0.0), None])),
             Some(Value::FloatArray(vec![Some(0.0)])),
             None,
+            Some(Value::FloatArray(vec![Some(1.0), None])),
+            None,
         ];
 
         assert_eq!(actual, expected);
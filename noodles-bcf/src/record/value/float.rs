@@ -7,12 +7,30 @@ pub enum Float {
     Reserved(f32),
 }
 
+// § 6.3.3 Type encoding (2021-05-13)
+const MISSING: u32 = 0x7f800001;
+const END_OF_VECTOR: u32 = 0x7f800002;
+
+impl Float {
+    /// Returns the value if it is not missing data.
+    ///
+    /// Both the missing (`0x7f800001`) and end-of-vector (`0x7f800002`) bit patterns, as well as
+    /// reserved values, are treated as missing data, i.e., `.` in VCF. This is distinct from a NaN
+    /// in the data, which is returned as a value.
+    pub fn value(&self) -> Option<f32> {
+        match self {
+            Self::Value(n) => Some(*n),
+            Self::Missing | Self::EndOfVector | Self::Reserved(_) => None,
+        }
+    }
+}
+
 impl From<f32> for Float {
     fn from(value: f32) -> Self {
         match value.to_bits() {
             0x7fc00000 => Self::Value(f32::NAN),
-            0x7f800001 => Self::Missing,
-            0x7f800002 => Self::EndOfVector,
+            MISSING => Self::Missing,
+            END_OF_VECTOR => Self::EndOfVector,
             0x7f800003..=0x7f800007 => Self::Reserved(value),
             _ => Self::Value(value),
         }
@@ -22,8 +40,8 @@ impl From<f32> for Float {
 impl From<Float> for f32 {
     fn from(value: Float) -> Self {
         match value {
-            Float::Missing => f32::from_bits(0x7f800001),
-            Float::EndOfVector => f32::from_bits(0x7f800002),
+            Float::Missing => f32::from_bits(MISSING),
+            Float::EndOfVector => f32::from_bits(END_OF_VECTOR),
             Float::Value(n) | Float::Reserved(n) => n,
         }
     }
@@ -33,6 +51,19 @@ impl From<Float> for f32 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_value() {
+        assert_eq!(Float::from(0.0).value(), Some(0.0));
+        assert!(matches!(Float::from(f32::NAN).value(), Some(n) if n.is_nan()));
+        assert!(matches!(
+            Float::from(f32::from_bits(0x7fc00001)).value(),
+            Some(n) if n.is_nan()
+        ));
+        assert!(Float::from(f32::from_bits(0x7f800001)).value().is_none());
+        assert!(Float::from(f32::from_bits(0x7f800002)).value().is_none());
+        assert!(Float::from(f32::from_bits(0x7f800003)).value().is_none());
+    }
+
     #[test]
     fn test_from_f32_for_float() {
         assert_eq!(Float::from(0.0), Float::Value(0.0));
@@ -1,12 +1,10 @@
 use std::io;
 
 use byteorder::{ByteOrder, LittleEndian};
-use bytes::{BufMut, BytesMut};
+use bytes::BytesMut;
 use tokio_util::codec::{Decoder, Encoder};
 
-use crate::{gz, BGZF_HEADER_SIZE};
-
-use super::writer::deflate::GzData;
+use crate::BGZF_HEADER_SIZE;
 
 pub struct BlockCodec;
 
@@ -35,57 +33,15 @@ impl Decoder for BlockCodec {
     }
 }
 
-impl Encoder<GzData> for BlockCodec {
+impl Encoder<Vec<u8>> for BlockCodec {
     type Error = io::Error;
 
-    fn encode(
-        &mut self,
-        (cdata, crc32, r#isize): GzData,
-        dst: &mut BytesMut,
-    ) -> Result<(), Self::Error> {
-        let cdata_len = cdata.len();
-        let block_size = BGZF_HEADER_SIZE + cdata_len + gz::TRAILER_SIZE;
-
-        dst.reserve(block_size);
-
-        put_header(dst, block_size);
-        dst.extend_from_slice(&cdata);
-        put_trailer(dst, crc32, r#isize);
-
+    fn encode(&mut self, block: Vec<u8>, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        dst.extend_from_slice(&block);
         Ok(())
     }
 }
 
-fn put_header(dst: &mut BytesMut, block_size: usize) {
-    const BGZF_FLG: u8 = 0x04; // FEXTRA
-    const BGZF_XFL: u8 = 0x00; // none
-    const BGZF_XLEN: u16 = 6;
-
-    const BGZF_SI1: u8 = 0x42;
-    const BGZF_SI2: u8 = 0x43;
-    const BGZF_SLEN: u16 = 2;
-
-    dst.extend_from_slice(&gz::MAGIC_NUMBER);
-    dst.put_u8(gz::CompressionMethod::Deflate as u8);
-    dst.put_u8(BGZF_FLG);
-    dst.put_u32_le(gz::MTIME_NONE);
-    dst.put_u8(BGZF_XFL);
-    dst.put_u8(gz::OperatingSystem::Unknown as u8);
-    dst.put_u16_le(BGZF_XLEN);
-
-    dst.put_u8(BGZF_SI1);
-    dst.put_u8(BGZF_SI2);
-    dst.put_u16_le(BGZF_SLEN);
-
-    let bsize = (block_size - 1) as u16;
-    dst.put_u16_le(bsize);
-}
-
-fn put_trailer(dst: &mut BytesMut, checksum: u32, uncompressed_size: u32) {
-    dst.put_u32_le(checksum);
-    dst.put_u32_le(uncompressed_size);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -123,16 +79,14 @@ mod tests {
 
     #[test]
     fn test_encode() -> io::Result<()> {
-        let mut encoder = BlockCodec;
+        use flate2::Compression;
 
-        let cdata = vec![0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00];
-        let crc32 = 0x802a58a1;
-        let r#isize = 7;
-        let data = (cdata, crc32, r#isize);
+        let mut encoder = BlockCodec;
 
+        let block = crate::block::deflate(b"noodles", Compression::default())?;
         let mut dst = BytesMut::new();
 
-        encoder.encode(data, &mut dst)?;
+        encoder.encode(block, &mut dst)?;
 
         assert_eq!(&dst[..], BLOCK);
 
@@ -13,7 +13,10 @@ use futures::{ready, stream::TryBuffered, Stream, TryStreamExt};
 use pin_project_lite::pin_project;
 use tokio::io::{AsyncBufRead, AsyncRead, AsyncSeek, ReadBuf};
 
-use crate::{Block, VirtualPosition};
+use crate::{
+    reader::{is_eof_block, MissingEofPolicy},
+    Block, VirtualPosition,
+};
 
 use self::inflater::Inflater;
 
@@ -28,6 +31,8 @@ pin_project! {
         block: Block,
         position: u64,
         worker_count: usize,
+        missing_eof_policy: MissingEofPolicy,
+        is_eof_block_missing: bool,
     }
 }
 
@@ -77,6 +82,37 @@ where
     pub fn virtual_position(&self) -> VirtualPosition {
         self.block.virtual_position()
     }
+
+    /// Returns the policy used when the stream ends without an EOF block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::{self as bgzf, reader::MissingEofPolicy};
+    /// let data = [];
+    /// let reader = bgzf::AsyncReader::new(&data[..]);
+    /// assert_eq!(reader.missing_eof_policy(), MissingEofPolicy::Warn);
+    /// ```
+    pub fn missing_eof_policy(&self) -> MissingEofPolicy {
+        self.missing_eof_policy
+    }
+
+    /// Returns whether the stream ended without an EOF block.
+    ///
+    /// This is only set when the missing EOF policy is [`MissingEofPolicy::Warn`]. It is checked
+    /// after the reader reaches the end of the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let reader = bgzf::AsyncReader::new(&data[..]);
+    /// assert!(!reader.is_eof_block_missing());
+    /// ```
+    pub fn is_eof_block_missing(&self) -> bool {
+        self.is_eof_block_missing
+    }
 }
 
 impl<R> Reader<R>
@@ -164,7 +200,22 @@ where
                     *this.block = block;
                 }
                 Some(Err(e)) => return Poll::Ready(Err(e)),
-                None => return Poll::Ready(Ok(&[])),
+                None => {
+                    if !is_eof_block(this.block) {
+                        match this.missing_eof_policy {
+                            MissingEofPolicy::Error => {
+                                return Poll::Ready(Err(io::Error::new(
+                                    io::ErrorKind::UnexpectedEof,
+                                    "missing BGZF EOF block",
+                                )))
+                            }
+                            MissingEofPolicy::Warn => *this.is_eof_block_missing = true,
+                            MissingEofPolicy::Ignore => {}
+                        }
+                    }
+
+                    return Poll::Ready(Ok(&[]));
+                }
             }
         }
 
@@ -220,4 +271,57 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_read_with_missing_eof_block() -> io::Result<()> {
+        use crate::{writer::BGZF_EOF, Writer};
+
+        let data = {
+            let mut writer = Writer::new(Vec::new());
+            std::io::Write::write_all(&mut writer, b"noodles")?;
+            let mut data = writer.finish()?;
+            data.truncate(data.len() - BGZF_EOF.len());
+            data
+        };
+
+        let mut buf = Vec::new();
+
+        let mut reader = Reader::builder(&data[..])
+            .set_missing_eof_policy(MissingEofPolicy::Error)
+            .build();
+        assert!(matches!(
+            reader.read_to_end(&mut buf).await,
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        buf.clear();
+        let mut reader = Reader::builder(&data[..])
+            .set_missing_eof_policy(MissingEofPolicy::Warn)
+            .build();
+        reader.read_to_end(&mut buf).await?;
+        assert_eq!(buf, b"noodles");
+        assert!(reader.is_eof_block_missing());
+
+        buf.clear();
+        let mut reader = Reader::builder(&data[..])
+            .set_missing_eof_policy(MissingEofPolicy::Ignore)
+            .build();
+        reader.read_to_end(&mut buf).await?;
+        assert_eq!(buf, b"noodles");
+        assert!(!reader.is_eof_block_missing());
+
+        let mut writer = Writer::new(Vec::new());
+        std::io::Write::write_all(&mut writer, b"noodles")?;
+        let data = writer.finish()?;
+
+        buf.clear();
+        let mut reader = Reader::builder(&data[..])
+            .set_missing_eof_policy(MissingEofPolicy::Error)
+            .build();
+        reader.read_to_end(&mut buf).await?;
+        assert_eq!(buf, b"noodles");
+        assert!(!reader.is_eof_block_missing());
+
+        Ok(())
+    }
 }
@@ -2,12 +2,13 @@ use futures::TryStreamExt;
 use tokio::io::AsyncRead;
 
 use super::{Inflater, Reader};
-use crate::Block;
+use crate::{reader::MissingEofPolicy, Block};
 
 /// An async BGZF reader builder.
 pub struct Builder<R> {
     inner: R,
     worker_count: Option<usize>,
+    missing_eof_policy: MissingEofPolicy,
 }
 
 impl<R> Builder<R>
@@ -18,9 +19,27 @@ where
         Self {
             inner,
             worker_count: None,
+            missing_eof_policy: MissingEofPolicy::default(),
         }
     }
 
+    /// Sets the policy used when the stream ends without an EOF block.
+    ///
+    /// By default, this is [`MissingEofPolicy::Warn`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::{self as bgzf, reader::MissingEofPolicy};
+    /// let data = [];
+    /// let builder = bgzf::AsyncReader::builder(&data[..])
+    ///     .set_missing_eof_policy(MissingEofPolicy::Error);
+    /// ```
+    pub fn set_missing_eof_policy(mut self, missing_eof_policy: MissingEofPolicy) -> Self {
+        self.missing_eof_policy = missing_eof_policy;
+        self
+    }
+
     /// Sets a worker count.
     ///
     /// By default, the worker count is set to the number of available logical CPUs.
@@ -54,6 +73,8 @@ where
             block: Block::default(),
             position: 0,
             worker_count,
+            missing_eof_policy: self.missing_eof_policy,
+            is_eof_block_missing: false,
         }
     }
 }
@@ -6,17 +6,18 @@ pub use self::builder::Builder;
 
 use std::{
     cmp,
+    convert::TryFrom,
     pin::Pin,
     task::{Context, Poll},
 };
 
 use bytes::{Buf, Bytes, BytesMut};
 use flate2::Compression;
-use futures::{ready, sink::Buffer, Sink};
+use futures::{ready, sink::Buffer, Sink, SinkExt};
 use pin_project_lite::pin_project;
-use tokio::io::{self, AsyncWrite};
+use tokio::io::{self, AsyncWrite, AsyncWriteExt};
 
-use crate::block;
+use crate::{writer::MAX_BUF_SIZE, VirtualPosition};
 
 use self::{deflate::Deflate, deflater::Deflater};
 
@@ -73,6 +74,42 @@ where
     pub fn into_inner(self) -> W {
         self.sink.into_inner().into_inner()
     }
+
+    /// Ends the current block, even if it is not full.
+    ///
+    /// The buffered data is compressed and written as a block, and the underlying writer is
+    /// flushed. This returns the virtual position of the start of the next block, which can be
+    /// used to seek directly to the data written after this call.
+    ///
+    /// If there is no buffered data, no block is written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_bgzf as bgzf;
+    /// use tokio::io::AsyncWriteExt;
+    ///
+    /// let mut writer = bgzf::AsyncWriter::new(Vec::new());
+    /// writer.write_all(b"noodles").await?;
+    ///
+    /// let position = writer.flush_block().await?;
+    /// assert_eq!(position, bgzf::VirtualPosition::from(35 << 16));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn flush_block(&mut self) -> io::Result<VirtualPosition> {
+        self.flush().await?;
+        self.sink.flush().await?;
+
+        let cpos = self.sink.get_ref().position();
+
+        VirtualPosition::try_from((cpos, 0))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
 }
 
 impl<W> AsyncWrite for Writer<W>
@@ -84,16 +121,13 @@ where
         cx: &mut Context<'_>,
         buf: &[u8],
     ) -> Poll<Result<usize, io::Error>> {
-        if self.buf.len() >= block::MAX_UNCOMPRESSED_DATA_LENGTH {
+        if self.buf.len() >= MAX_BUF_SIZE {
             if let Err(e) = ready!(self.as_mut().poll_flush(cx)) {
                 return Poll::Ready(Err(e));
             }
         }
 
-        let n = cmp::min(
-            block::MAX_UNCOMPRESSED_DATA_LENGTH - self.buf.len(),
-            buf.len(),
-        );
+        let n = cmp::min(MAX_BUF_SIZE - self.buf.len(), buf.len());
 
         self.as_mut().buf.extend_from_slice(&buf[..n]);
 
@@ -170,4 +204,66 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_write_with_incompressible_data() -> io::Result<()> {
+        use std::io::Read;
+
+        // xorshift32
+        let mut state = 0x2545f491u32;
+        let data: Vec<u8> = (0..(1 << 17))
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state as u8
+            })
+            .collect();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(&data).await?;
+        writer.shutdown().await?;
+
+        let compressed_data = writer.into_inner();
+
+        let mut reader = crate::Reader::new(&compressed_data[..]);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, data);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_flush_block() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::BufRead;
+
+        let mut writer = Writer::new(Vec::new());
+
+        writer.write_all(b"noodles").await?;
+        let position = writer.flush_block().await?;
+        assert_eq!(position, VirtualPosition::try_from((35, 0))?);
+
+        // No data is buffered, so no block is written.
+        assert_eq!(writer.flush_block().await?, position);
+
+        writer.write_all(b"bgzf").await?;
+        writer.shutdown().await?;
+
+        let data = writer.into_inner();
+        let mut reader = crate::Reader::new(std::io::Cursor::new(data));
+
+        assert_eq!(reader.fill_buf()?, b"noodles");
+        reader.consume(7);
+
+        assert_eq!(reader.fill_buf()?, b"bgzf");
+        reader.consume(4);
+
+        assert!(reader.fill_buf()?.is_empty());
+
+        reader.seek(position)?;
+        assert_eq!(reader.fill_buf()?, b"bgzf");
+
+        Ok(())
+    }
 }
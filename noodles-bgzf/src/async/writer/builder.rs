@@ -5,7 +5,10 @@ use tokio::io::AsyncWrite;
 use tokio_util::codec::FramedWrite;
 
 use super::{Deflater, Writer};
-use crate::{block, r#async::BlockCodec, writer::BGZF_EOF};
+use crate::{
+    r#async::BlockCodec,
+    writer::{BGZF_EOF, MAX_BUF_SIZE},
+};
 
 /// An async BGZF writer builder.
 #[derive(Debug)]
@@ -74,7 +77,7 @@ where
 
         Writer {
             sink: Deflater::new(FramedWrite::new(self.inner, BlockCodec)).buffer(worker_count),
-            buf: BytesMut::with_capacity(block::MAX_UNCOMPRESSED_DATA_LENGTH),
+            buf: BytesMut::with_capacity(MAX_BUF_SIZE),
             eof_buf: Bytes::from_static(BGZF_EOF),
             compression_level,
         }
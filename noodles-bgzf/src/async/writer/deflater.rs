@@ -20,6 +20,7 @@ pin_project! {
         sink: FramedWrite<W, BlockCodec>,
         #[pin]
         state: Option<Deflate>,
+        position: u64,
     }
 }
 
@@ -28,7 +29,16 @@ where
     W: AsyncWrite,
 {
     pub fn new(sink: FramedWrite<W, BlockCodec>) -> Self {
-        Self { sink, state: None }
+        Self {
+            sink,
+            state: None,
+            position: 0,
+        }
+    }
+
+    // Returns the number of compressed bytes sent to the underlying sink.
+    pub fn position(&self) -> u64 {
+        self.position
     }
 
     pub fn get_mut(&mut self) -> &mut W {
@@ -48,6 +58,7 @@ where
         };
 
         this.state.set(None);
+        *this.position += data.len() as u64;
         this.sink.start_send(data)?;
 
         Poll::Ready(Ok(()))
@@ -1,12 +1,28 @@
+//! BGZF block.
+
 use std::{
     convert::TryFrom,
-    io::{self, Read},
+    io::{self, Read, Write},
 };
 
-use super::{virtual_position, VirtualPosition};
+use byteorder::{LittleEndian, WriteBytesExt};
+use flate2::{write::DeflateEncoder, Compression, Crc};
+
+use super::{gz, virtual_position, VirtualPosition, BGZF_HEADER_SIZE};
 
 pub(crate) const MAX_UNCOMPRESSED_DATA_LENGTH: usize = 1 << 16; // bytes
 
+// BSIZE is the total block size minus 1 and is stored as a u16.
+const MAX_BLOCK_SIZE: usize = 1 << 16; // bytes
+
+const BGZF_FLG: u8 = 0x04; // FEXTRA
+const BGZF_XFL: u8 = 0x00; // none
+const BGZF_XLEN: u16 = 6;
+
+const BGZF_SI1: u8 = 0x42;
+const BGZF_SI2: u8 = 0x43;
+const BGZF_SLEN: u16 = 2;
+
 /// A BGZF block.
 ///
 /// A BGZF block is a gzip stream less than 64 KiB and contains an extra field describing the size
@@ -20,6 +36,11 @@ pub struct Block {
 }
 
 impl Block {
+    /// Returns the uncompressed data of this block.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
     /// Returns a mutable reference to the uncompressed data of this block.
     pub fn data_mut(&mut self) -> &mut Vec<u8> {
         &mut self.data
@@ -43,7 +64,6 @@ impl Block {
     }
 
     /// Returns the compressed data length.
-    #[cfg(feature = "async")]
     pub fn clen(&self) -> u64 {
         self.clen
     }
@@ -91,10 +111,132 @@ impl Read for Block {
     }
 }
 
+/// Compresses data into a complete BGZF block.
+///
+/// The returned block includes the gzip header with the BGZF extra field (`BC`), the compressed
+/// data, and the gzip trailer (CRC32 and ISIZE). This is the same block encoder used by the BGZF
+/// writers and can be used to compress blocks independently, e.g., in parallel.
+///
+/// The input is limited to 64 KiB, and the resulting block must not exceed 64 KiB.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use flate2::Compression;
+/// use noodles_bgzf as bgzf;
+/// let block = bgzf::block::deflate(b"noodles", Compression::default())?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn deflate(data: &[u8], compression_level: Compression) -> io::Result<Vec<u8>> {
+    if data.len() > MAX_UNCOMPRESSED_DATA_LENGTH {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "invalid data length: expected <= {}, got {}",
+                MAX_UNCOMPRESSED_DATA_LENGTH,
+                data.len()
+            ),
+        ));
+    }
+
+    let mut encoder = DeflateEncoder::new(Vec::new(), compression_level);
+    encoder.write_all(data)?;
+    let cdata = encoder.finish()?;
+
+    let mut crc = Crc::new();
+    crc.update(data);
+
+    let block_size = BGZF_HEADER_SIZE + cdata.len() + gz::TRAILER_SIZE;
+
+    if block_size > MAX_BLOCK_SIZE {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "invalid block size: expected <= {}, got {}",
+                MAX_BLOCK_SIZE, block_size
+            ),
+        ));
+    }
+
+    let mut block = Vec::with_capacity(block_size);
+    write_header(&mut block, block_size)?;
+    block.extend_from_slice(&cdata);
+    write_trailer(&mut block, crc.sum(), crc.amount())?;
+
+    Ok(block)
+}
+
+fn write_header<W>(writer: &mut W, block_size: usize) -> io::Result<()>
+where
+    W: Write,
+{
+    writer.write_all(&gz::MAGIC_NUMBER)?;
+    writer.write_u8(gz::CompressionMethod::Deflate as u8)?;
+    writer.write_u8(BGZF_FLG)?;
+    writer.write_u32::<LittleEndian>(gz::MTIME_NONE)?;
+    writer.write_u8(BGZF_XFL)?;
+    writer.write_u8(gz::OperatingSystem::Unknown as u8)?;
+    writer.write_u16::<LittleEndian>(BGZF_XLEN)?;
+
+    writer.write_u8(BGZF_SI1)?;
+    writer.write_u8(BGZF_SI2)?;
+    writer.write_u16::<LittleEndian>(BGZF_SLEN)?;
+
+    let bsize = (block_size - 1) as u16;
+    writer.write_u16::<LittleEndian>(bsize)?;
+
+    Ok(())
+}
+
+fn write_trailer<W>(writer: &mut W, checksum: u32, uncompressed_size: u32) -> io::Result<()>
+where
+    W: Write,
+{
+    writer.write_u32::<LittleEndian>(checksum)?;
+    writer.write_u32::<LittleEndian>(uncompressed_size)?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_deflate() -> io::Result<()> {
+        use flate2::read::GzDecoder;
+
+        let data = b"noodles-bgzf";
+        let block = deflate(data, Compression::default())?;
+
+        // BSIZE
+        let bsize = u16::from_le_bytes([block[16], block[17]]);
+        assert_eq!(usize::from(bsize) + 1, block.len());
+
+        // ISIZE
+        let isize_start = block.len() - 4;
+        let isize = u32::from_le_bytes([
+            block[isize_start],
+            block[isize_start + 1],
+            block[isize_start + 2],
+            block[isize_start + 3],
+        ]);
+        assert_eq!(isize as usize, data.len());
+
+        let mut decoder = GzDecoder::new(&block[..]);
+        let mut buf = Vec::new();
+        decoder.read_to_end(&mut buf)?;
+        assert_eq!(buf, data);
+
+        let data = vec![0; MAX_UNCOMPRESSED_DATA_LENGTH + 1];
+        assert!(matches!(
+            deflate(&data, Compression::default()),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_set_cpos() {
         let mut block = Block::default();
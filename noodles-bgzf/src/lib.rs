@@ -37,13 +37,18 @@
 #[cfg(feature = "async")]
 mod r#async;
 
-mod block;
+pub mod block;
 mod gz;
-mod reader;
+mod multithreaded_writer;
+mod open;
+pub mod reader;
 pub mod virtual_position;
-mod writer;
+pub mod writer;
 
-pub use self::{reader::Reader, virtual_position::VirtualPosition, writer::Writer};
+pub use self::{
+    multithreaded_writer::MultithreadedWriter, open::open, reader::Reader,
+    virtual_position::VirtualPosition, writer::Writer,
+};
 
 #[cfg(feature = "async")]
 pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
@@ -0,0 +1,410 @@
+use std::{
+    cmp,
+    io::{self, Write},
+    mem,
+    num::NonZeroUsize,
+    panic,
+    sync::{
+        mpsc::{self, Receiver, SyncSender},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+};
+
+use flate2::Compression;
+
+use super::{
+    block,
+    writer::{BGZF_EOF, MAX_BUF_SIZE},
+};
+
+type BufferedTx = SyncSender<io::Result<Vec<u8>>>;
+type BufferedRx = Receiver<io::Result<Vec<u8>>>;
+type DeflateTx = SyncSender<(Vec<u8>, BufferedTx)>;
+type DeflateRx = Receiver<(Vec<u8>, BufferedTx)>;
+type WriteTx = SyncSender<BufferedRx>;
+type WriteRx = Receiver<BufferedRx>;
+
+/// A multithreaded BGZF writer.
+///
+/// This is like [`crate::Writer`], but blocks are compressed in parallel by a pool of worker
+/// threads. The compressed blocks are written to the underlying writer in order on a separate
+/// writer thread, so the output is the same as the single-threaded writer.
+///
+/// # Examples
+///
+/// ```
+/// # use std::{io::{self, Write}, num::NonZeroUsize};
+/// use noodles_bgzf as bgzf;
+///
+/// let worker_count = NonZeroUsize::new(4).unwrap();
+/// let mut writer = bgzf::MultithreadedWriter::new(Vec::new(), worker_count);
+/// writer.write_all(b"noodles-bgzf")?;
+///
+/// let data = writer.finish()?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub struct MultithreadedWriter<W>
+where
+    W: Write + Send + 'static,
+{
+    buf: Vec<u8>,
+    compression_level: Compression,
+    deflate_tx: Option<DeflateTx>,
+    deflater_handles: Vec<JoinHandle<()>>,
+    write_tx: Option<WriteTx>,
+    writer_handle: Option<JoinHandle<io::Result<W>>>,
+    inner: Option<W>,
+}
+
+impl<W> MultithreadedWriter<W>
+where
+    W: Write + Send + 'static,
+{
+    /// Creates a multithreaded BGZF writer with a default compression level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::num::NonZeroUsize;
+    /// use noodles_bgzf as bgzf;
+    /// let writer = bgzf::MultithreadedWriter::new(Vec::new(), NonZeroUsize::new(4).unwrap());
+    /// ```
+    pub fn new(inner: W, worker_count: NonZeroUsize) -> Self {
+        Self::with_compression_level(inner, worker_count, Compression::default())
+    }
+
+    /// Creates a multithreaded BGZF writer with the given compression level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::num::NonZeroUsize;
+    /// use flate2::Compression;
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let writer = bgzf::MultithreadedWriter::with_compression_level(
+    ///     Vec::new(),
+    ///     NonZeroUsize::new(4).unwrap(),
+    ///     Compression::best(),
+    /// );
+    /// ```
+    pub fn with_compression_level(
+        inner: W,
+        worker_count: NonZeroUsize,
+        compression_level: Compression,
+    ) -> Self {
+        let worker_count = worker_count.get();
+
+        let (write_tx, write_rx) = mpsc::sync_channel(worker_count);
+        let writer_handle = spawn_writer(inner, write_rx);
+
+        let (deflate_tx, deflate_rx) = mpsc::sync_channel(worker_count);
+        let deflater_handles = spawn_deflaters(compression_level, worker_count, deflate_rx);
+
+        Self {
+            buf: Vec::with_capacity(MAX_BUF_SIZE),
+            compression_level,
+            deflate_tx: Some(deflate_tx),
+            deflater_handles,
+            write_tx: Some(write_tx),
+            writer_handle: Some(writer_handle),
+            inner: None,
+        }
+    }
+
+    /// Returns the compression level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::num::NonZeroUsize;
+    /// use flate2::Compression;
+    /// use noodles_bgzf as bgzf;
+    /// let writer = bgzf::MultithreadedWriter::new(Vec::new(), NonZeroUsize::new(1).unwrap());
+    /// assert_eq!(writer.compression_level(), Compression::default());
+    /// ```
+    pub fn compression_level(&self) -> Compression {
+        self.compression_level
+    }
+
+    /// Attempts to finish the output stream by flushing any remaining buffers.
+    ///
+    /// This waits for all blocks to be written and then appends the final BGZF EOF block. The
+    /// worker and writer threads are stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::{io::{self, Write}, num::NonZeroUsize};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::MultithreadedWriter::new(Vec::new(), NonZeroUsize::new(4).unwrap());
+    /// writer.write_all(b"noodles-bgzf")?;
+    ///
+    /// writer.try_finish()?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn try_finish(&mut self) -> io::Result<()> {
+        if self.writer_handle.is_none() {
+            return Ok(());
+        }
+
+        self.flush()?;
+        self.join_threads()
+    }
+
+    /// Returns the underlying writer after finishing the output stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::{io::{self, Write}, num::NonZeroUsize};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::MultithreadedWriter::new(Vec::new(), NonZeroUsize::new(4).unwrap());
+    /// writer.write_all(b"noodles-bgzf")?;
+    ///
+    /// let data = writer.finish()?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn finish(mut self) -> io::Result<W> {
+        self.try_finish()?;
+        self.inner.take().ok_or_else(writer_closed_error)
+    }
+
+    fn join_threads(&mut self) -> io::Result<()> {
+        self.deflate_tx.take();
+
+        for handle in self.deflater_handles.drain(..) {
+            if let Err(e) = handle.join() {
+                panic::resume_unwind(e);
+            }
+        }
+
+        self.write_tx.take();
+
+        if let Some(handle) = self.writer_handle.take() {
+            let inner = handle.join().unwrap_or_else(|e| panic::resume_unwind(e))?;
+            self.inner = Some(inner);
+        }
+
+        Ok(())
+    }
+
+    fn send_block(&mut self) -> io::Result<()> {
+        let (deflate_tx, write_tx) = match (self.deflate_tx.as_ref(), self.write_tx.as_ref()) {
+            (Some(deflate_tx), Some(write_tx)) => (deflate_tx, write_tx),
+            _ => return Err(writer_closed_error()),
+        };
+
+        let data = mem::replace(&mut self.buf, Vec::with_capacity(MAX_BUF_SIZE));
+
+        // The receiver is queued for the writer thread before the data is sent to the workers,
+        // which keeps the blocks in order regardless of which worker finishes first.
+        let (buffered_tx, buffered_rx) = mpsc::sync_channel(1);
+
+        let is_sent =
+            write_tx.send(buffered_rx).is_ok() && deflate_tx.send((data, buffered_tx)).is_ok();
+
+        if is_sent {
+            Ok(())
+        } else {
+            // The writer thread stopped early, so the error it stopped with is returned instead.
+            self.join_threads()?;
+            Err(writer_closed_error())
+        }
+    }
+}
+
+impl<W> Drop for MultithreadedWriter<W>
+where
+    W: Write + Send + 'static,
+{
+    fn drop(&mut self) {
+        let _ = self.try_finish();
+    }
+}
+
+impl<W> Write for MultithreadedWriter<W>
+where
+    W: Write + Send + 'static,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = cmp::min(MAX_BUF_SIZE - self.buf.len(), buf.len());
+
+        self.buf.extend_from_slice(&buf[..n]);
+
+        if self.buf.len() >= MAX_BUF_SIZE {
+            self.send_block()?;
+        }
+
+        Ok(n)
+    }
+
+    /// Sends the buffered data to be compressed as a block.
+    ///
+    /// This does not wait for the block to be written to the underlying writer.
+    fn flush(&mut self) -> io::Result<()> {
+        if self.buf.is_empty() {
+            Ok(())
+        } else {
+            self.send_block()
+        }
+    }
+}
+
+fn spawn_deflaters(
+    compression_level: Compression,
+    worker_count: usize,
+    deflate_rx: DeflateRx,
+) -> Vec<JoinHandle<()>> {
+    let deflate_rx = Arc::new(Mutex::new(deflate_rx));
+
+    (0..worker_count)
+        .map(|_| {
+            let deflate_rx = Arc::clone(&deflate_rx);
+
+            thread::spawn(move || loop {
+                let message = match deflate_rx.lock() {
+                    Ok(rx) => rx.recv(),
+                    Err(_) => return,
+                };
+
+                match message {
+                    Ok((data, buffered_tx)) => {
+                        let result = block::deflate(&data, compression_level);
+                        // The writer thread may have stopped after an error.
+                        let _ = buffered_tx.send(result);
+                    }
+                    Err(_) => return,
+                }
+            })
+        })
+        .collect()
+}
+
+fn spawn_writer<W>(mut inner: W, write_rx: WriteRx) -> JoinHandle<io::Result<W>>
+where
+    W: Write + Send + 'static,
+{
+    thread::spawn(move || {
+        for buffered_rx in write_rx {
+            let data = buffered_rx.recv().map_err(|_| {
+                io::Error::new(io::ErrorKind::BrokenPipe, "missing compressed block")
+            })??;
+
+            inner.write_all(&data)?;
+        }
+
+        inner.write_all(BGZF_EOF)?;
+        inner.flush()?;
+
+        Ok(inner)
+    })
+}
+
+fn writer_closed_error() -> io::Error {
+    io::Error::new(io::ErrorKind::BrokenPipe, "writer thread stopped")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Writer;
+
+    #[test]
+    fn test_write() -> io::Result<()> {
+        let data: Vec<u8> = (0..=u8::MAX).cycle().take(100 * MAX_BUF_SIZE).collect();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(&data)?;
+        let expected = writer.finish()?;
+
+        let worker_count = NonZeroUsize::new(4).unwrap();
+        let mut writer = MultithreadedWriter::new(Vec::new(), worker_count);
+        writer.write_all(&data)?;
+        let actual = writer.finish()?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_with_incompressible_data() -> io::Result<()> {
+        // xorshift32
+        let mut state = 0x2545f491u32;
+        let data: Vec<u8> = (0..(1 << 20))
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state as u8
+            })
+            .collect();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(&data)?;
+        let expected = writer.finish()?;
+
+        let worker_count = NonZeroUsize::new(4).unwrap();
+        let mut writer = MultithreadedWriter::new(Vec::new(), worker_count);
+        writer.write_all(&data)?;
+        let actual = writer.finish()?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_with_failing_inner_writer() {
+        struct FailingWriter;
+
+        impl Write for FailingWriter {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::Error::from(io::ErrorKind::PermissionDenied))
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let data = vec![0; 16 * MAX_BUF_SIZE];
+
+        let worker_count = NonZeroUsize::new(1).unwrap();
+        let mut writer = MultithreadedWriter::new(FailingWriter, worker_count);
+
+        assert!(matches!(
+            writer.write_all(&data).and_then(|_| writer.try_finish()),
+            Err(e) if e.kind() == io::ErrorKind::PermissionDenied
+        ));
+    }
+
+    #[test]
+    fn test_finish() -> io::Result<()> {
+        let worker_count = NonZeroUsize::new(2).unwrap();
+
+        let mut writer = MultithreadedWriter::new(Vec::new(), worker_count);
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        writer.write_all(b"bgzf")?;
+        let actual = writer.finish()?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        writer.write_all(b"bgzf")?;
+        let expected = writer.finish()?;
+
+        assert_eq!(actual, expected);
+        assert!(actual.ends_with(BGZF_EOF));
+
+        let writer = MultithreadedWriter::new(Vec::new(), worker_count);
+        assert_eq!(writer.finish()?, BGZF_EOF);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Read},
+    path::Path,
+};
+
+use flate2::read::MultiGzDecoder;
+
+use super::gz;
+
+/// Opens a file that is either gzip-compressed or uncompressed.
+///
+/// The compression is detected by the gzip magic number (`1f 8b`) at the start of the file rather
+/// than by its extension. BGZF and regular gzip streams are decompressed; all other inputs are
+/// read as is.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::io::{self, BufRead};
+/// use noodles_bgzf as bgzf;
+///
+/// let reader = bgzf::open("sample.vcf.gz")?;
+///
+/// for result in reader.lines() {
+///     let line = result?;
+///     println!("{}", line);
+/// }
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn open<P>(src: P) -> io::Result<Box<dyn BufRead>>
+where
+    P: AsRef<Path>,
+{
+    File::open(src).and_then(from_reader)
+}
+
+fn from_reader<R>(reader: R) -> io::Result<Box<dyn BufRead>>
+where
+    R: Read + 'static,
+{
+    let mut reader = BufReader::new(reader);
+
+    // `fill_buf` does not consume the buffer, so the sniffed bytes remain in the stream.
+    if is_gzip(&mut reader)? {
+        Ok(Box::new(BufReader::new(MultiGzDecoder::new(reader))))
+    } else {
+        Ok(Box::new(reader))
+    }
+}
+
+fn is_gzip<R>(reader: &mut R) -> io::Result<bool>
+where
+    R: BufRead,
+{
+    reader
+        .fill_buf()
+        .map(|buf| buf.starts_with(&gz::MAGIC_NUMBER))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::write::GzEncoder;
+
+    use super::*;
+    use crate::Writer;
+
+    const DATA: &[u8] = b"##fileformat=VCFv4.3\n#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n";
+
+    fn read_to_end(mut reader: Box<dyn BufRead>) -> io::Result<Vec<u8>> {
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        Ok(buf)
+    }
+
+    #[test]
+    fn test_from_reader() -> io::Result<()> {
+        let reader = from_reader(DATA)?;
+        assert_eq!(read_to_end(reader)?, DATA);
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(DATA)?;
+        let data = writer.finish()?;
+        let reader = from_reader(io::Cursor::new(data))?;
+        assert_eq!(read_to_end(reader)?, DATA);
+
+        let mut encoder = GzEncoder::new(Vec::new(), Default::default());
+        encoder.write_all(DATA)?;
+        let data = encoder.finish()?;
+        let reader = from_reader(io::Cursor::new(data))?;
+        assert_eq!(read_to_end(reader)?, DATA);
+
+        let reader = from_reader(&[0x1f][..])?;
+        assert_eq!(read_to_end(reader)?, [0x1f]);
+
+        let reader = from_reader(io::empty())?;
+        assert!(read_to_end(reader)?.is_empty());
+
+        Ok(())
+    }
+}
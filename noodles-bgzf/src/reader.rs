@@ -1,3 +1,11 @@
+//! BGZF reader.
+
+mod block_cache;
+mod builder;
+mod missing_eof_policy;
+
+pub use self::{builder::Builder, missing_eof_policy::MissingEofPolicy};
+
 use std::{
     cmp,
     io::{self, BufRead, Read, Seek, SeekFrom},
@@ -6,7 +14,8 @@ use std::{
 use byteorder::{ByteOrder, LittleEndian};
 use flate2::bufread::DeflateDecoder;
 
-use super::{gz, Block, VirtualPosition, BGZF_HEADER_SIZE};
+use self::block_cache::BlockCache;
+use super::{gz, writer::BGZF_EOF, Block, VirtualPosition, BGZF_HEADER_SIZE};
 
 /// A BGZF reader.
 ///
@@ -31,8 +40,12 @@ use super::{gz, Block, VirtualPosition, BGZF_HEADER_SIZE};
 pub struct Reader<R> {
     inner: R,
     position: u64,
+    uncompressed_bytes_read: u64,
     cdata: Vec<u8>,
     block: Block,
+    missing_eof_policy: MissingEofPolicy,
+    is_eof_block_missing: bool,
+    block_cache: BlockCache,
 }
 
 impl<R> Reader<R>
@@ -49,16 +62,30 @@ where
     /// let reader = bgzf::Reader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Self {
-            inner,
-            position: 0,
-            cdata: Vec::new(),
-            block: Block::default(),
-        }
+        Builder::new(inner).build()
+    }
+
+    /// Returns a BGZF reader builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::{self as bgzf, reader::MissingEofPolicy};
+    /// let data = [];
+    /// let reader = bgzf::Reader::builder(&data[..])
+    ///     .set_missing_eof_policy(MissingEofPolicy::Ignore)
+    ///     .build();
+    /// ```
+    pub fn builder(inner: R) -> Builder<R> {
+        Builder::new(inner)
     }
 
     /// Returns the current position of the stream.
     ///
+    /// This is the position in the compressed stream, i.e., the number of compressed bytes read
+    /// from the underlying reader. It can be compared to the size of the compressed file to report
+    /// progress.
+    ///
     /// # Examples
     ///
     /// ```
@@ -71,6 +98,33 @@ where
         self.position
     }
 
+    /// Returns the number of uncompressed bytes read.
+    ///
+    /// This is the total number of uncompressed bytes consumed from the reader. It is not changed
+    /// by seeking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Read};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::Writer::new(Vec::new());
+    /// io::Write::write_all(&mut writer, b"noodles")?;
+    /// let data = writer.finish()?;
+    ///
+    /// let mut reader = bgzf::Reader::new(&data[..]);
+    /// assert_eq!(reader.uncompressed_bytes_read(), 0);
+    ///
+    /// let mut buf = Vec::new();
+    /// reader.read_to_end(&mut buf)?;
+    /// assert_eq!(reader.uncompressed_bytes_read(), 7);
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn uncompressed_bytes_read(&self) -> u64 {
+        self.uncompressed_bytes_read
+    }
+
     /// Returns the current virtual position of the stream.
     ///
     /// # Examples
@@ -84,6 +138,63 @@ where
     pub fn virtual_position(&self) -> VirtualPosition {
         self.block.virtual_position()
     }
+
+    /// Returns the policy used when the stream ends without an EOF block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::{self as bgzf, reader::MissingEofPolicy};
+    /// let data = [];
+    /// let reader = bgzf::Reader::new(&data[..]);
+    /// assert_eq!(reader.missing_eof_policy(), MissingEofPolicy::Warn);
+    /// ```
+    pub fn missing_eof_policy(&self) -> MissingEofPolicy {
+        self.missing_eof_policy
+    }
+
+    /// Returns whether the stream ended without an EOF block.
+    ///
+    /// This is only set when the missing EOF policy is [`MissingEofPolicy::Warn`]. It is checked
+    /// after the reader reaches the end of the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Read};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::Writer::new(Vec::new());
+    /// io::Write::write_all(&mut writer, b"noodles")?;
+    /// let data = writer.finish()?;
+    ///
+    /// let mut reader = bgzf::Reader::new(&data[..]);
+    /// let mut buf = Vec::new();
+    /// reader.read_to_end(&mut buf)?;
+    /// assert!(!reader.is_eof_block_missing());
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn is_eof_block_missing(&self) -> bool {
+        self.is_eof_block_missing
+    }
+
+    fn handle_end_of_stream(&mut self) -> io::Result<()> {
+        if is_eof_block(&self.block) {
+            return Ok(());
+        }
+
+        match self.missing_eof_policy {
+            MissingEofPolicy::Error => Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "missing BGZF EOF block",
+            )),
+            MissingEofPolicy::Warn => {
+                self.is_eof_block_missing = true;
+                Ok(())
+            }
+            MissingEofPolicy::Ignore => Ok(()),
+        }
+    }
 }
 
 impl<R> Reader<R>
@@ -95,6 +206,10 @@ where
     /// The underlying stream's cursor is first moved the the compressed position. A block is read,
     /// decompressed, and has its own cursor moved to the uncompressed position.
     ///
+    /// If the reader has a block cache (see [`Builder::set_block_cache_size`]) and the block at
+    /// the compressed position is cached, the cached block is used instead of reading and
+    /// decompressing it again.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -108,10 +223,26 @@ where
     pub fn seek(&mut self, pos: VirtualPosition) -> io::Result<VirtualPosition> {
         let (cpos, upos) = pos.into();
 
-        self.inner.seek(SeekFrom::Start(cpos))?;
+        if let Some((clen, data)) = self.block_cache.get(cpos) {
+            self.inner.seek(SeekFrom::Start(cpos + clen))?;
+
+            let udata = self.block.data_mut();
+            udata.clear();
+            udata.extend_from_slice(data);
 
-        let block_size = read_block(&mut self.inner, &mut self.cdata, &mut self.block)?;
-        self.position = cpos + (block_size as u64);
+            self.block.set_clen(clen);
+            self.position = cpos + clen;
+        } else {
+            self.inner.seek(SeekFrom::Start(cpos))?;
+
+            let block_size = read_block(&mut self.inner, &mut self.cdata, &mut self.block)?;
+            self.position = cpos + (block_size as u64);
+
+            if block_size > 0 {
+                self.block_cache
+                    .insert(cpos, block_size as u64, self.block.data());
+            }
+        }
 
         self.block.set_cpos(cpos);
         self.block.set_upos(u32::from(upos));
@@ -143,12 +274,18 @@ where
     fn consume(&mut self, mut amt: usize) {
         amt = cmp::min(amt, crate::block::MAX_UNCOMPRESSED_DATA_LENGTH);
         let upos = cmp::min(self.block.ulen(), self.block.upos() + amt as u32);
+        self.uncompressed_bytes_read += u64::from(upos - self.block.upos());
         self.block.set_upos(upos);
     }
 
     fn fill_buf(&mut self) -> io::Result<&[u8]> {
         if self.block.is_eof() {
             let block_size = read_block(&mut self.inner, &mut self.cdata, &mut self.block)?;
+
+            if block_size == 0 {
+                self.handle_end_of_stream()?;
+            }
+
             self.block.set_cpos(self.position);
             self.position += block_size as u64;
         }
@@ -205,6 +342,10 @@ where
     decoder.read_to_end(writer)
 }
 
+pub(crate) fn is_eof_block(block: &Block) -> bool {
+    block.clen() == BGZF_EOF.len() as u64 && block.ulen() == 0
+}
+
 fn read_block<R>(reader: &mut R, cdata: &mut Vec<u8>, block: &mut Block) -> io::Result<usize>
 where
     R: Read,
@@ -254,10 +395,32 @@ where
 mod tests {
     use std::{convert::TryFrom, io::Cursor};
 
-    use crate::writer::BGZF_EOF;
-
     use super::*;
 
+    #[test]
+    fn test_uncompressed_bytes_read() -> io::Result<()> {
+        use crate::Writer;
+
+        let data: Vec<u8> = (0..=u8::MAX).cycle().take(200000).collect();
+
+        let mut writer = Writer::new(Vec::new());
+        io::Write::write_all(&mut writer, &data)?;
+        let compressed_data = writer.finish()?;
+
+        let mut reader = Reader::new(&compressed_data[..]);
+
+        let mut buf = [0; 1000];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(reader.uncompressed_bytes_read(), 1000);
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(reader.uncompressed_bytes_read(), data.len() as u64);
+        assert_eq!(reader.position(), compressed_data.len() as u64);
+
+        Ok(())
+    }
+
     #[test]
     fn test_seek() -> Result<(), Box<dyn std::error::Error>> {
         #[rustfmt::skip]
@@ -291,6 +454,125 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_seek_with_block_cache() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::Writer;
+
+        struct CountingReader<R> {
+            inner: R,
+            read_count: usize,
+        }
+
+        impl<R: Read> Read for CountingReader<R> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                self.read_count += 1;
+                self.inner.read(buf)
+            }
+        }
+
+        impl<R: Seek> Seek for CountingReader<R> {
+            fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+                self.inner.seek(pos)
+            }
+        }
+
+        let data: Vec<u8> = (0..=u8::MAX).cycle().take(200000).collect();
+
+        let mut writer = Writer::new(Vec::new());
+        io::Write::write_all(&mut writer, &data)?;
+        let compressed_data = writer.finish()?;
+
+        let inner = CountingReader {
+            inner: Cursor::new(compressed_data),
+            read_count: 0,
+        };
+
+        let mut reader = Reader::builder(inner).set_block_cache_size(2).build();
+        let mut buf = [0; 4];
+
+        reader.seek(VirtualPosition::try_from((0, 3))?)?;
+        reader.read_exact(&mut buf)?;
+        assert_eq!(buf, data[3..7]);
+
+        let read_count = reader.inner.read_count;
+
+        reader.seek(VirtualPosition::try_from((0, 8))?)?;
+        assert_eq!(reader.inner.read_count, read_count);
+        reader.read_exact(&mut buf)?;
+        assert_eq!(buf, data[8..12]);
+
+        // The next block is read from the underlying reader.
+        let mut buf = vec![0; data.len() - 12];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(buf, data[12..]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_with_missing_eof_block() -> io::Result<()> {
+        use crate::Writer;
+
+        let data = {
+            let mut writer = Writer::new(Vec::new());
+            io::Write::write_all(&mut writer, b"noodles")?;
+            let mut data = writer.finish()?;
+            data.truncate(data.len() - BGZF_EOF.len());
+            data
+        };
+
+        let mut buf = Vec::new();
+
+        let mut reader = Reader::builder(&data[..])
+            .set_missing_eof_policy(MissingEofPolicy::Error)
+            .build();
+        assert!(matches!(
+            reader.read_to_end(&mut buf),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        buf.clear();
+        let mut reader = Reader::builder(&data[..])
+            .set_missing_eof_policy(MissingEofPolicy::Warn)
+            .build();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodles");
+        assert!(reader.is_eof_block_missing());
+
+        buf.clear();
+        let mut reader = Reader::builder(&data[..])
+            .set_missing_eof_policy(MissingEofPolicy::Ignore)
+            .build();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodles");
+        assert!(!reader.is_eof_block_missing());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_with_eof_block() -> io::Result<()> {
+        use crate::Writer;
+
+        let mut writer = Writer::new(Vec::new());
+        io::Write::write_all(&mut writer, b"noodles")?;
+        let data = writer.finish()?;
+
+        let mut reader = Reader::builder(&data[..])
+            .set_missing_eof_policy(MissingEofPolicy::Error)
+            .build();
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodles");
+
+        // Reading past the EOF block checks the end of the stream.
+        assert_eq!(reader.read(&mut [0; 8])?, 0);
+        assert!(!reader.is_eof_block_missing());
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_header() -> io::Result<()> {
         let mut reader = BGZF_EOF;
@@ -0,0 +1,72 @@
+use std::collections::VecDeque;
+
+// A bounded, least recently used (LRU) cache of decompressed blocks keyed by compressed position.
+//
+// A capacity of 0 disables the cache.
+#[derive(Debug, Default)]
+pub struct BlockCache {
+    capacity: usize,
+    // (cpos, clen, udata), ordered from least to most recently used.
+    entries: VecDeque<(u64, u64, Vec<u8>)>,
+}
+
+impl BlockCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: VecDeque::new(),
+        }
+    }
+
+    // Returns the compressed length and uncompressed data of the block at the given compressed
+    // position, marking it as the most recently used.
+    pub fn get(&mut self, cpos: u64) -> Option<(u64, &[u8])> {
+        let i = self.entries.iter().position(|(pos, _, _)| *pos == cpos)?;
+        let entry = self.entries.remove(i)?;
+        self.entries.push_back(entry);
+        self.entries
+            .back()
+            .map(|(_, clen, data)| (*clen, data.as_slice()))
+    }
+
+    pub fn insert(&mut self, cpos: u64, clen: u64, data: &[u8]) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if let Some(i) = self.entries.iter().position(|(pos, _, _)| *pos == cpos) {
+            self.entries.remove(i);
+        } else if self.entries.len() >= self.capacity {
+            self.entries.pop_front();
+        }
+
+        self.entries.push_back((cpos, clen, data.to_vec()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_and_insert() {
+        let mut cache = BlockCache::new(2);
+
+        cache.insert(0, 8, b"nd");
+        cache.insert(8, 13, b"ls");
+        assert_eq!(cache.get(0), Some((8, &b"nd"[..])));
+
+        // evicts the least recently used block (8)
+        cache.insert(21, 5, b"bg");
+        assert!(cache.get(8).is_none());
+        assert_eq!(cache.get(0), Some((8, &b"nd"[..])));
+        assert_eq!(cache.get(21), Some((5, &b"bg"[..])));
+    }
+
+    #[test]
+    fn test_insert_with_zero_capacity() {
+        let mut cache = BlockCache::new(0);
+        cache.insert(0, 8, b"nd");
+        assert!(cache.get(0).is_none());
+    }
+}
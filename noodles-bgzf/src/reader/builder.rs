@@ -0,0 +1,84 @@
+use std::io::Read;
+
+use super::{BlockCache, MissingEofPolicy, Reader};
+use crate::Block;
+
+/// A BGZF reader builder.
+pub struct Builder<R> {
+    inner: R,
+    missing_eof_policy: MissingEofPolicy,
+    block_cache_size: usize,
+}
+
+impl<R> Builder<R>
+where
+    R: Read,
+{
+    pub(crate) fn new(inner: R) -> Self {
+        Self {
+            inner,
+            missing_eof_policy: MissingEofPolicy::default(),
+            block_cache_size: 0,
+        }
+    }
+
+    /// Sets the policy used when the stream ends without an EOF block.
+    ///
+    /// By default, this is [`MissingEofPolicy::Warn`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::{self as bgzf, reader::MissingEofPolicy};
+    /// let data = [];
+    /// let builder = bgzf::Reader::builder(&data[..])
+    ///     .set_missing_eof_policy(MissingEofPolicy::Error);
+    /// ```
+    pub fn set_missing_eof_policy(mut self, missing_eof_policy: MissingEofPolicy) -> Self {
+        self.missing_eof_policy = missing_eof_policy;
+        self
+    }
+
+    /// Sets the maximum number of decompressed blocks to cache.
+    ///
+    /// Blocks read when seeking are cached by their compressed position, and the least recently
+    /// used block is evicted when the cache is full. This avoids decompressing the same block again
+    /// when seeking to multiple virtual positions in a single block, e.g., querying several
+    /// regions of an indexed file.
+    ///
+    /// By default, this is 0, i.e., blocks are not cached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let builder = bgzf::Reader::builder(&data[..]).set_block_cache_size(8);
+    /// ```
+    pub fn set_block_cache_size(mut self, block_cache_size: usize) -> Self {
+        self.block_cache_size = block_cache_size;
+        self
+    }
+
+    /// Builds a BGZF reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let reader = bgzf::Reader::builder(&data[..]).build();
+    /// ```
+    pub fn build(self) -> Reader<R> {
+        Reader {
+            inner: self.inner,
+            position: 0,
+            uncompressed_bytes_read: 0,
+            cdata: Vec::new(),
+            block: Block::default(),
+            missing_eof_policy: self.missing_eof_policy,
+            is_eof_block_missing: false,
+            block_cache: BlockCache::new(self.block_cache_size),
+        }
+    }
+}
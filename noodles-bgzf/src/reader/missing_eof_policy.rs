@@ -0,0 +1,20 @@
+/// The behavior of a BGZF reader when the stream ends without an EOF block.
+///
+/// A well-formed BGZF stream ends with an empty block, the EOF marker. Its absence typically
+/// indicates a truncated file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MissingEofPolicy {
+    /// Return an error of kind [`std::io::ErrorKind::UnexpectedEof`].
+    Error,
+    /// Continue, but record that the EOF block is missing (see
+    /// [`super::Reader::is_eof_block_missing`]).
+    Warn,
+    /// Continue without recording that the EOF block is missing.
+    Ignore,
+}
+
+impl Default for MissingEofPolicy {
+    fn default() -> Self {
+        Self::Warn
+    }
+}
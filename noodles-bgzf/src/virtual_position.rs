@@ -215,4 +215,28 @@ mod tests {
             (399103671, 321)
         );
     }
+
+    #[test]
+    fn test_ord() -> Result<(), TryFromU64U16TupleError> {
+        // same block
+        assert!(VirtualPosition::try_from((8, 0))? < VirtualPosition::try_from((8, 13))?);
+
+        // across block boundaries, the compressed position takes precedence
+        assert!(VirtualPosition::try_from((8, 65535))? < VirtualPosition::try_from((13, 0))?);
+        assert!(VirtualPosition::try_from((13, 0))? > VirtualPosition::try_from((8, 21))?);
+
+        let mut positions = vec![
+            VirtualPosition::try_from((21, 5))?,
+            VirtualPosition::try_from((8, 13))?,
+            VirtualPosition::try_from((21, 0))?,
+            VirtualPosition::try_from((8, 65535))?,
+        ];
+
+        positions.sort();
+
+        let actual: Vec<(u64, u16)> = positions.into_iter().map(|pos| pos.into()).collect();
+        assert_eq!(actual, [(8, 13), (8, 65535), (21, 0), (21, 5)]);
+
+        Ok(())
+    }
 }
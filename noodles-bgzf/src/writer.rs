@@ -1,20 +1,18 @@
+//! BGZF writer.
+
+mod builder;
+
+pub use self::builder::Builder;
+
 use std::{
     cmp,
+    convert::TryFrom,
     io::{self, Write},
 };
 
-use byteorder::{LittleEndian, WriteBytesExt};
-use flate2::{write::DeflateEncoder, Compression, Crc};
+use flate2::Compression;
 
-use super::{block, gz, BGZF_HEADER_SIZE};
-
-const BGZF_FLG: u8 = 0x04; // FEXTRA
-const BGZF_XFL: u8 = 0x00; // none
-const BGZF_XLEN: u16 = 6;
-
-const BGZF_SI1: u8 = 0x42;
-const BGZF_SI2: u8 = 0x43;
-const BGZF_SLEN: u16 = 2;
+use super::{block, VirtualPosition};
 
 // § 4.1.2 End-of-file marker (2020-12-03)
 pub(crate) static BGZF_EOF: &[u8] = &[
@@ -33,6 +31,11 @@ pub(crate) static BGZF_EOF: &[u8] = &[
     0x00, 0x00, 0x00, 0x00, // ISIZE = 0
 ];
 
+// The maximum uncompressed data length of a written block. This is the same limit htslib uses
+// (`BGZF_BLOCK_SIZE`), and it leaves room for the DEFLATE overhead of incompressible data, which
+// would otherwise make the block larger than 64 KiB.
+pub(crate) const MAX_BUF_SIZE: usize = 0xff00;
+
 /// A BZGF writer.
 ///
 /// This implements [`std::io::Write`], consuming uncompressed data and emitting compressed data.
@@ -55,8 +58,9 @@ where
     W: Write,
 {
     inner: Option<W>,
-    encoder: DeflateEncoder<Vec<u8>>,
-    crc: Crc,
+    position: u64,
+    buf: Vec<u8>,
+    compression_level: Compression,
 }
 
 impl<W> Writer<W>
@@ -72,11 +76,23 @@ where
     /// let writer = bgzf::Writer::new(Vec::new());
     /// ```
     pub fn new(inner: W) -> Self {
-        Self {
-            inner: Some(inner),
-            encoder: DeflateEncoder::new(Vec::new(), Compression::default()),
-            crc: Crc::new(),
-        }
+        Builder::new(inner).build()
+    }
+
+    /// Creates a BGZF writer builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flate2::Compression;
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let writer = bgzf::Writer::builder(Vec::new())
+    ///     .set_compression_level(Compression::fast())
+    ///     .build();
+    /// ```
+    pub fn builder(inner: W) -> Builder<W> {
+        Builder::new(inner)
     }
 
     /// Returns a reference to the underlying writer.
@@ -92,18 +108,40 @@ where
         self.inner.as_ref().unwrap()
     }
 
+    /// Returns the current virtual position of the stream.
+    ///
+    /// The compressed position is the number of compressed bytes written to the underlying
+    /// writer, i.e., the start of the current block, and the uncompressed position is the number
+    /// of bytes buffered in the current block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Write};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::Writer::new(Vec::new());
+    /// assert_eq!(writer.virtual_position(), bgzf::VirtualPosition::from(0));
+    ///
+    /// writer.write_all(b"noodles")?;
+    /// assert_eq!(writer.virtual_position(), bgzf::VirtualPosition::from(7));
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn virtual_position(&self) -> VirtualPosition {
+        // `buf` is flushed when full, so its length is always < 2^16.
+        let uncompressed_position = self.buf.len() as u16;
+        VirtualPosition::try_from((self.position, uncompressed_position))
+            .expect("invalid virtual position")
+    }
+
     fn flush_block(&mut self) -> io::Result<()> {
-        self.encoder.try_finish()?;
+        let data = block::deflate(&self.buf, self.compression_level)?;
 
         let inner = self.inner.as_mut().unwrap();
-        let data = self.encoder.get_ref();
-
-        write_header(inner, data.len())?;
-        inner.write_all(&data[..])?;
-        write_trailer(inner, self.crc.sum(), self.crc.amount())?;
+        inner.write_all(&data)?;
 
-        self.encoder.reset(Vec::new())?;
-        self.crc.reset();
+        self.position += data.len() as u64;
+        self.buf.clear();
 
         Ok(())
     }
@@ -169,69 +207,122 @@ where
     W: Write,
 {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        let total_uncompressed_bytes_written = self.crc.amount() as usize;
+        let total_uncompressed_bytes_written = self.buf.len();
 
-        // Only the uncompressed size is tracked, and the assumption is that the uncompressed size
-        // will always be less than the compressed size.
-        if total_uncompressed_bytes_written >= block::MAX_UNCOMPRESSED_DATA_LENGTH {
+        // Only the uncompressed size is tracked. It is capped below the maximum block size so that
+        // even incompressible data fits in a single block.
+        if total_uncompressed_bytes_written >= MAX_BUF_SIZE {
             self.flush()?;
             return Err(io::Error::from(io::ErrorKind::Interrupted));
         }
 
-        let bytes_to_be_written = cmp::min(
-            block::MAX_UNCOMPRESSED_DATA_LENGTH - total_uncompressed_bytes_written,
-            buf.len(),
-        );
-        let bytes_written = self.encoder.write(&buf[..bytes_to_be_written])?;
-        self.crc.update(&buf[..bytes_written]);
+        let bytes_to_be_written =
+            cmp::min(MAX_BUF_SIZE - total_uncompressed_bytes_written, buf.len());
+        self.buf.extend_from_slice(&buf[..bytes_to_be_written]);
 
-        Ok(bytes_written)
+        if self.buf.len() >= MAX_BUF_SIZE {
+            self.flush()?;
+        }
+
+        Ok(bytes_to_be_written)
     }
 
     fn flush(&mut self) -> io::Result<()> {
-        if self.crc.amount() > 0 {
-            self.flush_block()
-        } else {
+        if self.buf.is_empty() {
             Ok(())
+        } else {
+            self.flush_block()
         }
     }
 }
 
-fn write_header<W>(writer: &mut W, cdata_len: usize) -> io::Result<()>
-where
-    W: Write,
-{
-    writer.write_all(&gz::MAGIC_NUMBER)?;
-    writer.write_u8(gz::CompressionMethod::Deflate as u8)?;
-    writer.write_u8(BGZF_FLG)?;
-    writer.write_u32::<LittleEndian>(gz::MTIME_NONE)?;
-    writer.write_u8(BGZF_XFL)?;
-    writer.write_u8(gz::OperatingSystem::Unknown as u8)?;
-    writer.write_u16::<LittleEndian>(BGZF_XLEN)?;
-
-    writer.write_u8(BGZF_SI1)?;
-    writer.write_u8(BGZF_SI2)?;
-    writer.write_u16::<LittleEndian>(BGZF_SLEN)?;
-
-    let bsize = (cdata_len + BGZF_HEADER_SIZE + gz::TRAILER_SIZE - 1) as u16;
-    writer.write_u16::<LittleEndian>(bsize)?;
-
-    Ok(())
-}
-
-fn write_trailer<W>(writer: &mut W, checksum: u32, uncompressed_size: u32) -> io::Result<()>
-where
-    W: Write,
-{
-    writer.write_u32::<LittleEndian>(checksum)?;
-    writer.write_u32::<LittleEndian>(uncompressed_size)?;
-    Ok(())
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_virtual_position() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        assert_eq!(writer.virtual_position(), VirtualPosition::from(7));
+
+        writer.flush()?;
+        let position = writer.get_ref().len() as u64;
+        assert_eq!(
+            writer.virtual_position(),
+            VirtualPosition::try_from((position, 0))?
+        );
+
+        // A full block is flushed, so the uncompressed position wraps to the next block.
+        let data = vec![0; MAX_BUF_SIZE];
+        writer.write_all(&data)?;
+        let position = writer.get_ref().len() as u64;
+        assert_eq!(
+            writer.virtual_position(),
+            VirtualPosition::try_from((position, 0))?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_with_compression_level() -> io::Result<()> {
+        use std::io::Read;
+
+        use crate::Reader;
+
+        let data = b"noodles".repeat(20000);
+
+        let mut writer = Writer::builder(Vec::new())
+            .set_compression_level(Compression::none())
+            .build();
+        writer.write_all(&data)?;
+        let uncompressed_data = writer.finish()?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(&data)?;
+        let compressed_data = writer.finish()?;
+
+        assert!(uncompressed_data.len() > data.len());
+        assert!(compressed_data.len() < uncompressed_data.len());
+
+        let mut reader = Reader::new(&uncompressed_data[..]);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_with_incompressible_data() -> io::Result<()> {
+        use std::io::Read;
+
+        use crate::Reader;
+
+        // xorshift32
+        let mut state = 0x2545f491u32;
+        let data: Vec<u8> = (0..(1 << 17))
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state as u8
+            })
+            .collect();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(&data)?;
+        let compressed_data = writer.finish()?;
+
+        let mut reader = Reader::new(&compressed_data[..]);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, data);
+
+        Ok(())
+    }
+
     #[test]
     fn test_finish() -> io::Result<()> {
         let mut writer = Writer::new(Vec::new());
@@ -0,0 +1,74 @@
+use std::io::Write;
+
+use flate2::Compression;
+
+use super::{Writer, MAX_BUF_SIZE};
+
+/// A BGZF writer builder.
+#[derive(Debug)]
+pub struct Builder<W> {
+    inner: W,
+    compression_level: Option<Compression>,
+}
+
+impl<W> Builder<W>
+where
+    W: Write,
+{
+    pub(crate) fn new(inner: W) -> Self {
+        Self {
+            inner,
+            compression_level: None,
+        }
+    }
+
+    /// Sets a compression level.
+    ///
+    /// By default, the compression level is set to level 6.
+    ///
+    /// A compression level of 0 ([`Compression::none`]) stores the data uncompressed, but it is
+    /// still wrapped in valid BGZF blocks, i.e., the output can be read and indexed like any other
+    /// BGZF stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flate2::Compression;
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let builder = bgzf::Writer::builder(Vec::new())
+    ///     .set_compression_level(Compression::best());
+    /// ```
+    pub fn set_compression_level(mut self, compression_level: Compression) -> Self {
+        self.compression_level = Some(compression_level);
+        self
+    }
+
+    /// Builds a BGZF writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let writer = bgzf::Writer::builder(Vec::new()).build();
+    /// ```
+    pub fn build(self) -> Writer<W> {
+        Writer {
+            inner: Some(self.inner),
+            position: 0,
+            buf: Vec::with_capacity(MAX_BUF_SIZE),
+            compression_level: self.compression_level.unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let builder = Builder::new(Vec::new());
+        assert!(builder.compression_level.is_none());
+    }
+}
@@ -19,6 +19,9 @@ static ALL_NAME: &str = ".";
 ///
 /// Genomic regions can either be mapped to a reference sequence, unmapped (*), or an inclusion of
 /// all reads (.).
+///
+/// The positions of a mapped region are 1-based and inclusive, e.g., `sq0:5-8` is the 4 bases from
+/// the 5th to the 8th positions of `sq0`.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Region {
     /// A mapped region.
@@ -33,7 +36,8 @@ impl Region {
     /// Parses a string to a region.
     ///
     /// A region string is specified as
-    /// `<reference-sequence-name>[:<start-position>[-<end-position>]]`.
+    /// `<reference-sequence-name>[:<start-position>[-<end-position>]]`. The start and end positions
+    /// are 1-based and inclusive.
     ///
     /// The reference sequence name can be "*" to represent unmapped records; or ".", all records.
     /// Otherwise, the reference sequence name must exist in the reference sequence dictionary.
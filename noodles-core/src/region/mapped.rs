@@ -4,9 +4,13 @@ use std::{
 };
 
 /// An interval.
+///
+/// The bounds of an interval are 1-based positions.
 pub type Interval = (Bound<i32>, Bound<i32>);
 
 /// A mapped region.
+///
+/// The interval of a mapped region is 1-based.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Mapped {
     name: String,
@@ -17,6 +21,8 @@ pub struct Mapped {
 impl Mapped {
     /// Creates a mapped region.
     ///
+    /// Positions are assumed to be 1-based.
+    ///
     /// # Examples
     ///
     /// ```
@@ -87,6 +93,8 @@ impl Mapped {
 
     /// Returns the start and end positions as an interval.
     ///
+    /// The interval is 1-based.
+    ///
     /// # Examples
     ///
     /// ```
@@ -87,6 +87,8 @@ impl Record {
 
     /// Returns the alignment start.
     ///
+    /// This value is 1-based.
+    ///
     /// # Examples
     ///
     /// ```
@@ -112,6 +114,8 @@ impl Record {
 
     /// Returns the alignment span.
     ///
+    /// This is the number of reference bases covered, starting at the alignment start.
+    ///
     /// # Examples
     ///
     /// ```
@@ -1,6 +1,8 @@
+//! CRAM data container and its slices.
+
 pub(crate) mod builder;
 pub(crate) mod compression_header;
-pub(crate) mod slice;
+pub mod slice;
 
 pub use self::{compression_header::CompressionHeader, slice::Slice};
 
@@ -23,6 +23,16 @@ impl SubstitutionMatrix {
     pub fn get(&self, reference_base: Base, substitution_code: u8) -> Base {
         self.substitutions[reference_base as usize][substitution_code as usize]
     }
+
+    /// Returns the substitution code for the given reference and read bases.
+    ///
+    /// This is the inverse of [`Self::get`]. `None` is returned if the bases are the same.
+    pub fn find(&self, reference_base: Base, read_base: Base) -> Option<u8> {
+        self.substitutions[reference_base as usize]
+            .iter()
+            .position(|&base| base == read_base)
+            .map(|i| i as u8)
+    }
 }
 
 impl Default for SubstitutionMatrix {
@@ -164,6 +174,23 @@ impl From<&SubstitutionMatrix> for [u8; 5] {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_find() {
+        let matrix = SubstitutionMatrix::default();
+
+        assert_eq!(matrix.find(Base::A, Base::C), Some(0));
+        assert_eq!(matrix.find(Base::A, Base::T), Some(2));
+        assert_eq!(matrix.find(Base::N, Base::A), Some(0));
+        assert_eq!(matrix.find(Base::G, Base::G), None);
+
+        for &reference_base in &[Base::A, Base::C, Base::G, Base::T, Base::N] {
+            for code in 0..4 {
+                let read_base = matrix.get(reference_base, code);
+                assert_eq!(matrix.find(reference_base, read_base), Some(code));
+            }
+        }
+    }
+
     #[test]
     fn test_try_from_u8_slice() -> Result<(), TryFromByteArrayError> {
         let codes = [0x93, 0x1b, 0x6c, 0xb1, 0xc6];
@@ -1,17 +1,26 @@
+//! CRAM data container slice.
+
 pub(crate) mod builder;
 pub(crate) mod header;
 
-pub use self::{builder::Builder, header::Header};
+pub use self::header::Header;
+
+pub(crate) use self::builder::Builder;
 
 use std::{
     convert::TryFrom,
     io::{self, Cursor},
 };
 
+use md5::{Digest, Md5};
+use noodles_fasta as fasta;
 use noodles_sam as sam;
 
 use super::CompressionHeader;
-use crate::{container::Block, BitReader, Record};
+use crate::{
+    container::{Block, ReferenceSequenceId},
+    BitReader, Record,
+};
 
 /// A CRAM data container slice.
 ///
@@ -37,7 +46,8 @@ impl Slice {
         }
     }
 
-    pub(crate) fn header(&self) -> &Header {
+    /// Returns the slice header.
+    pub fn header(&self) -> &Header {
         &self.header
     }
 
@@ -49,6 +59,78 @@ impl Slice {
         &self.external_blocks
     }
 
+    /// Verifies the reference MD5 checksum of this slice against the given reference assembly.
+    ///
+    /// The checksum is of the reference sequence region covered by the slice, i.e., from its
+    /// alignment start through its alignment span. Slices without a single reference sequence
+    /// (unmapped or multi-reference) or with a zeroed checksum are not checked.
+    ///
+    /// This is not done by [`crate::Reader::records`]; use
+    /// [`crate::Reader::records_with_reference_md5_verification`] to check each slice while
+    /// decoding.
+    ///
+    /// A mismatch returns an error of kind [`io::ErrorKind::InvalidData`], which the caller can
+    /// choose to treat as a warning and continue decoding the slice. An error of kind
+    /// [`io::ErrorKind::InvalidInput`] is returned if the reference assembly does not cover the
+    /// slice.
+    pub fn verify_reference_md5(&self, reference_assembly: &[fasta::Record]) -> io::Result<()> {
+        let id = match self.header.reference_sequence_id() {
+            ReferenceSequenceId::Some(id) => id,
+            _ => return Ok(()),
+        };
+
+        let expected = self.header.reference_md5();
+
+        if expected.iter().all(|&b| b == 0) {
+            return Ok(());
+        }
+
+        let reference_sequence = usize::try_from(id)
+            .ok()
+            .and_then(|i| reference_assembly.get(i))
+            .map(|record| record.sequence())
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidInput, "missing reference sequence")
+            })?;
+
+        let start = self
+            .header
+            .alignment_start()
+            .map(|position| position.to_zero_based() as usize)
+            .unwrap_or_default();
+        let span = usize::try_from(self.header.alignment_span())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let region = reference_sequence.get(start..start + span).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "slice is out of reference sequence bounds",
+            )
+        })?;
+
+        let actual = calculate_reference_md5(region);
+
+        if actual == expected {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "reference sequence checksum mismatch: expected {}, got {}",
+                    hex(expected),
+                    hex(&actual),
+                ),
+            ))
+        }
+    }
+
+    /// Reads and returns a list of raw records in this slice.
+    ///
+    /// The records are decoded as stored, i.e., the read features are populated, but the bases of
+    /// mapped records are not resolved. No reference sequence is needed to decode a slice, which
+    /// makes this suitable for feature-only scans. Bases can be resolved afterward using
+    /// [`crate::record::resolve::resolve_bases`] or by converting the record to a SAM record
+    /// ([`Record::try_into_sam_record`]).
     pub fn records(&self, compression_header: &CompressionHeader) -> io::Result<Vec<Record>> {
         use crate::reader::record::ExternalDataReaders;
 
@@ -88,6 +170,7 @@ impl Slice {
         Ok(records)
     }
 
+    /// Sets the mate fields of the given records using the mates found in the same slice.
     pub fn resolve_mates(&self, records: Vec<Record>) -> Vec<Record> {
         use std::cell::RefCell;
 
@@ -189,3 +272,115 @@ impl TryFrom<&[Block]> for Slice {
         Ok(Slice::new(header, core_data_block, external_blocks))
     }
 }
+
+pub(crate) fn calculate_reference_md5(reference_sequence: &[u8]) -> [u8; 16] {
+    let mut hasher = Md5::new();
+    hasher.update(reference_sequence);
+    <[u8; 16]>::from(hasher.finalize())
+}
+
+fn hex(buf: &[u8]) -> String {
+    buf.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_bam as bam;
+    use noodles_fasta as fasta;
+
+    use crate::{data_container::DataContainer, record::Feature};
+
+    use super::*;
+
+    #[test]
+    fn test_records_are_not_resolved() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequences = vec![fasta::Record::new(
+            fasta::record::Definition::new(String::from("sq0"), None),
+            b"ACGTACGTACGT".to_vec(),
+        )];
+
+        let features = vec![Feature::Insertion(2, b"GG".to_vec())];
+
+        let record = Record::builder()
+            .set_bam_flags(sam::record::Flags::empty())
+            .set_reference_sequence_id(bam::record::ReferenceSequenceId::try_from(0)?)
+            .set_read_length(6)
+            .set_alignment_start(sam::record::Position::try_from(1)?)
+            .set_features(features.clone())
+            .build();
+
+        let mut builder = DataContainer::builder(0);
+        builder
+            .add_record(reference_sequences[0].sequence(), record)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "could not add record"))?;
+        let data_container = builder.build(&reference_sequences)?;
+
+        let slice = &data_container.slices()[0];
+        let records = slice.records(data_container.compression_header())?;
+
+        assert_eq!(records.len(), 1);
+
+        let actual = &records[0];
+        assert_eq!(actual.features(), features);
+        assert!(actual.bases().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_reference_md5() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequences = vec![fasta::Record::new(
+            fasta::record::Definition::new(String::from("sq0"), None),
+            b"ACGTACGTACGT".to_vec(),
+        )];
+
+        let record = Record::builder()
+            .set_bam_flags(sam::record::Flags::empty())
+            .set_reference_sequence_id(bam::record::ReferenceSequenceId::try_from(0)?)
+            .set_read_length(4)
+            .set_alignment_start(sam::record::Position::try_from(2)?)
+            .build();
+
+        let mut builder = DataContainer::builder(0);
+        builder
+            .add_record(reference_sequences[0].sequence(), record)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "could not add record"))?;
+        let data_container = builder.build(&reference_sequences)?;
+
+        let slice = &data_container.slices()[0];
+        let header = slice.header();
+        assert_eq!(header.reference_sequence_id(), ReferenceSequenceId::Some(0));
+        assert_eq!(
+            header.alignment_start(),
+            Some(sam::record::Position::try_from(2)?)
+        );
+        assert_eq!(header.alignment_span(), 4);
+        // MD5("CGTA")
+        assert_eq!(
+            header.reference_md5(),
+            [
+                0x7d, 0x38, 0xbb, 0x5e, 0x5a, 0xcb, 0x31, 0x9c, 0x84, 0xe5, 0xd4, 0x1b, 0xea, 0x1f,
+                0xe6, 0x04
+            ]
+        );
+
+        slice.verify_reference_md5(&reference_sequences)?;
+
+        let reference_sequences = vec![fasta::Record::new(
+            fasta::record::Definition::new(String::from("sq0"), None),
+            b"ACCTACGTACGT".to_vec(),
+        )];
+
+        assert!(matches!(
+            slice.verify_reference_md5(&reference_sequences),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert!(matches!(
+            slice.verify_reference_md5(&[]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
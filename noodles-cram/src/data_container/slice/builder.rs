@@ -1,6 +1,5 @@
 use std::{cmp, collections::HashMap, convert::TryFrom, io};
 
-use md5::{Digest, Md5};
 use noodles_fasta as fasta;
 use noodles_sam as sam;
 
@@ -13,7 +12,7 @@ use crate::{
     writer, BitWriter, Record,
 };
 
-use super::{Header, Slice};
+use super::{calculate_reference_md5, Header, Slice};
 
 use noodles_bam as bam;
 
@@ -159,9 +158,7 @@ impl Builder {
             let start = (slice_alignment_start - 1) as usize;
             let end = (slice_alignment_end - 1) as usize;
 
-            let mut hasher = Md5::new();
-            hasher.update(&reference_sequence[start..=end]);
-            <[u8; 16]>::from(hasher.finalize())
+            calculate_reference_md5(&reference_sequence[start..=end])
         } else {
             [0; 16]
         };
@@ -13,6 +13,7 @@ use crate::{
     num::{Itf8, Ltf8},
 };
 
+/// A CRAM data container slice header.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Header {
     reference_sequence_id: ReferenceSequenceId,
@@ -28,48 +29,63 @@ pub struct Header {
 }
 
 impl Header {
+    /// Creates a slice header builder.
     pub fn builder() -> Builder {
         Builder::default()
     }
 
+    /// Returns the reference sequence ID of the slice.
     pub fn reference_sequence_id(&self) -> ReferenceSequenceId {
         self.reference_sequence_id
     }
 
+    /// Returns the start position of the reference sequence region covered by the slice.
+    ///
+    /// This value is 1-based.
     pub fn alignment_start(&self) -> Option<sam::record::Position> {
         self.alignment_start
     }
 
+    /// Returns the length of the reference sequence region covered by the slice.
     pub fn alignment_span(&self) -> Itf8 {
         self.alignment_span
     }
 
+    /// Returns the number of records in the slice.
     pub fn record_count(&self) -> usize {
         self.record_count
     }
 
+    /// Returns the record counter, i.e., the 0-based index of the first record in the slice.
     pub fn record_counter(&self) -> Ltf8 {
         self.record_counter
     }
 
+    /// Returns the number of blocks in the slice.
     pub fn block_count(&self) -> usize {
         self.block_count
     }
 
+    /// Returns the content IDs of the blocks in the slice.
     pub fn block_content_ids(&self) -> &[Itf8] {
         &self.block_content_ids
     }
 
+    /// Returns the content ID of the block that holds the embedded reference bases.
     pub fn embedded_reference_bases_block_content_id(
         &self,
     ) -> EmbeddedReferenceBasesBlockContentId {
         self.embedded_reference_bases_block_content_id
     }
 
+    /// Returns the MD5 checksum of the reference sequence region covered by the slice.
+    ///
+    /// This is all zeros if the slice is not mapped to a single reference sequence.
     pub fn reference_md5(&self) -> &[u8] {
         &self.reference_md5
     }
 
+    /// Returns the raw optional tags.
     pub fn optional_tags(&self) -> &[u8] {
         &self.optional_tags
     }
@@ -10,7 +10,7 @@ mod bit_reader;
 mod bit_writer;
 pub(crate) mod container;
 pub mod crai;
-mod data_container;
+pub mod data_container;
 pub mod file_definition;
 mod huffman;
 mod num;
@@ -20,8 +20,8 @@ pub mod record;
 pub(crate) mod writer;
 
 pub use self::{
-    data_container::DataContainer, file_definition::FileDefinition, reader::Reader, record::Record,
-    writer::Writer,
+    container::ReferenceSequenceId, data_container::DataContainer, file_definition::FileDefinition,
+    reader::Reader, record::Record, writer::Writer,
 };
 
 #[cfg(feature = "async")]
@@ -35,6 +35,16 @@ use noodles_bam as bam;
 
 static MAGIC_NUMBER: &[u8] = b"CRAM";
 
+/// The CRAM 3.0 end-of-file (EOF) container.
+///
+/// A CRAM stream ends with this container. It is written by [`Writer::try_finish`], and
+/// [`Reader::read_data_container`] stops when it is read.
+pub static EOF_CONTAINER: &[u8] = &[
+    0x0f, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x0f, 0xe0, 0x45, 0x4f, 0x46, 0x00, 0x00, 0x00,
+    0x00, 0x01, 0x00, 0x05, 0xbd, 0xd9, 0x4f, 0x00, 0x01, 0x00, 0x06, 0x06, 0x01, 0x00, 0x01, 0x00,
+    0x01, 0x00, 0xee, 0x63, 0x01, 0x4b,
+];
+
 /// Indexes a CRAM file.
 ///
 /// # Examples
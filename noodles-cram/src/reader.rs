@@ -2,13 +2,14 @@
 
 mod container;
 pub(crate) mod data_container;
+mod data_containers;
 pub(crate) mod num;
 pub(crate) mod record;
 mod records;
 
 use crate::data_container::DataContainer;
 
-pub use self::records::Records;
+pub use self::{data_containers::DataContainers, records::Records};
 
 use std::{
     io::{self, Read, Seek, SeekFrom},
@@ -16,6 +17,7 @@ use std::{
 };
 
 use byteorder::{LittleEndian, ReadBytesExt};
+use noodles_fasta as fasta;
 
 use self::container::read_container;
 use super::{container::Block, file_definition::Version, Container, FileDefinition, MAGIC_NUMBER};
@@ -154,6 +156,31 @@ where
         read_data_container(&mut self.inner)
     }
 
+    /// Returns an iterator over data containers starting from the current stream position.
+    ///
+    /// The stream is expected to be at the start of a data container. Each container is read
+    /// and parsed as the iterator advances. The iterator ends at the EOF container.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram as cram;
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::Reader::new)?;
+    /// reader.read_file_definition()?;
+    /// reader.read_file_header()?;
+    ///
+    /// for result in reader.data_containers() {
+    ///     let container = result?;
+    ///     println!("{}", container.slices().len());
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn data_containers(&mut self) -> DataContainers<'_, R> {
+        DataContainers::new(self)
+    }
+
     /// Returns a iterator over records starting from the current stream position.
     ///
     /// The stream is expected to be at the start of a data container.
@@ -177,6 +204,39 @@ where
     pub fn records(&mut self) -> Records<'_, R> {
         Records::new(self)
     }
+
+    /// Returns a iterator over records that verifies the reference MD5 checksum of each slice.
+    ///
+    /// This is the same as [`Self::records`], but each slice is checked against the given
+    /// reference assembly before its records are decoded (see
+    /// [`crate::data_container::Slice::verify_reference_md5`]). A mismatch is returned as an error
+    /// of kind [`io::ErrorKind::InvalidData`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram as cram;
+    /// use noodles_fasta as fasta;
+    ///
+    /// let reference_assembly: Vec<fasta::Record> = Vec::new();
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::Reader::new)?;
+    /// reader.read_file_definition()?;
+    /// reader.read_file_header()?;
+    ///
+    /// for result in reader.records_with_reference_md5_verification(&reference_assembly) {
+    ///     let record = result?;
+    ///     println!("{:?}", record);
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn records_with_reference_md5_verification<'a>(
+        &'a mut self,
+        reference_assembly: &'a [fasta::Record],
+    ) -> Records<'a, R> {
+        Records::with_reference_assembly(self, reference_assembly)
+    }
 }
 
 impl<R> Reader<R>
@@ -307,6 +367,122 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_data_containers() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_bam as bam;
+        use noodles_fasta as fasta;
+        use noodles_sam as sam;
+
+        use crate::{Record, Writer};
+
+        let reference_sequences: Vec<_> = ["sq0", "sq1", "sq2"]
+            .iter()
+            .map(|name| {
+                fasta::Record::new(
+                    fasta::record::Definition::new(String::from(*name), None),
+                    b"ACGTACGT".to_vec(),
+                )
+            })
+            .collect();
+
+        let mut writer = Writer::new(Vec::new(), reference_sequences);
+        writer.write_file_definition()?;
+        writer.write_file_header(&sam::Header::default())?;
+
+        // Records on different reference sequences are written to different containers.
+        for reference_sequence_id in 0..3 {
+            let record = Record::builder()
+                .set_reference_sequence_id(bam::record::ReferenceSequenceId::try_from(
+                    reference_sequence_id,
+                )?)
+                .set_alignment_start(sam::record::Position::try_from(1)?)
+                .set_read_length(4)
+                .set_bases(b"ACGT".to_vec())
+                .set_quality_scores(vec![45; 4])
+                .build();
+
+            writer.write_record(record)?;
+        }
+
+        writer.try_finish()?;
+        let data = writer.get_ref().clone();
+        assert!(data.ends_with(crate::EOF_CONTAINER));
+
+        let mut reader = Reader::new(std::io::Cursor::new(&data));
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let container_count = reader
+            .data_containers()
+            .collect::<io::Result<Vec<_>>>()?
+            .len();
+
+        assert_eq!(container_count, 3);
+
+        // The reader stops after the EOF container.
+        assert_eq!(reader.position()?, data.len() as u64);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_with_reference_md5_verification() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_bam as bam;
+        use noodles_sam as sam;
+
+        use crate::{Record, Writer};
+
+        let build_reference_assembly = |sequence: &[u8]| {
+            vec![fasta::Record::new(
+                fasta::record::Definition::new(String::from("sq0"), None),
+                sequence.to_vec(),
+            )]
+        };
+
+        let reference_assembly = build_reference_assembly(b"ACGTACGT");
+
+        let mut writer = Writer::new(Vec::new(), reference_assembly.clone());
+        writer.write_file_definition()?;
+        writer.write_file_header(&sam::Header::default())?;
+
+        let record = Record::builder()
+            .set_reference_sequence_id(bam::record::ReferenceSequenceId::try_from(0)?)
+            .set_alignment_start(sam::record::Position::try_from(1)?)
+            .set_read_length(4)
+            .set_bases(b"ACGT".to_vec())
+            .set_quality_scores(vec![45; 4])
+            .build();
+
+        writer.write_record(record)?;
+        writer.try_finish()?;
+
+        let data = writer.get_ref().clone();
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let records = reader
+            .records_with_reference_md5_verification(&reference_assembly)
+            .collect::<io::Result<Vec<_>>>()?;
+        assert_eq!(records.len(), 1);
+
+        let reference_assembly = build_reference_assembly(b"TTTTTTTT");
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        assert!(matches!(
+            reader
+                .records_with_reference_md5_verification(&reference_assembly)
+                .next(),
+            Some(Err(ref e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_file_header_block() -> io::Result<()> {
         let expected = "noodles";
@@ -8,6 +8,8 @@ use std::io::{self, Read};
 
 use crate::DataContainer;
 
+use super::container::read_block;
+
 pub fn read_data_container<R>(reader: &mut R) -> io::Result<Option<DataContainer>>
 where
     R: Read,
@@ -17,6 +19,11 @@ where
     let header = container::read_header(reader)?;
 
     if header.is_eof() {
+        // Consume the rest of the EOF container, leaving the stream at its end.
+        for _ in 0..header.block_count() {
+            read_block(reader)?;
+        }
+
         return Ok(None);
     }
 
@@ -37,8 +44,6 @@ fn read_compression_header_from_block<R>(reader: &mut R) -> io::Result<Compressi
 where
     R: Read,
 {
-    use super::container::read_block;
-
     let block = read_block(reader)?;
     let data = block.decompressed_data()?;
     let mut data_reader = &data[..];
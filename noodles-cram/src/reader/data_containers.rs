@@ -0,0 +1,35 @@
+use std::io::{self, Read};
+
+use crate::DataContainer;
+
+use super::Reader;
+
+/// An iterator over data containers of a CRAM reader.
+///
+/// This is created by calling [`Reader::data_containers`].
+pub struct DataContainers<'a, R>
+where
+    R: Read,
+{
+    reader: &'a mut Reader<R>,
+}
+
+impl<'a, R> DataContainers<'a, R>
+where
+    R: Read,
+{
+    pub(crate) fn new(reader: &'a mut Reader<R>) -> Self {
+        Self { reader }
+    }
+}
+
+impl<'a, R> Iterator for DataContainers<'a, R>
+where
+    R: Read,
+{
+    type Item = io::Result<DataContainer>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.reader.read_data_container().transpose()
+    }
+}
@@ -3,18 +3,22 @@ use std::{
     vec,
 };
 
+use noodles_fasta as fasta;
+
 use crate::Record;
 
 use super::Reader;
 
 /// An iterator over records of a CRAM reader.
 ///
-/// This is created by calling [`Reader::records`].
+/// This is created by calling [`Reader::records`] or
+/// [`Reader::records_with_reference_md5_verification`].
 pub struct Records<'a, R>
 where
     R: Read,
 {
     reader: &'a mut Reader<R>,
+    reference_assembly: Option<&'a [fasta::Record]>,
     records: vec::IntoIter<Record>,
 }
 
@@ -25,6 +29,18 @@ where
     pub(crate) fn new(reader: &'a mut Reader<R>) -> Records<'_, R> {
         Self {
             reader,
+            reference_assembly: None,
+            records: Vec::new().into_iter(),
+        }
+    }
+
+    pub(crate) fn with_reference_assembly(
+        reader: &'a mut Reader<R>,
+        reference_assembly: &'a [fasta::Record],
+    ) -> Self {
+        Self {
+            reader,
+            reference_assembly: Some(reference_assembly),
             records: Vec::new().into_iter(),
         }
     }
@@ -35,10 +51,16 @@ where
             None => return Ok(true),
         };
 
+        let reference_assembly = self.reference_assembly;
+
         self.records = container
             .slices()
             .iter()
             .map(|slice| {
+                if let Some(reference_assembly) = reference_assembly {
+                    slice.verify_reference_md5(reference_assembly)?;
+                }
+
                 slice
                     .records(container.compression_header())
                     .map(|r| slice.resolve_mates(r))
@@ -60,6 +60,8 @@ impl Builder {
     }
 
     /// Sets the alignment start position.
+    ///
+    /// This value is 1-based.
     pub fn set_alignment_start(mut self, alignment_start: sam::record::Position) -> Self {
         self.alignment_start = Some(alignment_start);
         self
@@ -93,6 +95,8 @@ impl Builder {
     }
 
     /// Sets the alignment start position of the next mate.
+    ///
+    /// This value is 1-based.
     pub fn set_next_mate_alignment_start(
         mut self,
         next_mate_alignment_start: sam::record::Position,
@@ -11,11 +11,55 @@ use crate::data_container::CompressionHeader;
 
 use super::{
     resolve::{resolve_bases, resolve_features},
-    Record, Tag,
+    Record,
 };
 
 impl Record {
+    /// Converts the tags of this CRAM record to SAM record data.
+    ///
+    /// Each tag is converted to a SAM data field with the same tag and value type. The order of
+    /// the tags is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam::record::data::field::Value;
+    /// use noodles_cram::{self as cram, record::{tag::Key, Tag}};
+    /// use noodles_sam::record::data::field::Tag as SamTag;
+    ///
+    /// let value = Value::Int8(1);
+    /// let key = Key::new([b'N', b'H'], value.ty());
+    ///
+    /// let record = cram::Record::builder()
+    ///     .set_tags(vec![Tag::new(key, value)])
+    ///     .build();
+    ///
+    /// let data = record.data()?;
+    /// let field = data.get(&SamTag::AlignmentHitCount);
+    /// assert_eq!(field.and_then(|field| field.value().as_int()), Some(1));
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn data(&self) -> io::Result<Data> {
+        use sam::record::data::Field;
+
+        let fields = self
+            .tags()
+            .iter()
+            .cloned()
+            .map(|tag| {
+                Field::try_from(tag).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        Data::try_from(fields).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+    }
+
     /// Converts this CRAM record to a SAM record.
+    ///
+    /// The reference sequence ID is resolved to a name using the given reference sequences, the
+    /// CIGAR is built from the read features, and, for mapped records, the read bases are
+    /// resolved against the reference sequence in the reference assembly.
     pub fn try_into_sam_record(
         &self,
         reference_assembly: &[fasta::Record],
@@ -81,7 +125,7 @@ impl Record {
                     self.features(),
                     self.alignment_start().map(i32::from).unwrap_or_default(),
                     self.read_length() as usize,
-                );
+                )?;
 
                 bytes_to_sequence(&raw_bases)?
             } else {
@@ -97,7 +141,7 @@ impl Record {
         }
 
         if !self.tags().is_empty() {
-            let data = tags_to_data(self.tags())?;
+            let data = self.data()?;
             builder = builder.set_data(data);
         }
 
@@ -146,26 +190,98 @@ fn bytes_to_quality_scores(data: &[u8]) -> io::Result<QualityScores> {
         .map(QualityScores::from)
 }
 
-fn tags_to_data(tags: &[Tag]) -> io::Result<Data> {
-    use sam::record::data::Field;
-
-    let mut fields = Vec::with_capacity(tags.len());
-
-    for tag in tags {
-        let raw_tag = tag.key().tag();
-        let sam_tag = str::from_utf8(&raw_tag)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
-            .and_then(|s| {
-                s.parse()
-                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
-            })?;
-
-        let bam_value = tag.value();
-        let sam_value = bam_value.clone().into();
-
-        let field = Field::new(sam_tag, sam_value);
-        fields.push(field);
+#[cfg(test)]
+mod tests {
+    use noodles_bam as bam;
+    use noodles_sam::header::ReferenceSequence;
+
+    use super::*;
+    use crate::record::Feature;
+
+    #[test]
+    fn test_data() -> Result<(), Box<dyn std::error::Error>> {
+        use bam::record::data::field::Value;
+        use sam::record::data::field::{Tag as SamTag, Value as SamValue};
+
+        use crate::record::{tag::Key, Tag};
+
+        let record = Record::default();
+        assert!(record.data()?.is_empty());
+
+        let nh = Value::UInt8(2);
+        let co = Value::String(String::from("ndls"));
+
+        let record = Record::builder()
+            .set_tags(vec![
+                Tag::new(Key::new([b'N', b'H'], nh.ty()), nh),
+                Tag::new(Key::new([b'C', b'O'], co.ty()), co),
+            ])
+            .build();
+
+        let data = record.data()?;
+        assert_eq!(data.len(), 2);
+        assert_eq!(
+            data.get(&SamTag::AlignmentHitCount)
+                .map(|field| field.value()),
+            Some(&SamValue::Int(2))
+        );
+        assert_eq!(
+            data.get(&SamTag::Comment).map(|field| field.value()),
+            Some(&SamValue::String(String::from("ndls")))
+        );
+        assert_eq!(data.to_string(), "NH:i:2\tCO:Z:ndls");
+
+        Ok(())
     }
 
-    Data::try_from(fields).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+    #[test]
+    fn test_try_into_sam_record() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_assembly = [fasta::Record::new(
+            fasta::record::Definition::new(String::from("sq0"), None),
+            b"ACGTACGTACGT".to_vec(),
+        )];
+
+        let reference_sequences = vec![("sq0", 12)]
+            .into_iter()
+            .map(|(name, len)| ReferenceSequence::new(name, len).map(|rs| (name.into(), rs)))
+            .collect::<Result<_, _>>()?;
+
+        let compression_header = CompressionHeader::builder().build();
+
+        let record = Record::builder()
+            .set_read_name(b"r0".to_vec())
+            .set_bam_flags(sam::record::Flags::empty())
+            .set_reference_sequence_id(bam::record::ReferenceSequenceId::try_from(0)?)
+            .set_alignment_start(sam::record::Position::try_from(3)?)
+            .set_read_length(5)
+            .set_mapping_quality(sam::record::MappingQuality::from(13))
+            .set_features(vec![Feature::SoftClip(1, b"TT".to_vec())])
+            .set_quality_scores(vec![45, 35, 43, 50, 40])
+            .build();
+
+        let sam_record = record.try_into_sam_record(
+            &reference_assembly,
+            &reference_sequences,
+            &compression_header,
+        )?;
+
+        assert_eq!(sam_record.read_name().map(|name| name.as_str()), Some("r0"));
+        assert_eq!(sam_record.flags(), sam::record::Flags::empty());
+        assert_eq!(
+            sam_record
+                .reference_sequence_name()
+                .map(|name| name.as_str()),
+            Some("sq0")
+        );
+        assert_eq!(
+            sam_record.position(),
+            Some(sam::record::Position::try_from(3)?)
+        );
+        assert_eq!(u8::from(sam_record.mapping_quality()), 13);
+        assert_eq!(sam_record.cigar().to_string(), "2S3M");
+        assert_eq!(sam_record.sequence().to_string(), "TTGTA");
+        assert_eq!(sam_record.quality_scores().to_string(), "NDLSI");
+
+        Ok(())
+    }
 }
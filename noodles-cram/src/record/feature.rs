@@ -4,6 +4,14 @@ pub mod code;
 
 pub use self::code::Code;
 
+use std::{convert::TryFrom, io};
+
+use noodles_sam::record::{cigar::op::Kind, Cigar, Sequence};
+
+use crate::data_container::compression_header::preservation_map::substitution_matrix::{
+    Base, SubstitutionMatrix,
+};
+
 /// A CRAM record feature.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Feature {
@@ -34,6 +42,116 @@ pub enum Feature {
 }
 
 impl Feature {
+    /// Builds a list of features from an alignment.
+    ///
+    /// This is the inverse of [`super::resolve::resolve_bases`] and
+    /// [`super::resolve::resolve_features`]. Read bases that match the reference sequence are
+    /// omitted, and mismatches are encoded as substitutions using the given substitution matrix.
+    /// Mismatches that cannot be represented by the substitution matrix (i.e., bases not in
+    /// {A, C, G, T, N}) are stored verbatim as bases.
+    ///
+    /// `reference_sequence` is the entire reference sequence, and `alignment_start` is the
+    /// 1-based position of the first aligned reference base.
+    ///
+    /// This returns an error if the alignment start is < 1 or if the sequence is shorter than the
+    /// read length of the CIGAR.
+    pub fn from_alignment(
+        cigar: &Cigar,
+        sequence: &Sequence,
+        reference_sequence: &[u8],
+        alignment_start: i32,
+        substitution_matrix: &SubstitutionMatrix,
+    ) -> io::Result<Vec<Self>> {
+        let mut ref_pos = alignment_start
+            .checked_sub(1)
+            .and_then(|n| usize::try_from(n).ok())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("invalid alignment start: {}", alignment_start),
+                )
+            })?;
+
+        let read_len = cigar.read_len() as usize;
+
+        if sequence.len() < read_len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "sequence length mismatch: expected >= {}, got {}",
+                    read_len,
+                    sequence.len()
+                ),
+            ));
+        }
+
+        let mut features = Vec::new();
+        let mut read_pos = 0;
+
+        let read_bases = |start: usize, len: usize| -> Vec<u8> {
+            sequence[start..start + len]
+                .iter()
+                .map(|&base| char::from(base) as u8)
+                .collect()
+        };
+
+        for op in cigar.iter() {
+            let len = op.len() as usize;
+            let pos = (read_pos + 1) as i32;
+
+            match op.kind() {
+                Kind::Match | Kind::SeqMatch | Kind::SeqMismatch => {
+                    for i in 0..len {
+                        let read_base = char::from(sequence[read_pos + i]) as u8;
+                        let reference_base =
+                            reference_sequence.get(ref_pos + i).copied().unwrap_or(b'N');
+
+                        if read_base.eq_ignore_ascii_case(&reference_base) {
+                            continue;
+                        }
+
+                        let pos = (read_pos + i + 1) as i32;
+
+                        match find_substitution_code(substitution_matrix, reference_base, read_base)
+                        {
+                            Some(code) => features.push(Self::Substitution(pos, code)),
+                            None => features.push(Self::Bases(pos, vec![read_base])),
+                        }
+                    }
+
+                    read_pos += len;
+                    ref_pos += len;
+                }
+                Kind::Insertion => {
+                    if len == 1 {
+                        let base = char::from(sequence[read_pos]) as u8;
+                        features.push(Self::InsertBase(pos, base));
+                    } else {
+                        features.push(Self::Insertion(pos, read_bases(read_pos, len)));
+                    }
+
+                    read_pos += len;
+                }
+                Kind::Deletion => {
+                    features.push(Self::Deletion(pos, len as i32));
+                    ref_pos += len;
+                }
+                Kind::Skip => {
+                    features.push(Self::ReferenceSkip(pos, len as i32));
+                    ref_pos += len;
+                }
+                Kind::SoftClip => {
+                    features.push(Self::SoftClip(pos, read_bases(read_pos, len)));
+                    read_pos += len;
+                }
+                Kind::HardClip => features.push(Self::HardClip(pos, len as i32)),
+                Kind::Pad => features.push(Self::Padding(pos, len as i32)),
+            }
+        }
+
+        Ok(features)
+    }
+
     /// Returns the feature code.
     ///
     /// # Examples
@@ -62,6 +180,8 @@ impl Feature {
 
     /// Returns the feature position.
     ///
+    /// This is the 1-based position in the read.
+    ///
     /// # Examples
     ///
     /// ```
@@ -87,10 +207,90 @@ impl Feature {
     }
 }
 
+fn find_substitution_code(
+    substitution_matrix: &SubstitutionMatrix,
+    reference_base: u8,
+    read_base: u8,
+) -> Option<u8> {
+    let reference_base = Base::try_from(char::from(reference_base.to_ascii_uppercase())).ok()?;
+    let read_base = Base::try_from(char::from(read_base.to_ascii_uppercase())).ok()?;
+    substitution_matrix.find(reference_base, read_base)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_from_alignment() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequence = b"NNNNACGTACGTNNNN";
+        let substitution_matrix = SubstitutionMatrix::default();
+
+        let cigar = "2S6M".parse()?;
+        let sequence = "TTACTTAC".parse()?;
+
+        let actual = Feature::from_alignment(
+            &cigar,
+            &sequence,
+            reference_sequence,
+            5,
+            &substitution_matrix,
+        )?;
+
+        let expected = [
+            Feature::SoftClip(1, b"TT".to_vec()),
+            // G => T
+            Feature::Substitution(5, 2),
+        ];
+
+        assert_eq!(actual, expected);
+
+        let cigar = "1H2M1I1M2D2M".parse()?;
+        let sequence = "ACGTCG".parse()?;
+
+        let actual = Feature::from_alignment(
+            &cigar,
+            &sequence,
+            reference_sequence,
+            5,
+            &substitution_matrix,
+        )?;
+
+        let expected = [
+            Feature::HardClip(1, 1),
+            Feature::InsertBase(3, b'G'),
+            // G => T
+            Feature::Substitution(4, 2),
+            Feature::Deletion(5, 2),
+        ];
+
+        assert_eq!(actual, expected);
+
+        let cigar = "4M".parse()?;
+        let sequence = Sequence::default();
+
+        assert!(matches!(
+            Feature::from_alignment(&cigar, &sequence, reference_sequence, 5, &substitution_matrix),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let sequence = "AC".parse()?;
+
+        assert!(matches!(
+            Feature::from_alignment(&cigar, &sequence, reference_sequence, 5, &substitution_matrix),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let sequence = "ACGT".parse()?;
+
+        assert!(matches!(
+            Feature::from_alignment(&cigar, &sequence, reference_sequence, 0, &substitution_matrix),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_code() {
         assert_eq!(Feature::Bases(1, Vec::new()).code(), Code::Bases);
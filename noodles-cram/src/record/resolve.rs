@@ -1,6 +1,6 @@
 //! CRAM record field resolvers.
 
-use std::convert::TryFrom;
+use std::{convert::TryFrom, io};
 
 use noodles_fasta as fasta;
 use noodles_sam::record::Cigar;
@@ -18,7 +18,7 @@ pub fn resolve_bases(
     features: &[Feature],
     alignment_start: i32,
     read_len: usize,
-) -> Vec<u8> {
+) -> io::Result<Vec<u8>> {
     let mut buf = vec![b'-'; read_len];
 
     let mut ref_pos = (alignment_start - 1) as usize;
@@ -47,6 +47,13 @@ pub fn resolve_bases(
                 ref_pos += 1;
                 read_pos += 1;
             }
+            Feature::Bases(_, bases) => {
+                for &base in bases {
+                    buf[read_pos] = base;
+                    ref_pos += 1;
+                    read_pos += 1;
+                }
+            }
             Feature::Insertion(_, bases) => {
                 for &base in bases {
                     buf[read_pos] = base;
@@ -66,8 +73,16 @@ pub fn resolve_bases(
                     read_pos += 1;
                 }
             }
-            Feature::HardClip(..) => {}
-            _ => todo!("resolve_bases: {:?}", feature),
+            Feature::ReferenceSkip(_, len) => {
+                ref_pos += *len as usize;
+            }
+            Feature::HardClip(..) | Feature::Padding(..) => {}
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("unsupported feature: {:?}", feature),
+                ))
+            }
         }
     }
 
@@ -76,7 +91,7 @@ pub fn resolve_bases(
         ref_pos += 1;
     }
 
-    buf
+    Ok(buf)
 }
 
 /// Resolves the read features as CIGAR operations.
@@ -129,8 +144,46 @@ pub fn resolve_features(features: &[Feature], read_len: i32) -> Cigar {
 
 #[cfg(test)]
 mod tests {
+    use noodles_sam::record::Sequence;
+
+    use crate::data_container::compression_header::SubstitutionMatrix;
+
     use super::*;
 
+    #[test]
+    fn test_resolve_bases_and_features_with_skip_and_padding(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequence_record = fasta::Record::new(
+            fasta::record::Definition::new(String::from("sq0"), None),
+            b"NNNNACGTACGTNNNN".to_vec(),
+        );
+        let compression_header = CompressionHeader::builder().build();
+
+        let cigar: Cigar = "2M2N2M1P2M".parse()?;
+        let sequence: Sequence = "ACACGT".parse()?;
+
+        let features = Feature::from_alignment(
+            &cigar,
+            &sequence,
+            reference_sequence_record.sequence(),
+            5,
+            &SubstitutionMatrix::default(),
+        )?;
+
+        let actual = resolve_bases(
+            &reference_sequence_record,
+            &compression_header,
+            &features,
+            5,
+            sequence.len(),
+        )?;
+        assert_eq!(actual, sequence.to_string().as_bytes());
+
+        assert_eq!(resolve_features(&features, sequence.len() as i32), cigar);
+
+        Ok(())
+    }
+
     #[test]
     fn test_resolve_features() {
         use noodles_sam::record::cigar::{op::Kind, Op};
@@ -26,3 +26,16 @@ where
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_container_with_eof_container() -> io::Result<()> {
+        let mut buf = Vec::new();
+        write_container(&mut buf, &Container::eof())?;
+        assert_eq!(buf, crate::EOF_CONTAINER);
+        Ok(())
+    }
+}
@@ -5,7 +5,7 @@ use std::{
 
 use byteorder::{LittleEndian, WriteBytesExt};
 
-use noodles_bam::record::data::field::Value;
+use noodles_bam::record::data::field::{value::char_to_u8, Value};
 
 pub fn write_value<W>(writer: &mut W, value: &Value) -> io::Result<()>
 where
@@ -16,7 +16,10 @@ where
     }
 
     match value {
-        Value::Char(c) => writer.write_u8(*c as u8),
+        Value::Char(c) => {
+            let b = char_to_u8(*c)?;
+            writer.write_u8(b)
+        }
         Value::Int8(n) => writer.write_i8(*n),
         Value::UInt8(n) => writer.write_u8(*n),
         Value::Int16(n) => writer.write_i16::<LittleEndian>(*n),
@@ -3,7 +3,10 @@
 mod reader;
 mod writer;
 
-pub use self::{reader::Reader, writer::Writer};
+pub use self::{
+    reader::{read_unplaced_unmapped_record_count, Reader},
+    writer::Writer,
+};
 
 use std::path::Path;
 
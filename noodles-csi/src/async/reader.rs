@@ -1,4 +1,4 @@
-use std::convert::TryFrom;
+use std::{convert::TryFrom, mem};
 
 use noodles_bgzf as bgzf;
 use tokio::io::{self, AsyncRead, AsyncReadExt};
@@ -256,15 +256,53 @@ where
     Ok(Metadata::new(ref_beg, ref_end, n_mapped, n_unmapped))
 }
 
-async fn read_unplaced_unmapped_record_count<R>(reader: &mut R) -> io::Result<Option<u64>>
+/// Reads the optional number of unplaced, unmapped records (`n_no_coor`) at the end of an index.
+///
+/// This is the async equivalent of [`crate::read_unplaced_unmapped_record_count`].
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> io::Result<()> {
+/// use noodles_csi as csi;
+///
+/// let data = [];
+/// assert!(csi::r#async::read_unplaced_unmapped_record_count(&mut &data[..]).await?.is_none());
+///
+/// let data = [0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+/// assert_eq!(
+///     csi::r#async::read_unplaced_unmapped_record_count(&mut &data[..]).await?,
+///     Some(8)
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub async fn read_unplaced_unmapped_record_count<R>(reader: &mut R) -> io::Result<Option<u64>>
 where
     R: AsyncRead + Unpin,
 {
-    match reader.read_u64_le().await {
-        Ok(n_no_coor) => Ok(Some(n_no_coor)),
-        Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => Ok(None),
-        Err(e) => Err(e),
+    const LEN: usize = mem::size_of::<u64>();
+
+    // n_no_coor is optional. A clean EOF at its start means it is absent, but a partial value is
+    // invalid.
+    let mut buf = Vec::with_capacity(LEN);
+    reader.take(LEN as u64).read_to_end(&mut buf).await?;
+
+    if buf.is_empty() {
+        return Ok(None);
     }
+
+    <[u8; LEN]>::try_from(&buf[..])
+        .map(|bytes| Some(u64::from_le_bytes(bytes)))
+        .map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "unexpected EOF in unplaced, unmapped record count",
+            )
+        })
 }
 
 #[cfg(test)]
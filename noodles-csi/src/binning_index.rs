@@ -27,10 +27,65 @@ where
     fn unplaced_unmapped_record_count(&self) -> Option<u64>;
 
     /// Returns the chunks that overlap with the given region.
+    ///
+    /// The interval values are 1-based.
     fn query<B>(&self, reference_sequence_id: usize, interval: B) -> io::Result<Vec<Chunk>>
     where
         B: RangeBounds<i32> + Clone;
 
+    /// Estimates the number of records that overlap with the given region.
+    ///
+    /// This does not read any records. The estimate is the number of records in the reference
+    /// sequence, scaled by the fraction of the reference sequence's span in the file that is
+    /// covered by the chunks of the query. It assumes records are evenly distributed in the
+    /// compressed stream and is typically an overestimate, as chunks are coarse. It is useful,
+    /// e.g., to compare the cost of queries.
+    ///
+    /// The interval values are 1-based.
+    ///
+    /// This returns `None` if the reference sequence has no metadata.
+    fn estimate_records<B>(
+        &self,
+        reference_sequence_id: usize,
+        interval: B,
+    ) -> io::Result<Option<u64>>
+    where
+        B: RangeBounds<i32> + Clone,
+    {
+        let chunks = self.query(reference_sequence_id, interval)?;
+
+        let metadata = match self
+            .reference_sequences()
+            .get(reference_sequence_id)
+            .and_then(|rs| rs.metadata())
+        {
+            Some(metadata) => metadata,
+            None => return Ok(None),
+        };
+
+        let record_count = metadata.mapped_record_count() + metadata.unmapped_record_count();
+
+        let span = |start: bgzf::VirtualPosition, end: bgzf::VirtualPosition| {
+            u64::from(end).saturating_sub(u64::from(start))
+        };
+
+        let reference_sequence_span = span(metadata.start_position(), metadata.end_position());
+
+        if reference_sequence_span == 0 {
+            return Ok(Some(0));
+        }
+
+        let chunks_span: u64 = merge_chunks(&chunks)
+            .iter()
+            .map(|chunk| span(chunk.start(), chunk.end()))
+            .sum();
+
+        let estimate = (u128::from(record_count) * u128::from(chunks_span)
+            / u128::from(reference_sequence_span)) as u64;
+
+        Ok(Some(estimate.min(record_count)))
+    }
+
     /// Returns the start position of the first record in the last linear bin.
     ///
     /// This is the closest position to the unplaced, unmapped records, if any, that is available
@@ -143,6 +198,14 @@ pub fn optimize_chunks(chunks: &[Chunk], min_offset: bgzf::VirtualPosition) -> V
 
 #[cfg(test)]
 mod tests {
+    use crate::{
+        index::{
+            reference_sequence::{Bin, Metadata},
+            ReferenceSequence,
+        },
+        Index,
+    };
+
     use super::*;
 
     fn build_chunks() -> Vec<Chunk> {
@@ -222,4 +285,62 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_estimate_records() -> io::Result<()> {
+        fn build_chunk(start: u64, end: u64) -> Chunk {
+            Chunk::new(
+                bgzf::VirtualPosition::from(start << 16),
+                bgzf::VirtualPosition::from(end << 16),
+            )
+        }
+
+        // min_shift = 14, depth = 5 => bins 4681.. each span 16 KiB.
+        let bins = vec![
+            Bin::new(
+                4681,
+                bgzf::VirtualPosition::default(),
+                vec![build_chunk(0, 100)],
+            ),
+            Bin::new(
+                4682,
+                bgzf::VirtualPosition::default(),
+                vec![build_chunk(100, 400)],
+            ),
+            Bin::new(
+                4683,
+                bgzf::VirtualPosition::default(),
+                vec![build_chunk(400, 1000)],
+            ),
+        ];
+
+        let metadata = Metadata::new(
+            bgzf::VirtualPosition::from(0),
+            bgzf::VirtualPosition::from(1000 << 16),
+            200,
+            0,
+        );
+
+        let index = Index::builder()
+            .set_reference_sequences(vec![ReferenceSequence::new(bins, Some(metadata))])
+            .build();
+
+        let narrow = index.estimate_records(0, 1..=8192)?;
+        let wide = index.estimate_records(0, 1..=32768)?;
+        let all = index.estimate_records(0, ..)?;
+
+        assert_eq!(narrow, Some(20));
+        assert_eq!(wide, Some(80));
+        assert_eq!(all, Some(200));
+        assert!(narrow <= wide && wide <= all);
+
+        let index = Index::builder()
+            .set_reference_sequences(vec![ReferenceSequence::new(Vec::new(), None)])
+            .build();
+
+        assert!(index.estimate_records(0, ..)?.is_none());
+        assert!(index.estimate_records(1, ..).is_err());
+
+        Ok(())
+    }
 }
@@ -9,6 +9,7 @@ pub struct Builder {
     aux: Vec<u8>,
     reference_sequences: Vec<ReferenceSequence>,
     unplaced_unmapped_record_count: Option<u64>,
+    max_reference_sequence_length: Option<i64>,
 }
 
 impl Builder {
@@ -40,6 +41,29 @@ impl Builder {
         self
     }
 
+    /// Sets the maximum reference sequence length.
+    ///
+    /// When set, the depth is increased at build time, if necessary, to the minimum depth that
+    /// can index positions up to this length with the current min shift. The depth is never
+    /// decreased. For example, with the default min shift (14) and depth (5), the maximum indexable
+    /// position is 2^29 - 1 (~512 Mbp); a reference sequence of length 2^30 requires a depth of 6.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    ///
+    /// let index = csi::Index::builder()
+    ///     .set_max_reference_sequence_length(1 << 30)
+    ///     .build();
+    ///
+    /// assert_eq!(index.depth(), 6);
+    /// ```
+    pub fn set_max_reference_sequence_length(mut self, max_reference_sequence_length: i64) -> Self {
+        self.max_reference_sequence_length = Some(max_reference_sequence_length);
+        self
+    }
+
     /// Set auxiliary data.
     ///
     /// # Examples
@@ -116,9 +140,19 @@ impl Builder {
     /// let index = csi::Index::builder().build();
     /// ```
     pub fn build(self) -> Index {
+        let mut depth = self.depth;
+
+        if let Some(max_reference_sequence_length) = self.max_reference_sequence_length {
+            while ReferenceSequence::max_position(self.min_shift, depth)
+                < max_reference_sequence_length
+            {
+                depth += 1;
+            }
+        }
+
         Index {
             min_shift: self.min_shift,
-            depth: self.depth,
+            depth,
             aux: self.aux,
             reference_sequences: self.reference_sequences,
             n_no_coor: self.unplaced_unmapped_record_count,
@@ -134,6 +168,7 @@ impl Default for Builder {
             aux: Vec::new(),
             reference_sequences: Vec::new(),
             unplaced_unmapped_record_count: None,
+            max_reference_sequence_length: None,
         }
     }
 }
@@ -151,5 +186,31 @@ mod tests {
         assert!(builder.aux.is_empty());
         assert!(builder.reference_sequences.is_empty());
         assert!(builder.unplaced_unmapped_record_count.is_none());
+        assert!(builder.max_reference_sequence_length.is_none());
+    }
+
+    #[test]
+    fn test_build_with_max_reference_sequence_length() {
+        let index = Builder::default()
+            .set_max_reference_sequence_length(248956422)
+            .build();
+        assert_eq!(index.depth(), 5);
+
+        let index = Builder::default()
+            .set_max_reference_sequence_length(1 << 29)
+            .build();
+        assert_eq!(index.depth(), 6);
+
+        let index = Builder::default()
+            .set_depth(7)
+            .set_max_reference_sequence_length(1 << 29)
+            .build();
+        assert_eq!(index.depth(), 7);
+
+        let index = Builder::default()
+            .set_min_shift(12)
+            .set_max_reference_sequence_length(1 << 29)
+            .build();
+        assert_eq!(index.depth(), 6);
     }
 }
@@ -53,10 +53,14 @@ impl fmt::Display for QueryError {
 }
 
 impl ReferenceSequence {
-    fn max_position(min_shift: i32, depth: i32) -> i64 {
-        let min_shift = i64::from(min_shift);
-        let depth = i64::from(depth);
-        (1 << (min_shift + 3 * depth)) - 1
+    pub(crate) fn max_position(min_shift: i32, depth: i32) -> i64 {
+        let shift = i64::from(min_shift) + 3 * i64::from(depth);
+
+        if shift >= 63 {
+            i64::MAX
+        } else {
+            (1 << shift) - 1
+        }
     }
 
     /// Creates a CSI reference sequence.
@@ -230,6 +234,10 @@ mod tests {
     fn test_max_position() {
         let max_position = ReferenceSequence::max_position(MIN_SHIFT, DEPTH);
         assert_eq!(max_position, 536870911);
+
+        assert_eq!(ReferenceSequence::max_position(MIN_SHIFT, 6), 4294967295);
+        assert_eq!(ReferenceSequence::max_position(MIN_SHIFT, 17), i64::MAX);
+        assert_eq!(ReferenceSequence::max_position(MIN_SHIFT, 32), i64::MAX);
     }
 
     #[test]
@@ -246,6 +254,28 @@ mod tests {
             reference_sequence.query(MIN_SHIFT, DEPTH, 1..=end),
             Err(QueryError::InvalidEndPosition(536870911, end))
         );
+        assert_eq!(
+            reference_sequence.query(MIN_SHIFT, 6, 1..=end),
+            Ok(Vec::new())
+        );
+    }
+
+    #[test]
+    fn test_query_with_depth_6() {
+        let bins = vec![
+            Bin::new(0, bgzf::VirtualPosition::from(0), Vec::new()),
+            // [0, 2^32) / 8^6 bins at the deepest level
+            // 600000000 >> 14 = 36621 => 37449 + 36621
+            Bin::new(74070, bgzf::VirtualPosition::from(0), Vec::new()),
+            Bin::new(74071, bgzf::VirtualPosition::from(0), Vec::new()),
+        ];
+        let reference_sequence = ReferenceSequence::new(bins, None);
+
+        let query_bins = reference_sequence
+            .query(MIN_SHIFT, 6, 600000000..=600000001)
+            .unwrap();
+        let ids: Vec<_> = query_bins.iter().map(|bin| bin.id()).collect();
+        assert_eq!(ids, [0, 74070]);
     }
 
     #[test]
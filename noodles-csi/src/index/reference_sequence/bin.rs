@@ -9,6 +9,9 @@ use noodles_bgzf as bgzf;
 pub(crate) const METADATA_CHUNK_COUNT: u32 = 2;
 
 /// A CSI reference sequence bin.
+///
+/// A bin covers a 0-based, half-open interval of the reference sequence, as calculated by
+/// `reg2bin`. Query intervals are 1-based and are converted when finding the overlapping bins.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Bin {
     id: u32,
@@ -13,7 +13,7 @@ mod writer;
 pub use self::{
     binning_index::{BinningIndex, BinningIndexReferenceSequence},
     index::Index,
-    reader::Reader,
+    reader::{read_unplaced_unmapped_record_count, Reader},
     writer::Writer,
 };
 
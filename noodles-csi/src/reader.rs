@@ -1,6 +1,7 @@
 use std::{
     convert::TryFrom,
     io::{self, Read},
+    mem,
 };
 
 use byteorder::{LittleEndian, ReadBytesExt};
@@ -214,19 +215,53 @@ where
     Ok(Metadata::new(ref_beg, ref_end, n_mapped, n_unmapped))
 }
 
-fn read_unplaced_unmapped_record_count<R>(reader: &mut R) -> io::Result<Option<u64>>
+/// Reads the optional number of unplaced, unmapped records (`n_no_coor`) at the end of an index.
+///
+/// This is shared by the binning index formats (BAI, CSI, and tabix). If the stream is at EOF, the
+/// field is absent, and this returns `None`. A partial value is an error.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_csi as csi;
+///
+/// let data = [];
+/// assert!(csi::read_unplaced_unmapped_record_count(&mut &data[..])?.is_none());
+///
+/// let data = [0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+/// assert_eq!(csi::read_unplaced_unmapped_record_count(&mut &data[..])?, Some(8));
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn read_unplaced_unmapped_record_count<R>(reader: &mut R) -> io::Result<Option<u64>>
 where
     R: Read,
 {
-    match reader.read_u64::<LittleEndian>() {
-        Ok(n) => Ok(Some(n)),
-        Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => Ok(None),
-        Err(e) => Err(e),
+    const LEN: usize = mem::size_of::<u64>();
+
+    // n_no_coor is optional. A clean EOF at its start means it is absent, but a partial value is
+    // invalid.
+    let mut buf = Vec::with_capacity(LEN);
+    reader.take(LEN as u64).read_to_end(&mut buf)?;
+
+    if buf.is_empty() {
+        return Ok(None);
     }
+
+    <[u8; LEN]>::try_from(&buf[..])
+        .map(|bytes| Some(u64::from_le_bytes(bytes)))
+        .map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "unexpected EOF in unplaced, unmapped record count",
+            )
+        })
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::BinningIndex;
+
     use super::*;
 
     #[test]
@@ -295,6 +330,46 @@ mod tests {
         let mut reader = &data[..];
         assert_eq!(read_unplaced_unmapped_record_count(&mut reader)?, Some(8));
 
+        let data = [0x08, 0x00, 0x00];
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_unplaced_unmapped_record_count(&mut reader),
+            Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_index_with_unplaced_unmapped_record_count() -> io::Result<()> {
+        fn compress(data: &[u8]) -> io::Result<Vec<u8>> {
+            use std::io::Write;
+
+            let mut writer = bgzf::Writer::new(Vec::new());
+            writer.write_all(data)?;
+            writer.finish()
+        }
+
+        let mut data = vec![
+            b'C', b'S', b'I', 0x01, // magic
+            0x0e, 0x00, 0x00, 0x00, // min_shift = 14
+            0x05, 0x00, 0x00, 0x00, // depth = 5
+            0x00, 0x00, 0x00, 0x00, // l_aux = 0
+            0x00, 0x00, 0x00, 0x00, // n_ref = 0
+        ];
+
+        let compressed_data = compress(&data)?;
+        let mut reader = Reader::new(&compressed_data[..]);
+        let index = reader.read_index()?;
+        assert!(index.unplaced_unmapped_record_count().is_none());
+
+        data.extend_from_slice(&[0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]); // n_no_coor = 8
+
+        let compressed_data = compress(&data)?;
+        let mut reader = Reader::new(&compressed_data[..]);
+        let index = reader.read_index()?;
+        assert_eq!(index.unplaced_unmapped_record_count(), Some(8));
+
         Ok(())
     }
 }
@@ -0,0 +1,92 @@
+//! Indexed FASTA reader.
+
+use std::io::{self, BufRead, Seek};
+
+use noodles_core::Region;
+
+use super::{fai, Reader, Record};
+
+/// An indexed FASTA reader.
+///
+/// This pairs a FASTA reader with its associated index (FAI) to allow querying by region.
+pub struct IndexedReader<R> {
+    inner: Reader<R>,
+    index: fai::Index,
+}
+
+impl<R> IndexedReader<R>
+where
+    R: BufRead,
+{
+    /// Creates an indexed FASTA reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, fai};
+    ///
+    /// let data = b">sq0\nACGT\n";
+    /// let index = vec![fai::Record::new(String::from("sq0"), 4, 5, 4, 5)];
+    ///
+    /// let reader = fasta::IndexedReader::new(&data[..], index);
+    /// ```
+    pub fn new(inner: R, index: fai::Index) -> Self {
+        Self {
+            inner: Reader::new(inner),
+            index,
+        }
+    }
+
+    /// Returns the associated index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, fai};
+    ///
+    /// let data = b">sq0\nACGT\n";
+    /// let index = vec![fai::Record::new(String::from("sq0"), 4, 5, 4, 5)];
+    ///
+    /// let reader = fasta::IndexedReader::new(&data[..], index);
+    /// assert_eq!(reader.index().len(), 1);
+    /// ```
+    pub fn index(&self) -> &[fai::Record] {
+        &self.index
+    }
+}
+
+impl<R> IndexedReader<R>
+where
+    R: BufRead + Seek,
+{
+    /// Returns a record of the given region.
+    ///
+    /// This is the same as [`Reader::query`] using the associated index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_core::Region;
+    /// use noodles_fasta::{self as fasta, fai};
+    ///
+    /// let data = b">sq0\nNNNN\n>sq1\nACGT\n";
+    /// let index = vec![
+    ///     fai::Record::new(String::from("sq0"), 4, 5, 4, 5),
+    ///     fai::Record::new(String::from("sq1"), 4, 15, 4, 5),
+    /// ];
+    ///
+    /// let mut reader = fasta::IndexedReader::new(Cursor::new(data), index);
+    ///
+    /// let region = Region::mapped("sq1", 2..=3);
+    /// let record = reader.query(&region)?;
+    /// assert_eq!(record, fasta::Record::new(
+    ///     fasta::record::Definition::new(String::from("sq1:2-3"), None),
+    ///     b"CG".to_vec(),
+    /// ));
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn query(&mut self, region: &Region) -> io::Result<Record> {
+        self.inner.query(&self.index, region)
+    }
+}
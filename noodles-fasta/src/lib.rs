@@ -53,12 +53,13 @@
 //! ```
 
 pub mod fai;
+mod indexed_reader;
 mod indexer;
 pub mod reader;
 pub mod record;
 mod writer;
 
-pub use self::{reader::Reader, record::Record, writer::Writer};
+pub use self::{indexed_reader::IndexedReader, reader::Reader, record::Record, writer::Writer};
 
 use std::{
     fs::File,
@@ -1,3 +1,7 @@
+mod builder;
+
+pub use self::builder::Builder;
+
 use std::{
     cmp,
     io::{self, Write},
@@ -5,17 +9,31 @@ use std::{
 
 use super::Record;
 
-const LINE_BASES: u64 = 80;
+const LINE_BASES: usize = 80;
 
 /// A FASTA writer.
 pub struct Writer<W> {
     inner: W,
+    line_bases: usize,
 }
 
 impl<W> Writer<W>
 where
     W: Write,
 {
+    /// Creates a FASTA writer builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// let builder = fasta::Writer::builder(Vec::new());
+    /// let writer = builder.build();
+    /// ```
+    pub fn builder(inner: W) -> Builder<W> {
+        Builder::new(inner)
+    }
+
     /// Creates a FASTA writer.
     ///
     /// # Examples
@@ -25,7 +43,7 @@ where
     /// let writer = fasta::Writer::new(Vec::new());
     /// ```
     pub fn new(inner: W) -> Self {
-        Self { inner }
+        Self::builder(inner).build()
     }
 
     /// Returns a reference to the underlying writer.
@@ -43,7 +61,8 @@ where
 
     /// Writes a FASTA record.
     ///
-    /// Sequence lines are hard wrapped at 80 bases.
+    /// Sequence lines are hard wrapped at the configured line width (default: 80 bases). See
+    /// [`Builder::set_line_width`].
     ///
     /// # Examples
     ///
@@ -63,7 +82,7 @@ where
     /// ```
     pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
         writeln!(self.inner, "{}", record.definition())?;
-        write_record_sequence(&mut self.inner, record.sequence(), LINE_BASES as usize)?;
+        write_record_sequence(&mut self.inner, record.sequence(), self.line_bases)?;
         Ok(())
     }
 }
@@ -72,6 +91,12 @@ fn write_record_sequence<W>(writer: &mut W, sequence: &[u8], line_bases: usize)
 where
     W: Write,
 {
+    if line_bases == 0 {
+        writer.write_all(sequence)?;
+        writeln!(writer)?;
+        return Ok(());
+    }
+
     let mut start = 0;
 
     while start < sequence.len() {
@@ -109,6 +134,40 @@ mod tests {
         write_record_sequence(&mut writer, b"ACGTACGTAC", 4)?;
         assert_eq!(writer, b"ACGT\nACGT\nAC\n");
 
+        writer.clear();
+        write_record_sequence(&mut writer, b"ACGTACGTAC", 0)?;
+        assert_eq!(writer, b"ACGTACGTAC\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_line_width() -> io::Result<()> {
+        use crate::record::Definition;
+
+        let definition = Definition::new(String::from("sq0"), Some(String::from("LN:150")));
+        let record = Record::new(definition, vec![b'A'; 150]);
+
+        let mut writer = Writer::builder(Vec::new()).set_line_width(60).build();
+        writer.write_record(&record)?;
+
+        let mut lines = writer.get_ref().split(|&b| b == b'\n');
+        assert_eq!(lines.next(), Some(&b">sq0 LN:150"[..]));
+        assert_eq!(lines.next().map(|line| line.len()), Some(60));
+        assert_eq!(lines.next().map(|line| line.len()), Some(60));
+        assert_eq!(lines.next().map(|line| line.len()), Some(30));
+        assert_eq!(lines.next(), Some(&b""[..]));
+        assert!(lines.next().is_none());
+
+        let mut writer = Writer::builder(Vec::new()).set_line_width(0).build();
+        writer.write_record(&record)?;
+
+        let mut lines = writer.get_ref().split(|&b| b == b'\n');
+        assert_eq!(lines.next(), Some(&b">sq0 LN:150"[..]));
+        assert_eq!(lines.next().map(|line| line.len()), Some(150));
+        assert_eq!(lines.next(), Some(&b""[..]));
+        assert!(lines.next().is_none());
+
         Ok(())
     }
 }
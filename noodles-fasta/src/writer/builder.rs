@@ -0,0 +1,54 @@
+use std::io::Write;
+
+use super::{Writer, LINE_BASES};
+
+/// A FASTA writer builder.
+pub struct Builder<W> {
+    inner: W,
+    line_bases: usize,
+}
+
+impl<W> Builder<W>
+where
+    W: Write,
+{
+    pub(crate) fn new(inner: W) -> Self {
+        Self {
+            inner,
+            line_bases: LINE_BASES,
+        }
+    }
+
+    /// Sets the number of bases per line.
+    ///
+    /// Sequence lines are hard wrapped at this width. A width of 0 disables wrapping, i.e., each
+    /// sequence is written on a single line.
+    ///
+    /// By default, this is 80.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// let builder = fasta::Writer::builder(Vec::new()).set_line_width(60);
+    /// ```
+    pub fn set_line_width(mut self, line_width: usize) -> Self {
+        self.line_bases = line_width;
+        self
+    }
+
+    /// Builds a FASTA writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// let writer = fasta::Writer::builder(Vec::new()).build();
+    /// ```
+    pub fn build(self) -> Writer<W> {
+        Writer {
+            inner: self.inner,
+            line_bases: self.line_bases,
+        }
+    }
+}
@@ -98,6 +98,8 @@ where
         n => n,
     };
 
+    record.split_name();
+
     len += read_line(reader, record.sequence_mut()).await?;
     len += consume_line(reader).await?;
     len += read_line(reader, record.quality_scores_mut()).await?;
@@ -78,6 +78,12 @@ where
 {
     writer.write_all(b"@").await?;
     writer.write_all(record.name()).await?;
+
+    if !record.description().is_empty() {
+        writer.write_all(b" ").await?;
+        writer.write_all(record.description()).await?;
+    }
+
     writer.write_all(b"\n").await?;
 
     writer.write_all(record.sequence()).await?;
@@ -103,6 +109,15 @@ mod tests {
         let expected = b"@r0\nACGT\n+\nNDLS\n";
         assert_eq!(buf, expected);
 
+        let mut record = Record::new("r0", "ACGT", "NDLS");
+        record.set_description("1:N:0:ACGT");
+
+        buf.clear();
+        write_record(&mut buf, &record).await?;
+
+        let expected = b"@r0 1:N:0:ACGT\nACGT\n+\nNDLS\n";
+        assert_eq!(buf, expected);
+
         Ok(())
     }
 }
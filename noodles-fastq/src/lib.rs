@@ -33,8 +33,8 @@ mod r#async;
 
 pub mod fai;
 mod indexer;
-mod reader;
-mod record;
+pub mod reader;
+pub mod record;
 mod writer;
 
 pub use self::{indexer::Indexer, reader::Reader, record::Record, writer::Writer};
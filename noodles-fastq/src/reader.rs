@@ -1,17 +1,29 @@
+//! FASTQ reader and record iterator.
+
+mod builder;
+mod parse_mode;
 mod records;
 
-pub use self::records::Records;
+pub use self::{builder::Builder, parse_mode::ParseMode, records::Records};
 
 use std::io::{self, BufRead, Read};
 
-use super::Record;
+use super::{
+    record::{ValidationError, DEFAULT_ALPHABET},
+    Record,
+};
 
 const LINE_FEED: u8 = b'\n';
 const CARRIAGE_RETURN: u8 = b'\r';
+const MISSING_QUALITY_SCORE: u8 = b'!';
 
 /// A FASTQ reader.
 pub struct Reader<R> {
     inner: R,
+    validate: bool,
+    alphabet: Vec<u8>,
+    parse_mode: ParseMode,
+    length_mismatch_count: u64,
 }
 
 impl<R> Reader<R>
@@ -28,7 +40,26 @@ where
     /// let reader = fastq::Reader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            validate: false,
+            alphabet: DEFAULT_ALPHABET.to_vec(),
+            parse_mode: ParseMode::default(),
+            length_mismatch_count: 0,
+        }
+    }
+
+    /// Returns a FASTQ reader builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let data = b"@r0\nATCG\n+\nNDLS\n";
+    /// let reader = fastq::Reader::builder(&data[..]).set_validate(true).build();
+    /// ```
+    pub fn builder(inner: R) -> Builder<R> {
+        Builder::new(inner)
     }
 
     /// Reads a FASTQ record.
@@ -59,7 +90,139 @@ where
     /// Ok::<(), io::Error>(())
     /// ```
     pub fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
-        read_record(&mut self.inner, record)
+        let n = read_record(&mut self.inner, record)?;
+
+        if n == 0 {
+            return Ok(n);
+        }
+
+        let sequence_len = record.sequence().len();
+
+        match self.parse_mode {
+            ParseMode::Strict => {
+                let quality_scores_len = record.quality_scores().len();
+
+                if sequence_len != quality_scores_len {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        ValidationError::LengthMismatch(sequence_len, quality_scores_len),
+                    ));
+                }
+            }
+            ParseMode::Lenient => {
+                if resize_quality_scores(sequence_len, record.quality_scores_mut()) {
+                    self.length_mismatch_count += 1;
+                }
+            }
+        }
+
+        if self.validate {
+            record
+                .validate_with_alphabet(&self.alphabet)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        }
+
+        Ok(n)
+    }
+
+    /// Returns the parse mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::{self as fastq, reader::ParseMode};
+    /// let data = b"@r0\nATCG\n+\nNDLS\n";
+    /// let reader = fastq::Reader::new(&data[..]);
+    /// assert_eq!(reader.parse_mode(), ParseMode::Strict);
+    /// ```
+    pub fn parse_mode(&self) -> ParseMode {
+        self.parse_mode
+    }
+
+    /// Returns the number of records read with mismatched sequence and quality scores lengths.
+    ///
+    /// Mismatches are only counted in lenient mode (see [`ParseMode::Lenient`]), where they are
+    /// corrected instead of returning an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::{self as fastq, reader::ParseMode};
+    ///
+    /// let data = b"@r0\nATCG\n+\nNDL\n";
+    /// let mut reader = fastq::Reader::builder(&data[..])
+    ///     .set_parse_mode(ParseMode::Lenient)
+    ///     .build();
+    ///
+    /// let mut record = fastq::Record::default();
+    /// reader.read_record(&mut record)?;
+    ///
+    /// assert_eq!(record.quality_scores(), b"NDL!");
+    /// assert_eq!(reader.length_mismatch_count(), 1);
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn length_mismatch_count(&self) -> u64 {
+        self.length_mismatch_count
+    }
+
+    /// Reads a pair of records from an interleaved FASTQ.
+    ///
+    /// In an interleaved FASTQ, the records of a pair are consecutive, i.e., the first segment is
+    /// directly followed by the last segment. The names of the two records must match. If either
+    /// name has a trailing segment suffix (`/1` or `/2`), the name of the first record must end
+    /// with `/1`, the name of the last record must end with `/2`, and the names must match without
+    /// the suffixes. Descriptions are not compared.
+    ///
+    /// If successful, the total number of bytes read is returned. If the number of bytes read is
+    /// 0, the stream reached EOF. An error of kind [`io::ErrorKind::UnexpectedEof`] is returned if
+    /// the stream ends after the first record of a pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    ///
+    /// let data = b"@r0/1\nATCG\n+\nNDLS\n@r0/2\nCGAT\n+\nSLDN\n";
+    /// let mut reader = fastq::Reader::new(&data[..]);
+    ///
+    /// let mut r1 = fastq::Record::default();
+    /// let mut r2 = fastq::Record::default();
+    /// reader.read_pair(&mut r1, &mut r2)?;
+    ///
+    /// assert_eq!(r1.sequence(), b"ATCG");
+    /// assert_eq!(r2.sequence(), b"CGAT");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn read_pair(&mut self, r1: &mut Record, r2: &mut Record) -> io::Result<usize> {
+        let mut len = match self.read_record(r1)? {
+            0 => return Ok(0),
+            n => n,
+        };
+
+        len += match self.read_record(r2)? {
+            0 => {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "missing mate record",
+                ))
+            }
+            n => n,
+        };
+
+        if !is_mate_name_pair(r1.name(), r2.name()) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "mate name mismatch: {} != {}",
+                    String::from_utf8_lossy(r1.name()),
+                    String::from_utf8_lossy(r2.name())
+                ),
+            ));
+        }
+
+        Ok(len)
     }
 
     /// Returns an iterator over records starting from the current stream position.
@@ -102,6 +265,8 @@ where
         Err(e) => return Err(e),
     };
 
+    record.split_name();
+
     len += read_line(reader, record.sequence_mut())?;
     len += consume_line(reader)?;
     len += read_line(reader, record.quality_scores_mut())?;
@@ -109,6 +274,32 @@ where
     Ok(len)
 }
 
+// Truncates or pads the quality scores to the sequence length. This returns whether the lengths
+// differed.
+fn resize_quality_scores(sequence_len: usize, quality_scores: &mut Vec<u8>) -> bool {
+    if quality_scores.len() == sequence_len {
+        false
+    } else {
+        quality_scores.resize(sequence_len, MISSING_QUALITY_SCORE);
+        true
+    }
+}
+
+fn is_mate_name_pair(r1_name: &[u8], r2_name: &[u8]) -> bool {
+    fn has_segment_suffix(name: &[u8]) -> bool {
+        name.ends_with(b"/1") || name.ends_with(b"/2")
+    }
+
+    if has_segment_suffix(r1_name) || has_segment_suffix(r2_name) {
+        match (r1_name.strip_suffix(b"/1"), r2_name.strip_suffix(b"/2")) {
+            (Some(r1_base_name), Some(r2_base_name)) => r1_base_name == r2_base_name,
+            _ => false,
+        }
+    } else {
+        r1_name == r2_name
+    }
+}
+
 fn consume_line<R>(reader: &mut R) -> io::Result<usize>
 where
     R: BufRead,
@@ -218,6 +409,152 @@ dcba
         Ok(())
     }
 
+    #[test]
+    fn test_read_record_with_description() -> io::Result<()> {
+        let data = b"@r0 1:N:0:ACGT\nAGCT\n+\nabcd\n";
+
+        let mut reader = &data[..];
+        let mut record = Record::default();
+        read_record(&mut reader, &mut record)?;
+
+        let mut expected = Record::new("r0", "AGCT", "abcd");
+        expected.set_description("1:N:0:ACGT");
+        assert_eq!(record, expected);
+        assert_eq!(record.name(), b"r0");
+        assert_eq!(record.description(), b"1:N:0:ACGT");
+
+        let n = read_record(&mut reader, &mut record)?;
+        assert_eq!(n, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_validation() -> io::Result<()> {
+        let data = b"@r0\nAGCU\n+\nabcd\n";
+
+        let mut reader = Reader::builder(&data[..]).set_validate(true).build();
+        let mut record = Record::default();
+        reader.read_record(&mut record)?;
+
+        let mut reader = Reader::builder(&data[..])
+            .set_validate(true)
+            .set_alphabet(b"ACGT".to_vec())
+            .build();
+        assert!(matches!(
+            reader.read_record(&mut record),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_parse_mode() -> io::Result<()> {
+        let data = b"@r0\nAGCT\n+\nabc\n@r1\nAG\n+\nabc\n@r2\nAG\n+\nab\n";
+
+        // Strict mode rejects length mismatches with or without validation.
+        for validate in [false, true].iter().copied() {
+            let mut reader = Reader::builder(&data[..]).set_validate(validate).build();
+            let mut record = Record::default();
+            assert!(matches!(
+                reader.read_record(&mut record),
+                Err(ref e) if e.kind() == io::ErrorKind::InvalidData
+            ));
+        }
+
+        let mut record = Record::default();
+
+        let mut reader = Reader::builder(&data[..])
+            .set_validate(true)
+            .set_parse_mode(ParseMode::Lenient)
+            .build();
+
+        reader.read_record(&mut record)?;
+        assert_eq!(record, Record::new("r0", "AGCT", "abc!"));
+
+        reader.read_record(&mut record)?;
+        assert_eq!(record, Record::new("r1", "AG", "ab"));
+
+        reader.read_record(&mut record)?;
+        assert_eq!(record, Record::new("r2", "AG", "ab"));
+
+        assert_eq!(reader.length_mismatch_count(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_pair() -> io::Result<()> {
+        let data = b"\
+@r0/1
+AGCT
++
+abcd
+@r0/2
+TCGA
++
+dcba
+@r1 1:N:0:ACGT
+AGCT
++
+abcd
+@r1 2:N:0:ACGT
+TCGA
++
+dcba
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let mut r1 = Record::default();
+        let mut r2 = Record::default();
+
+        reader.read_pair(&mut r1, &mut r2)?;
+        assert_eq!(r1, Record::new("r0/1", "AGCT", "abcd"));
+        assert_eq!(r2, Record::new("r0/2", "TCGA", "dcba"));
+
+        reader.read_pair(&mut r1, &mut r2)?;
+        assert_eq!(r1.name(), b"r1");
+        assert_eq!(r1.description(), b"1:N:0:ACGT");
+        assert_eq!(r2.name(), b"r1");
+        assert_eq!(r2.description(), b"2:N:0:ACGT");
+
+        assert_eq!(reader.read_pair(&mut r1, &mut r2)?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_pair_with_mismatched_names() {
+        let mut r1 = Record::default();
+        let mut r2 = Record::default();
+
+        for (r1_name, r2_name) in &[
+            ("r0/1", "r1/2"),
+            ("r0/1", "r0/1"),
+            ("r0/2", "r0/1"),
+            ("r0/2", "r0/2"),
+            ("r0", "r0/2"),
+            ("r0/1", "r0"),
+        ] {
+            let data = format!("@{}\nAGCT\n+\nabcd\n@{}\nTCGA\n+\ndcba\n", r1_name, r2_name);
+            let mut reader = Reader::new(data.as_bytes());
+
+            assert!(matches!(
+                reader.read_pair(&mut r1, &mut r2),
+                Err(ref e) if e.kind() == io::ErrorKind::InvalidData
+            ));
+        }
+
+        let data = b"@r0/1\nAGCT\n+\nabcd\n";
+        let mut reader = Reader::new(&data[..]);
+
+        assert!(matches!(
+            reader.read_pair(&mut r1, &mut r2),
+            Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+    }
+
     #[test]
     fn test_consume_line() -> io::Result<()> {
         fn t(mut data: &[u8], expected: &[u8]) -> io::Result<()> {
@@ -0,0 +1,97 @@
+use std::io::BufRead;
+
+use super::{ParseMode, Reader};
+use crate::record::DEFAULT_ALPHABET;
+
+/// A FASTQ reader builder.
+pub struct Builder<R> {
+    inner: R,
+    validate: bool,
+    alphabet: Vec<u8>,
+    parse_mode: ParseMode,
+}
+
+impl<R> Builder<R>
+where
+    R: BufRead,
+{
+    pub(crate) fn new(inner: R) -> Self {
+        Self {
+            inner,
+            validate: false,
+            alphabet: DEFAULT_ALPHABET.to_vec(),
+            parse_mode: ParseMode::default(),
+        }
+    }
+
+    /// Sets whether each read record is validated.
+    ///
+    /// When enabled, the reader returns an error of kind [`std::io::ErrorKind::InvalidData`] for
+    /// a record that fails [`crate::Record::validate_with_alphabet`]. By default, records are not
+    /// validated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let data = b"@r0\nATCG\n+\nNDLS\n";
+    /// let builder = fastq::Reader::builder(&data[..]).set_validate(true);
+    /// ```
+    pub fn set_validate(mut self, validate: bool) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    /// Sets the alphabet of valid sequence bases used for validation.
+    ///
+    /// By default, this is [`crate::record::DEFAULT_ALPHABET`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let data = b"@r0\nATCG\n+\nNDLS\n";
+    /// let builder = fastq::Reader::builder(&data[..])
+    ///     .set_validate(true)
+    ///     .set_alphabet(b"ACGT".to_vec());
+    /// ```
+    pub fn set_alphabet(mut self, alphabet: Vec<u8>) -> Self {
+        self.alphabet = alphabet;
+        self
+    }
+
+    /// Sets the parse mode.
+    ///
+    /// By default, this is [`ParseMode::Strict`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::{self as fastq, reader::ParseMode};
+    /// let data = b"@r0\nATCG\n+\nNDLS\n";
+    /// let builder = fastq::Reader::builder(&data[..]).set_parse_mode(ParseMode::Lenient);
+    /// ```
+    pub fn set_parse_mode(mut self, parse_mode: ParseMode) -> Self {
+        self.parse_mode = parse_mode;
+        self
+    }
+
+    /// Builds a FASTQ reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let data = b"@r0\nATCG\n+\nNDLS\n";
+    /// let reader = fastq::Reader::builder(&data[..]).build();
+    /// ```
+    pub fn build(self) -> Reader<R> {
+        Reader {
+            inner: self.inner,
+            validate: self.validate,
+            alphabet: self.alphabet,
+            parse_mode: self.parse_mode,
+            length_mismatch_count: 0,
+        }
+    }
+}
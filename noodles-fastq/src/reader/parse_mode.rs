@@ -0,0 +1,21 @@
+/// A FASTQ record parse mode.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseMode {
+    /// A record with a sequence and quality scores of different lengths is an error.
+    ///
+    /// This is independent of whether records are validated (see
+    /// [`super::Builder::set_validate`]).
+    Strict,
+    /// A record with a sequence and quality scores of different lengths is corrected.
+    ///
+    /// Quality scores longer than the sequence are truncated, and quality scores shorter than the
+    /// sequence are padded with the lowest score (`!`). Each corrected record is counted (see
+    /// [`super::Reader::length_mismatch_count`]).
+    Lenient,
+}
+
+impl Default for ParseMode {
+    fn default() -> Self {
+        Self::Strict
+    }
+}
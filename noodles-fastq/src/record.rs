@@ -1,9 +1,17 @@
-use std::fmt;
+//! FASTQ record and validation.
+
+use std::{error, fmt};
+
+/// The default alphabet of valid sequence bases.
+///
+/// This is the set of IUPAC nucleotide codes in either case.
+pub const DEFAULT_ALPHABET: &[u8] = b"ACGTUNRYSWKMBDHVacgtunryswkmbdhv";
 
 /// A FASTQ record.
 #[derive(Clone, Default, Debug, Eq, PartialEq)]
 pub struct Record {
     name: Vec<u8>,
+    description: Vec<u8>,
     sequence: Vec<u8>,
     quality_scores: Vec<u8>,
 }
@@ -25,6 +33,7 @@ impl Record {
     {
         Self {
             name: name.into(),
+            description: Vec::new(),
             sequence: sequence.into(),
             quality_scores: quality_scores.into(),
         }
@@ -61,6 +70,50 @@ impl Record {
         &mut self.name
     }
 
+    /// Returns the description of the record.
+    ///
+    /// The description is the optional part of the name line after the first space. It is empty
+    /// if the name line has no description.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::Record;
+    /// let record = Record::new("r0", "AGCT", "NDLS");
+    /// assert!(record.description().is_empty());
+    /// ```
+    pub fn description(&self) -> &[u8] {
+        &self.description
+    }
+
+    /// Sets the description of the record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::Record;
+    ///
+    /// let mut record = Record::new("r0", "AGCT", "NDLS");
+    /// record.set_description("1:N:0:ACGT");
+    ///
+    /// assert_eq!(record.description(), b"1:N:0:ACGT");
+    /// assert_eq!(record.to_string(), "@r0 1:N:0:ACGT\nAGCT\n+\nNDLS\n");
+    /// ```
+    pub fn set_description<D>(&mut self, description: D)
+    where
+        D: Into<Vec<u8>>,
+    {
+        self.description = description.into();
+    }
+
+    // Splits a raw name line into the name and description at the first space.
+    pub(crate) fn split_name(&mut self) {
+        if let Some(i) = self.name.iter().position(|&b| b == b' ') {
+            self.description = self.name.split_off(i + 1);
+            self.name.truncate(i);
+        }
+    }
+
     /// Returns the sequence of the record.
     ///
     /// # Examples
@@ -95,9 +148,100 @@ impl Record {
         &mut self.quality_scores
     }
 
+    /// Validates the record using the default alphabet.
+    ///
+    /// See [`Self::validate_with_alphabet`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::{record::ValidationError, Record};
+    ///
+    /// let record = Record::new("r0", "AGCT", "NDLS");
+    /// assert!(record.validate().is_ok());
+    ///
+    /// let record = Record::new("r0", "AGCT", "NDL");
+    /// assert_eq!(record.validate(), Err(ValidationError::LengthMismatch(4, 3)));
+    /// ```
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        self.validate_with_alphabet(DEFAULT_ALPHABET)
+    }
+
+    /// Validates the record using the given alphabet of valid sequence bases.
+    ///
+    /// This checks that the sequence and quality scores have the same length and that each base
+    /// in the sequence is in the alphabet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::{record::ValidationError, Record};
+    ///
+    /// let record = Record::new("r0", "AGCU", "NDLS");
+    /// assert!(record.validate_with_alphabet(b"ACGTU").is_ok());
+    /// assert_eq!(
+    ///     record.validate_with_alphabet(b"ACGT"),
+    ///     Err(ValidationError::InvalidBase(3, b'U'))
+    /// );
+    /// ```
+    pub fn validate_with_alphabet(&self, alphabet: &[u8]) -> Result<(), ValidationError> {
+        let sequence_len = self.sequence().len();
+        let quality_scores_len = self.quality_scores().len();
+
+        if sequence_len != quality_scores_len {
+            return Err(ValidationError::LengthMismatch(
+                sequence_len,
+                quality_scores_len,
+            ));
+        }
+
+        if let Some((i, &b)) = self
+            .sequence()
+            .iter()
+            .enumerate()
+            .find(|(_, b)| !alphabet.contains(b))
+        {
+            return Err(ValidationError::InvalidBase(i, b));
+        }
+
+        Ok(())
+    }
+
+    /// Reverse complements the record in place.
+    ///
+    /// The sequence is reversed and each base is replaced by its complement, and the quality
+    /// scores are reversed.
+    ///
+    /// Bases are complemented using the IUPAC nucleotide codes, i.e., `A` ↔ `T`, `C` ↔ `G`,
+    /// `R` ↔ `Y`, `K` ↔ `M`, `B` ↔ `V`, and `D` ↔ `H`. `S`, `W`, and `N` are their own
+    /// complements. `U` is complemented to `A`. Case is preserved, and any other byte is left
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::Record;
+    ///
+    /// let mut record = Record::new("r0", "AGCTn", "NDLS#");
+    /// record.reverse_complement();
+    ///
+    /// assert_eq!(record.sequence(), b"nAGCT");
+    /// assert_eq!(record.quality_scores(), b"#SLDN");
+    /// ```
+    pub fn reverse_complement(&mut self) {
+        self.sequence.reverse();
+
+        for b in &mut self.sequence {
+            *b = complement(*b);
+        }
+
+        self.quality_scores.reverse();
+    }
+
     // Truncates all field buffers to 0.
     pub(crate) fn clear(&mut self) {
         self.name.clear();
+        self.description.clear();
         self.sequence.clear();
         self.quality_scores.clear();
     }
@@ -111,6 +255,14 @@ impl fmt::Display for Record {
             write!(f, "{}", b as char)?;
         }
 
+        if !self.description().is_empty() {
+            f.write_str(" ")?;
+
+            for &b in self.description() {
+                write!(f, "{}", b as char)?;
+            }
+        }
+
         writeln!(f)?;
 
         for &b in self.sequence() {
@@ -131,6 +283,60 @@ impl fmt::Display for Record {
     }
 }
 
+fn complement(b: u8) -> u8 {
+    let c = match b.to_ascii_uppercase() {
+        b'A' => b'T',
+        b'C' => b'G',
+        b'G' => b'C',
+        b'T' | b'U' => b'A',
+        b'R' => b'Y',
+        b'Y' => b'R',
+        b'K' => b'M',
+        b'M' => b'K',
+        b'B' => b'V',
+        b'V' => b'B',
+        b'D' => b'H',
+        b'H' => b'D',
+        _ => return b,
+    };
+
+    if b.is_ascii_lowercase() {
+        c.to_ascii_lowercase()
+    } else {
+        c
+    }
+}
+
+/// An error returned when a FASTQ record fails validation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidationError {
+    /// The sequence and quality scores lengths differ.
+    ///
+    /// This includes the sequence length and the quality scores length, respectively.
+    LengthMismatch(usize, usize),
+    /// A sequence base is not in the alphabet.
+    ///
+    /// This includes the position of the base in the sequence and the base.
+    InvalidBase(usize, u8),
+}
+
+impl error::Error for ValidationError {}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LengthMismatch(sequence_len, quality_scores_len) => write!(
+                f,
+                "sequence length ({}) does not match quality scores length ({})",
+                sequence_len, quality_scores_len
+            ),
+            Self::InvalidBase(i, b) => {
+                write!(f, "invalid base at position {}: {:?}", i, char::from(*b))
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -139,6 +345,58 @@ mod tests {
     fn test_fmt() {
         let record = Record::new("r0", "ATCG", "NDLS");
         assert_eq!(record.to_string(), "@r0\nATCG\n+\nNDLS\n");
+
+        let mut record = Record::new("r0", "ATCG", "NDLS");
+        record.set_description("1:N:0:ACGT");
+        assert_eq!(record.to_string(), "@r0 1:N:0:ACGT\nATCG\n+\nNDLS\n");
+    }
+
+    #[test]
+    fn test_split_name() {
+        let mut record = Record::new("r0 1:N:0:ACGT extra", "ATCG", "NDLS");
+        record.split_name();
+        assert_eq!(record.name(), b"r0");
+        assert_eq!(record.description(), b"1:N:0:ACGT extra");
+
+        let mut record = Record::new("r0", "ATCG", "NDLS");
+        record.split_name();
+        assert_eq!(record.name(), b"r0");
+        assert!(record.description().is_empty());
+    }
+
+    #[test]
+    fn test_validate() {
+        let record = Record::new("r0", "ACGTN", "NDLS#");
+        assert!(record.validate().is_ok());
+
+        let record = Record::new("r0", "ACGT", "NDLS#");
+        assert_eq!(
+            record.validate(),
+            Err(ValidationError::LengthMismatch(4, 5))
+        );
+
+        let record = Record::new("r0", "AC.T", "NDLS");
+        assert_eq!(
+            record.validate(),
+            Err(ValidationError::InvalidBase(2, b'.'))
+        );
+    }
+
+    #[test]
+    fn test_reverse_complement() {
+        let mut record = Record::new("r0", "AGCTN", "!!!!!");
+        record.reverse_complement();
+        assert_eq!(record.sequence(), b"NAGCT");
+        assert_eq!(record.quality_scores(), b"!!!!!");
+
+        let mut record = Record::new("r0", "ACGTacgt", "ABCDEFGH");
+        record.reverse_complement();
+        assert_eq!(record.sequence(), b"acgtACGT");
+        assert_eq!(record.quality_scores(), b"HGFEDCBA");
+
+        let mut record = Record::new("r0", "RYKMSWBDHVNU", "############");
+        record.reverse_complement();
+        assert_eq!(record.sequence(), b"ANBDHVWSKMRY");
     }
 
     #[test]
@@ -147,6 +405,7 @@ mod tests {
         record.clear();
 
         assert!(record.name().is_empty());
+        assert!(record.description().is_empty());
         assert!(record.sequence().is_empty());
         assert!(record.quality_scores().is_empty());
     }
@@ -55,6 +55,30 @@ where
     pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
         write_record(&mut self.inner, record)
     }
+
+    /// Writes a pair of records to an interleaved FASTQ.
+    ///
+    /// The first segment is written followed by the last segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    ///
+    /// let mut writer = fastq::Writer::new(Vec::new());
+    ///
+    /// let r1 = fastq::Record::new("r0/1", "ATCG", "NDLS");
+    /// let r2 = fastq::Record::new("r0/2", "CGAT", "SLDN");
+    /// writer.write_pair(&r1, &r2)?;
+    ///
+    /// assert_eq!(writer.get_ref(), b"@r0/1\nATCG\n+\nNDLS\n@r0/2\nCGAT\n+\nSLDN\n");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn write_pair(&mut self, r1: &Record, r2: &Record) -> io::Result<()> {
+        write_record(&mut self.inner, r1)?;
+        write_record(&mut self.inner, r2)
+    }
 }
 
 fn write_record<W>(writer: &mut W, record: &Record) -> io::Result<()>
@@ -63,6 +87,12 @@ where
 {
     writer.write_all(b"@")?;
     writer.write_all(record.name())?;
+
+    if !record.description().is_empty() {
+        writer.write_all(b" ")?;
+        writer.write_all(record.description())?;
+    }
+
     writer.write_all(b"\n")?;
 
     writer.write_all(record.sequence())?;
@@ -88,6 +118,15 @@ mod tests {
         let expected = b"@r0\nACGT\n+\nNDLS\n";
         assert_eq!(buf, expected);
 
+        let mut record = Record::new("r0", "ACGT", "NDLS");
+        record.set_description("1:N:0:ACGT");
+
+        buf.clear();
+        write_record(&mut buf, &record)?;
+
+        let expected = b"@r0 1:N:0:ACGT\nACGT\n+\nNDLS\n";
+        assert_eq!(buf, expected);
+
         Ok(())
     }
 }
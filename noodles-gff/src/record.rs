@@ -2,9 +2,9 @@
 
 pub mod attributes;
 mod builder;
-mod field;
+pub mod field;
 mod phase;
-mod strand;
+pub mod strand;
 
 pub use self::{
     attributes::Attributes, builder::Builder, field::Field, phase::Phase, strand::Strand,
@@ -317,6 +317,41 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_from_str_with_score() {
+        fn t(score: &str) -> Result<Record, ParseError> {
+            let s = format!("sq0\tNOODLES\tgene\t8\t13\t{}\t+\t.\tgene_id=ndls0", score);
+            s.parse()
+        }
+
+        assert_eq!(t("0.95").map(|record| record.score()), Ok(Some(0.95)));
+        assert_eq!(t("1e-3").map(|record| record.score()), Ok(Some(0.001)));
+        assert_eq!(t("-2.5E2").map(|record| record.score()), Ok(Some(-250.0)));
+        assert_eq!(t(".").map(|record| record.score()), Ok(None));
+
+        assert!(matches!(t("high"), Err(ParseError::InvalidScore(_))));
+    }
+
+    #[test]
+    fn test_from_str_with_strand() {
+        fn t(strand: &str) -> Result<Record, ParseError> {
+            let s = format!("sq0\tNOODLES\tgene\t8\t13\t.\t{}\t.\tgene_id=ndls0", strand);
+            s.parse()
+        }
+
+        assert_eq!(t(".").map(|record| record.strand()), Ok(Strand::None));
+        assert_eq!(t("+").map(|record| record.strand()), Ok(Strand::Forward));
+        assert_eq!(t("-").map(|record| record.strand()), Ok(Strand::Reverse));
+        assert_eq!(t("?").map(|record| record.strand()), Ok(Strand::Unknown));
+
+        assert_eq!(
+            t("*"),
+            Err(ParseError::InvalidStrand(strand::ParseError::Invalid(
+                String::from("*")
+            )))
+        );
+    }
+
     #[test]
     fn test_from_str_with_cds_feature_and_no_phase() {
         let s = "sq0\tNOODLES\tCDS\t8\t13\t.\t+\t.\tgene_id=ndls0;gene_name=gene0";
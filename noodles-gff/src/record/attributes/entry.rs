@@ -19,17 +19,23 @@ const PERCENT_ENCODE_SET: &AsciiSet = &CONTROLS
     .add(b',');
 
 const SEPARATOR: char = '=';
+const VALUE_DELIMITER: char = ',';
 
 /// A GFF record attribute entry.
+///
+/// An entry value can be a list of values, e.g., `Parent=gene0,gene1`. Values are delimited by
+/// unescaped commas (`,`). An escaped comma (`%2C`) is part of a value.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Entry {
     key: String,
-    value: String,
+    values: Vec<String>,
 }
 
 impl Entry {
     /// Creates a GFF record attribute.
     ///
+    /// The value is a single value, i.e., any commas in it are part of the value.
+    ///
     /// # Examples
     ///
     /// ```
@@ -43,7 +49,37 @@ impl Entry {
     {
         Self {
             key: key.into(),
-            value: value.into(),
+            values: vec![value.into()],
+        }
+    }
+
+    /// Creates a GFF record attribute with a list of values.
+    ///
+    /// This returns an error if the list of values is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noodles_gff::record::attributes::entry::FromValuesError;
+    /// use noodles_gff::record::attributes::Entry;
+    ///
+    /// let entry = Entry::from_values("Parent", vec![String::from("gene0"), String::from("gene1")])?;
+    /// assert_eq!(entry.to_string(), "Parent=gene0,gene1");
+    ///
+    /// assert_eq!(Entry::from_values("Parent", Vec::new()), Err(FromValuesError::Empty));
+    /// # Ok::<(), FromValuesError>(())
+    /// ```
+    pub fn from_values<K>(key: K, values: Vec<String>) -> Result<Self, FromValuesError>
+    where
+        K: Into<String>,
+    {
+        if values.is_empty() {
+            Err(FromValuesError::Empty)
+        } else {
+            Ok(Self {
+                key: key.into(),
+                values,
+            })
         }
     }
 
@@ -62,6 +98,12 @@ impl Entry {
 
     /// Returns the value of the entry.
     ///
+    /// The value is percent-decoded, e.g., `%3B` is `;`. It is percent-encoded again when the
+    /// entry is formatted.
+    ///
+    /// If the entry has a list of values, this is only the first value. Use [`Self::values`] to
+    /// get all the values.
+    ///
     /// # Examples
     ///
     /// ```
@@ -70,19 +112,60 @@ impl Entry {
     /// assert_eq!(entry.value(), "gene0");
     /// ```
     pub fn value(&self) -> &str {
-        &self.value
+        // An entry always has at least one value.
+        &self.values[0]
+    }
+
+    /// Returns an iterator over the values of the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noodles_gff::record::attributes::entry::ParseError;
+    /// use noodles_gff::record::attributes::Entry;
+    ///
+    /// let entry: Entry = "Parent=gene0,gene1".parse()?;
+    /// assert_eq!(entry.values().collect::<Vec<_>>(), ["gene0", "gene1"]);
+    ///
+    /// let entry: Entry = "Note=13%2C21".parse()?;
+    /// assert_eq!(entry.values().collect::<Vec<_>>(), ["13,21"]);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn values(&self) -> impl Iterator<Item = &str> {
+        self.values.iter().map(|value| value.as_str())
     }
 }
 
 impl fmt::Display for Entry {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}{}{}",
-            percent_encode(self.key()),
-            SEPARATOR,
-            percent_encode(self.value())
-        )
+        write!(f, "{}{}", percent_encode(self.key()), SEPARATOR)?;
+
+        for (i, value) in self.values().enumerate() {
+            if i > 0 {
+                write!(f, "{}", VALUE_DELIMITER)?;
+            }
+
+            write!(f, "{}", percent_encode(value))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned when a GFF record attribute entry fails to be created from a list of values.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FromValuesError {
+    /// The list of values is empty.
+    Empty,
+}
+
+impl error::Error for FromValuesError {}
+
+impl fmt::Display for FromValuesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty values"),
+        }
     }
 }
 
@@ -101,6 +184,10 @@ pub enum ParseError {
     MissingValue,
     /// The entry value is invalid.
     InvalidValue(str::Utf8Error),
+    /// The entry key or value has an invalid percent-encoded sequence.
+    ///
+    /// A percent sign (`%`) must be followed by two hexadecimal digits.
+    InvalidEncoding,
 }
 
 impl error::Error for ParseError {}
@@ -114,6 +201,7 @@ impl fmt::Display for ParseError {
             Self::InvalidKey(e) => write!(f, "invalid key: {}", e),
             Self::MissingValue => f.write_str("missing value"),
             Self::InvalidValue(e) => write!(f, "invalid value: {}", e),
+            Self::InvalidEncoding => f.write_str("invalid percent encoding"),
         }
     }
 }
@@ -128,25 +216,58 @@ impl FromStr for Entry {
 
         match s.split_once(SEPARATOR) {
             Some((k, v)) => {
+                if !is_valid_percent_encoding(k) || !is_valid_percent_encoding(v) {
+                    return Err(ParseError::InvalidEncoding);
+                }
+
                 let key = if k.is_empty() {
                     return Err(ParseError::MissingKey);
                 } else {
                     percent_decode(k).map_err(ParseError::InvalidKey)?
                 };
 
-                let value = if v.is_empty() {
+                if v.is_empty() {
                     return Err(ParseError::MissingValue);
-                } else {
-                    percent_decode(v).map_err(ParseError::InvalidValue)?
-                };
+                }
+
+                let values = v
+                    .split(VALUE_DELIMITER)
+                    .map(|s| {
+                        percent_decode(s)
+                            .map(|value| value.into_owned())
+                            .map_err(ParseError::InvalidValue)
+                    })
+                    .collect::<Result<_, _>>()?;
 
-                Ok(Self::new(key, value))
+                Ok(Self {
+                    key: key.into_owned(),
+                    values,
+                })
             }
             None => Err(ParseError::Invalid),
         }
     }
 }
 
+fn is_valid_percent_encoding(s: &str) -> bool {
+    let mut bytes = s.bytes();
+
+    while let Some(b) = bytes.next() {
+        if b == b'%' {
+            let is_valid = matches!(
+                (bytes.next(), bytes.next()),
+                (Some(h), Some(l)) if h.is_ascii_hexdigit() && l.is_ascii_hexdigit()
+            );
+
+            if !is_valid {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
 fn percent_decode(s: &str) -> Result<Cow<'_, str>, str::Utf8Error> {
     percent_decode_str(s).decode_utf8()
 }
@@ -166,6 +287,57 @@ mod tests {
 
         let entry = Entry::new("%s", "13,21");
         assert_eq!(entry.to_string(), "%25s=13%2C21");
+
+        let entry =
+            Entry::from_values("Parent", vec![String::from("a"), String::from("b,c")]).unwrap();
+        assert_eq!(entry.to_string(), "Parent=a,b%2Cc");
+    }
+
+    #[test]
+    fn test_from_values() {
+        assert_eq!(
+            Entry::from_values("Parent", vec![String::from("a"), String::from("b")]),
+            Ok("Parent=a,b".parse().unwrap())
+        );
+
+        assert_eq!(
+            Entry::from_values("Parent", Vec::new()),
+            Err(FromValuesError::Empty)
+        );
+    }
+
+    #[test]
+    fn test_eq() -> Result<(), ParseError> {
+        let entry: Entry = "Parent=a,b".parse()?;
+        assert_eq!(
+            entry,
+            Entry::from_values("Parent", vec![String::from("a"), String::from("b")]).unwrap()
+        );
+        assert_ne!(entry, Entry::new("Parent", "a"));
+
+        // A single value with a comma is not a list of values.
+        let entry: Entry = "Note=a,b".parse()?;
+        assert_ne!(entry, Entry::new("Note", "a,b"));
+        assert_eq!("Note=a%2Cb".parse(), Ok(Entry::new("Note", "a,b")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_values() -> Result<(), ParseError> {
+        let entry: Entry = "Parent=a,b,c".parse()?;
+        assert_eq!(entry.values().collect::<Vec<_>>(), ["a", "b", "c"]);
+        assert_eq!(entry.value(), "a");
+        assert_eq!(entry.to_string(), "Parent=a,b,c");
+
+        let entry: Entry = "Note=a%2Cb,c".parse()?;
+        assert_eq!(entry.values().collect::<Vec<_>>(), ["a,b", "c"]);
+        assert_eq!(entry.to_string(), "Note=a%2Cb,c");
+
+        let entry = Entry::new("Note", "a,b");
+        assert_eq!(entry.values().collect::<Vec<_>>(), ["a,b"]);
+
+        Ok(())
     }
 
     #[test]
@@ -180,5 +352,32 @@ mod tests {
         assert_eq!("gene_name".parse::<Entry>(), Err(ParseError::Invalid));
         assert_eq!("=gene0".parse::<Entry>(), Err(ParseError::MissingKey));
         assert_eq!("gene_name=".parse::<Entry>(), Err(ParseError::MissingValue));
+
+        assert_eq!(
+            "%zz=gene0".parse::<Entry>(),
+            Err(ParseError::InvalidEncoding)
+        );
+        assert_eq!(
+            "gene_name=gene%3".parse::<Entry>(),
+            Err(ParseError::InvalidEncoding)
+        );
+        assert_eq!(
+            "gene_name=100%".parse::<Entry>(),
+            Err(ParseError::InvalidEncoding)
+        );
+    }
+
+    #[test]
+    fn test_percent_encoding_round_trip() -> Result<(), ParseError> {
+        let entry: Entry = "gene_name=foo%3Bbar%3Dbaz".parse()?;
+        assert_eq!(entry.key(), "gene_name");
+        assert_eq!(entry.value(), "foo;bar=baz");
+        assert_eq!(entry.to_string(), "gene_name=foo%3Bbar%3Dbaz");
+
+        let entry = Entry::new("a=b", "c;d&e");
+        assert_eq!(entry.to_string(), "a%3Db=c%3Bd%26e");
+        assert_eq!(entry.to_string().parse(), Ok(entry));
+
+        Ok(())
     }
 }
@@ -1,3 +1,7 @@
+//! GFF record field.
+
+use std::{convert::TryFrom, error, fmt};
+
 /// A GFF record field.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Field {
@@ -20,3 +24,89 @@ pub enum Field {
     /// The attributes.
     Attributes,
 }
+
+impl Field {
+    /// Returns the 0-based column index of the field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::Field;
+    /// assert_eq!(Field::ReferenceSequenceName.index(), 0);
+    /// assert_eq!(Field::Attributes.index(), 8);
+    /// ```
+    pub fn index(&self) -> usize {
+        match self {
+            Self::ReferenceSequenceName => 0,
+            Self::Source => 1,
+            Self::Type => 2,
+            Self::Start => 3,
+            Self::End => 4,
+            Self::Score => 5,
+            Self::Strand => 6,
+            Self::Phase => 7,
+            Self::Attributes => 8,
+        }
+    }
+}
+
+/// An error returned when a column index fails to convert to a GFF record field.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TryFromUsizeError(usize);
+
+impl error::Error for TryFromUsizeError {}
+
+impl fmt::Display for TryFromUsizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid index: expected 0..=8, got {}", self.0)
+    }
+}
+
+impl TryFrom<usize> for Field {
+    type Error = TryFromUsizeError;
+
+    fn try_from(n: usize) -> Result<Self, Self::Error> {
+        match n {
+            0 => Ok(Self::ReferenceSequenceName),
+            1 => Ok(Self::Source),
+            2 => Ok(Self::Type),
+            3 => Ok(Self::Start),
+            4 => Ok(Self::End),
+            5 => Ok(Self::Score),
+            6 => Ok(Self::Strand),
+            7 => Ok(Self::Phase),
+            8 => Ok(Self::Attributes),
+            _ => Err(TryFromUsizeError(n)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index() {
+        assert_eq!(Field::ReferenceSequenceName.index(), 0);
+        assert_eq!(Field::Source.index(), 1);
+        assert_eq!(Field::Type.index(), 2);
+        assert_eq!(Field::Start.index(), 3);
+        assert_eq!(Field::End.index(), 4);
+        assert_eq!(Field::Score.index(), 5);
+        assert_eq!(Field::Strand.index(), 6);
+        assert_eq!(Field::Phase.index(), 7);
+        assert_eq!(Field::Attributes.index(), 8);
+    }
+
+    #[test]
+    fn test_try_from_usize_for_field() {
+        assert_eq!(Field::try_from(6), Ok(Field::Strand));
+        assert_eq!(Field::try_from(Field::Strand.index()), Ok(Field::Strand));
+
+        for i in 0..9 {
+            assert_eq!(Field::try_from(i).map(|field| field.index()), Ok(i));
+        }
+
+        assert_eq!(Field::try_from(9), Err(TryFromUsizeError(9)));
+    }
+}
@@ -73,6 +73,10 @@ where
     /// # }
     /// ```
     pub async fn write_header(&mut self, header: &Header) -> io::Result<()> {
+        header
+            .validate()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
         let raw_header = header.to_string();
         self.inner.write_all(raw_header.as_bytes()).await
     }
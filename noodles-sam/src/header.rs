@@ -68,7 +68,7 @@ pub mod read_group;
 pub mod record;
 pub mod reference_sequence;
 
-use std::{collections::HashSet, convert::TryFrom, error, fmt, str::FromStr};
+use std::{collections::HashSet, convert::TryFrom, error, fmt, mem, str::FromStr};
 
 use indexmap::IndexMap;
 
@@ -80,6 +80,12 @@ pub use self::{
 pub use self::record::Record;
 
 /// A reference seqeuence dictionary.
+///
+/// This is an insertion-ordered map keyed by reference sequence name. The order is the order of
+/// the `@SQ` records in the header, and the index of a reference sequence in this map (e.g.,
+/// [`IndexMap::get_index_of`]) is its reference sequence ID, i.e., the position of the same
+/// reference sequence in [`IndexMap::values`]. Binary formats, such as BAM, rely on this to map
+/// reference sequence IDs to names.
 pub type ReferenceSequences = IndexMap<String, ReferenceSequence>;
 
 /// An ordered map of read groups.
@@ -206,6 +212,84 @@ impl Header {
         &mut self.reference_sequences
     }
 
+    /// Returns the sum of the lengths of all reference sequences.
+    ///
+    /// This is the total length of the genome, e.g., for normalizing coverage. The sum is
+    /// accumulated as a `u64`, as it can exceed the range of a reference sequence length for large
+    /// genomes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, header::ReferenceSequence};
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_reference_sequence(ReferenceSequence::new("sq0", 8)?)
+    ///     .add_reference_sequence(ReferenceSequence::new("sq1", 13)?)
+    ///     .build();
+    ///
+    /// assert_eq!(header.genome_size(), 21);
+    /// # Ok::<(), sam::header::reference_sequence::NewError>(())
+    /// ```
+    pub fn genome_size(&self) -> u64 {
+        self.reference_sequences
+            .values()
+            .filter_map(|reference_sequence| u64::try_from(reference_sequence.len()).ok())
+            .sum()
+    }
+
+    /// Renames a reference sequence.
+    ///
+    /// The reference sequence keeps its position in the reference sequence dictionary, so
+    /// reference sequence IDs, e.g., of BAM records, still resolve to the same reference sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, header::ReferenceSequence};
+    ///
+    /// let mut header = sam::Header::builder()
+    ///     .add_reference_sequence(ReferenceSequence::new("1", 8)?)
+    ///     .add_reference_sequence(ReferenceSequence::new("2", 13)?)
+    ///     .build();
+    ///
+    /// header.rename_reference("1", "chr1")?;
+    ///
+    /// let reference_sequences = header.reference_sequences();
+    /// assert_eq!(reference_sequences.get_index_of("chr1"), Some(0));
+    /// assert_eq!(reference_sequences[0].name(), "chr1");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn rename_reference(&mut self, from: &str, to: &str) -> Result<(), RenameReferenceError> {
+        use crate::record::reference_sequence_name::is_valid_name;
+
+        if !self.reference_sequences.contains_key(from) {
+            return Err(RenameReferenceError::MissingReferenceSequence(from.into()));
+        } else if from == to {
+            return Ok(());
+        } else if !is_valid_name(to) {
+            return Err(RenameReferenceError::InvalidName(to.into()));
+        } else if self.reference_sequences.contains_key(to) {
+            return Err(RenameReferenceError::DuplicateReferenceSequenceName(
+                to.into(),
+            ));
+        }
+
+        self.reference_sequences = mem::take(&mut self.reference_sequences)
+            .into_iter()
+            .map(|(name, mut reference_sequence)| {
+                if name == from {
+                    *reference_sequence.name_mut() = to.into();
+                    (to.into(), reference_sequence)
+                } else {
+                    (name, reference_sequence)
+                }
+            })
+            .collect();
+
+        Ok(())
+    }
+
     /// Returns the SAM header read groups.
     ///
     /// # Examples
@@ -365,6 +449,35 @@ impl Header {
             && self.programs.is_empty()
             && self.comments.is_empty()
     }
+
+    /// Validates that the read groups and programs can be serialized.
+    ///
+    /// See [`ReadGroup::validate`] and [`Program::validate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, header::ReadGroup};
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_read_group(ReadGroup::new("rg0"))
+    ///     .build();
+    ///
+    /// assert!(header.validate().is_ok());
+    /// ```
+    pub fn validate(&self) -> Result<(), ValidateError> {
+        for read_group in self.read_groups.values() {
+            read_group
+                .validate()
+                .map_err(ValidateError::InvalidReadGroup)?;
+        }
+
+        for program in self.programs.values() {
+            program.validate().map_err(ValidateError::InvalidProgram)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl fmt::Display for Header {
@@ -415,6 +528,53 @@ impl fmt::Display for Header {
     }
 }
 
+/// An error returned when a SAM header fails to validate.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidateError {
+    /// A read group is invalid.
+    InvalidReadGroup(read_group::ValidateError),
+    /// A program is invalid.
+    InvalidProgram(program::ValidateError),
+}
+
+impl error::Error for ValidateError {}
+
+impl fmt::Display for ValidateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidReadGroup(e) => write!(f, "invalid read group: {}", e),
+            Self::InvalidProgram(e) => write!(f, "invalid program: {}", e),
+        }
+    }
+}
+
+/// An error returned when a SAM header reference sequence fails to be renamed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RenameReferenceError {
+    /// The reference sequence is not in the reference sequence dictionary.
+    MissingReferenceSequence(String),
+    /// The new name is invalid.
+    InvalidName(String),
+    /// The new name is already used by another reference sequence.
+    DuplicateReferenceSequenceName(String),
+}
+
+impl error::Error for RenameReferenceError {}
+
+impl fmt::Display for RenameReferenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingReferenceSequence(name) => {
+                write!(f, "missing reference sequence: {}", name)
+            }
+            Self::InvalidName(name) => write!(f, "invalid name: {}", name),
+            Self::DuplicateReferenceSequenceName(name) => {
+                write!(f, "duplicate reference sequence name: {}", name)
+            }
+        }
+    }
+}
+
 /// An error returned when a raw SAM header fails to parse.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ParseError {
@@ -552,6 +712,92 @@ impl FromStr for Header {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_genome_size() -> Result<(), reference_sequence::NewError> {
+        let header = Header::default();
+        assert_eq!(header.genome_size(), 0);
+
+        let header = Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0", i32::MAX)?)
+            .add_reference_sequence(ReferenceSequence::new("sq1", i32::MAX)?)
+            .build();
+
+        assert_eq!(header.genome_size(), 4294967294);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reference_sequences_order() -> Result<(), reference_sequence::NewError> {
+        let header = Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq2", 21)?)
+            .add_reference_sequence(ReferenceSequence::new("sq0", 8)?)
+            .add_reference_sequence(ReferenceSequence::new("sq1", 13)?)
+            .build();
+
+        let reference_sequences = header.reference_sequences();
+
+        let names: Vec<_> = reference_sequences
+            .keys()
+            .map(|name| name.as_str())
+            .collect();
+        assert_eq!(names, ["sq2", "sq0", "sq1"]);
+
+        for (i, reference_sequence) in reference_sequences.values().enumerate() {
+            assert_eq!(
+                reference_sequences.get_index_of(reference_sequence.name()),
+                Some(i)
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rename_reference() -> Result<(), Box<dyn std::error::Error>> {
+        let mut header = Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("1", 8)?)
+            .add_reference_sequence(ReferenceSequence::new("2", 13)?)
+            .add_reference_sequence(ReferenceSequence::new("3", 21)?)
+            .build();
+
+        header.rename_reference("2", "chr2")?;
+
+        let reference_sequences = header.reference_sequences();
+        let names: Vec<_> = reference_sequences
+            .keys()
+            .map(|name| name.as_str())
+            .collect();
+        assert_eq!(names, ["1", "chr2", "3"]);
+
+        // A BAM record with reference sequence ID 1 still resolves to the same reference sequence.
+        let (name, reference_sequence) = reference_sequences
+            .get_index(1)
+            .ok_or("missing reference sequence")?;
+        assert_eq!(name, "chr2");
+        assert_eq!(reference_sequence.name(), "chr2");
+        assert_eq!(reference_sequence.len(), 13);
+
+        assert_eq!(
+            header.rename_reference("2", "chr2"),
+            Err(RenameReferenceError::MissingReferenceSequence(
+                String::from("2")
+            ))
+        );
+        assert_eq!(
+            header.rename_reference("1", "3"),
+            Err(RenameReferenceError::DuplicateReferenceSequenceName(
+                String::from("3")
+            ))
+        );
+        assert_eq!(
+            header.rename_reference("1", "*"),
+            Err(RenameReferenceError::InvalidName(String::from("*")))
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() -> Result<(), reference_sequence::NewError> {
         let header = Header::builder()
@@ -159,6 +159,55 @@ impl Program {
         self.version.as_deref()
     }
 
+    /// Validates the program field values.
+    ///
+    /// A field value is invalid if it contains a tab or newline, as it cannot be serialized as a
+    /// SAM header record field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noodles_sam::header::program::builder;
+    /// use noodles_sam::header::{program::{Tag, ValidateError}, Program};
+    ///
+    /// let program = Program::builder()
+    ///     .set_id("pg0")
+    ///     .set_command_line("noodles\nsam")
+    ///     .build()?;
+    ///
+    /// assert_eq!(
+    ///     program.validate(),
+    ///     Err(ValidateError::InvalidFieldValue(
+    ///         Tag::CommandLine,
+    ///         String::from("noodles\nsam")
+    ///     ))
+    /// );
+    /// # Ok::<(), builder::BuildError>(())
+    /// ```
+    pub fn validate(&self) -> Result<(), ValidateError> {
+        let values = [
+            (Tag::Id, Some(self.id())),
+            (Tag::Name, self.name()),
+            (Tag::CommandLine, self.command_line()),
+            (Tag::PreviousId, self.previous_id()),
+            (Tag::Description, self.description()),
+            (Tag::Version, self.version()),
+        ];
+
+        let fields = values
+            .iter()
+            .filter_map(|(tag, value)| value.map(|v| (tag, v)))
+            .chain(self.fields.iter().map(|(tag, value)| (tag, value.as_str())));
+
+        for (tag, value) in fields {
+            if !record::value::is_valid_field_value(value) {
+                return Err(ValidateError::InvalidFieldValue(tag.clone(), value.into()));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Returns the raw fields of the program.
     ///
     /// This includes any field that is not specially handled by the structure itself. For example,
@@ -224,6 +273,25 @@ impl fmt::Display for Program {
     }
 }
 
+/// An error returned when a SAM header program fails to validate.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidateError {
+    /// A field value is invalid.
+    InvalidFieldValue(Tag, String),
+}
+
+impl error::Error for ValidateError {}
+
+impl fmt::Display for ValidateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidFieldValue(tag, value) => {
+                write!(f, "invalid field value: {}: {:?}", tag, value)
+            }
+        }
+    }
+}
+
 /// An error returned when a raw SAM header program fails to parse.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum TryFromRecordError {
@@ -299,6 +367,31 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_validate() -> Result<(), builder::BuildError> {
+        let program = Program::builder()
+            .set_id("pg0")
+            .set_description("noodles sam")
+            .build()?;
+
+        assert_eq!(program.validate(), Ok(()));
+
+        let program = Program::builder()
+            .set_id("pg0")
+            .set_description("noodles\tsam")
+            .build()?;
+
+        assert_eq!(
+            program.validate(),
+            Err(ValidateError::InvalidFieldValue(
+                Tag::Description,
+                String::from("noodles\tsam")
+            ))
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_try_from_record_for_program_with_invalid_record() {
         let record = Record::new(
@@ -163,6 +163,38 @@ impl ReadGroup {
         self.produced_at.as_deref()
     }
 
+    /// Parses the datetime of run (`DT`) as an ISO 8601 datetime.
+    ///
+    /// A date without a time is taken as midnight UTC. This returns `None` if the datetime of run
+    /// is missing or malformed. The raw value is still available using [`Self::produced_at`].
+    ///
+    /// This requires the `time` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::ReadGroup;
+    ///
+    /// let read_group = ReadGroup::builder()
+    ///     .set_id("rg0")
+    ///     .set_produced_at("2020-08-19T20:00:00Z")
+    ///     .build()?;
+    ///
+    /// let produced_at = read_group.produced_at_datetime().unwrap();
+    /// assert_eq!(produced_at.unix_timestamp(), 1597867200);
+    /// # Ok::<(), noodles_sam::header::read_group::builder::BuildError>(())
+    /// ```
+    #[cfg(feature = "time")]
+    pub fn produced_at_datetime(&self) -> Option<time::OffsetDateTime> {
+        use time::{format_description::well_known::Iso8601, Date, OffsetDateTime};
+
+        let s = self.produced_at()?;
+
+        OffsetDateTime::parse(s, &Iso8601::DEFAULT)
+            .or_else(|_| Date::parse(s, &Iso8601::DEFAULT).map(|date| date.midnight().assume_utc()))
+            .ok()
+    }
+
     /// Returns the flow order.
     ///
     /// # Examples
@@ -280,6 +312,61 @@ impl ReadGroup {
         self.sample.as_deref()
     }
 
+    /// Validates the read group field values.
+    ///
+    /// A field value is invalid if it contains a tab or newline, as it cannot be serialized as a
+    /// SAM header record field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noodles_sam::header::read_group::builder;
+    /// use noodles_sam::header::{read_group::{Tag, ValidateError}, ReadGroup};
+    ///
+    /// let read_group = ReadGroup::builder()
+    ///     .set_id("rg0")
+    ///     .set_description("noodles\tsam")
+    ///     .build()?;
+    ///
+    /// assert_eq!(
+    ///     read_group.validate(),
+    ///     Err(ValidateError::InvalidFieldValue(
+    ///         Tag::Description,
+    ///         String::from("noodles\tsam")
+    ///     ))
+    /// );
+    /// # Ok::<(), builder::BuildError>(())
+    /// ```
+    pub fn validate(&self) -> Result<(), ValidateError> {
+        let values = [
+            (Tag::Id, Some(self.id())),
+            (Tag::Barcode, self.barcode()),
+            (Tag::SequencingCenter, self.sequencing_center()),
+            (Tag::Description, self.description()),
+            (Tag::ProducedAt, self.produced_at()),
+            (Tag::FlowOrder, self.flow_order()),
+            (Tag::KeySequence, self.key_sequence()),
+            (Tag::Library, self.library()),
+            (Tag::Program, self.program()),
+            (Tag::PlatformModel, self.platform_model()),
+            (Tag::PlatformUnit, self.platform_unit()),
+            (Tag::Sample, self.sample()),
+        ];
+
+        let fields = values
+            .iter()
+            .filter_map(|(tag, value)| value.map(|v| (tag, v)))
+            .chain(self.fields.iter().map(|(tag, value)| (tag, value.as_str())));
+
+        for (tag, value) in fields {
+            if !record::value::is_valid_field_value(value) {
+                return Err(ValidateError::InvalidFieldValue(tag.clone(), value.into()));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Returns the raw fields of the read group.
     ///
     /// This includes any field that is not specially handled by the structure itself. For example,
@@ -382,6 +469,25 @@ impl fmt::Display for ReadGroup {
     }
 }
 
+/// An error returned when a SAM header read group fails to validate.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidateError {
+    /// A field value is invalid.
+    InvalidFieldValue(Tag, String),
+}
+
+impl error::Error for ValidateError {}
+
+impl fmt::Display for ValidateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidFieldValue(tag, value) => {
+                write!(f, "invalid field value: {}: {:?}", tag, value)
+            }
+        }
+    }
+}
+
 /// An error returned when a raw SAM header read group fails to parse.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum TryFromRecordError {
@@ -482,6 +588,82 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_produced_at_datetime() -> Result<(), builder::BuildError> {
+        use time::macros::datetime;
+
+        let read_group = ReadGroup::builder()
+            .set_id("rg0")
+            .set_produced_at("2020-08-19T20:00:00+02:00")
+            .build()?;
+
+        assert_eq!(
+            read_group.produced_at_datetime(),
+            Some(datetime!(2020-08-19 20:00:00 +02:00))
+        );
+
+        let read_group = ReadGroup::builder()
+            .set_id("rg0")
+            .set_produced_at("2020-08-19")
+            .build()?;
+
+        assert_eq!(
+            read_group.produced_at_datetime(),
+            Some(datetime!(2020-08-19 00:00:00 UTC))
+        );
+
+        let read_group = ReadGroup::builder()
+            .set_id("rg0")
+            .set_produced_at("19 Aug 2020")
+            .build()?;
+
+        assert_eq!(read_group.produced_at(), Some("19 Aug 2020"));
+        assert!(read_group.produced_at_datetime().is_none());
+
+        assert!(ReadGroup::new("rg0").produced_at_datetime().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate() -> Result<(), builder::BuildError> {
+        let read_group = ReadGroup::builder()
+            .set_id("rg0")
+            .set_description("noodles sam")
+            .build()?;
+
+        assert_eq!(read_group.validate(), Ok(()));
+
+        let read_group = ReadGroup::builder()
+            .set_id("rg0")
+            .set_description("noodles\tsam")
+            .build()?;
+
+        assert_eq!(
+            read_group.validate(),
+            Err(ValidateError::InvalidFieldValue(
+                Tag::Description,
+                String::from("noodles\tsam")
+            ))
+        );
+
+        let read_group = ReadGroup::builder()
+            .set_id("rg0")
+            .insert(Tag::Other(String::from("zn")), String::from("noodles\n"))
+            .build()?;
+
+        assert_eq!(
+            read_group.validate(),
+            Err(ValidateError::InvalidFieldValue(
+                Tag::Other(String::from("zn")),
+                String::from("noodles\n")
+            ))
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_try_from_record_for_read_group_with_invalid_record() {
         let record = Record::new(
@@ -7,6 +7,12 @@ use indexmap::IndexMap;
 /// An ordered map of raw tag-value pairs.
 pub type Fields = IndexMap<String, String>;
 
+// SAM header fields are tab-delimited and records are line-delimited, so neither character can
+// appear in a field value.
+pub(crate) fn is_valid_field_value(s: &str) -> bool {
+    !s.contains(['\t', '\n'])
+}
+
 /// A SAM header record value.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Value {
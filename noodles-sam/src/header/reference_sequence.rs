@@ -276,6 +276,28 @@ impl ReferenceSequence {
         self.molecule_topology
     }
 
+    /// Returns the molecule topology or the default topology (linear) if it is not set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::{reference_sequence::MoleculeTopology, ReferenceSequence};
+    ///
+    /// let reference_sequence = ReferenceSequence::new("sq0", 13)?;
+    /// assert_eq!(reference_sequence.topology(), MoleculeTopology::Linear);
+    ///
+    /// let reference_sequence = ReferenceSequence::builder()
+    ///     .set_name("sq0")
+    ///     .set_length(13)
+    ///     .set_molecule_topology(MoleculeTopology::Circular)
+    ///     .build()?;
+    /// assert_eq!(reference_sequence.topology(), MoleculeTopology::Circular);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn topology(&self) -> MoleculeTopology {
+        self.molecule_topology.unwrap_or_default()
+    }
+
     /// Returns the URI.
     ///
     /// # Examples
@@ -520,6 +542,35 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_try_from_record_for_reference_sequence_with_molecule_topology(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        fn parse(s: &str) -> Result<ReferenceSequence, Box<dyn std::error::Error>> {
+            let record: Record = s.parse()?;
+            ReferenceSequence::try_from(record).map_err(|e| e.into())
+        }
+
+        let reference_sequence = parse("@SQ\tSN:sq0\tLN:13\tTP:circular")?;
+        assert_eq!(
+            reference_sequence.molecule_topology(),
+            Some(MoleculeTopology::Circular)
+        );
+        assert_eq!(reference_sequence.topology(), MoleculeTopology::Circular);
+
+        let reference_sequence = parse("@SQ\tSN:sq0\tLN:13\tTP:linear")?;
+        assert_eq!(
+            reference_sequence.molecule_topology(),
+            Some(MoleculeTopology::Linear)
+        );
+        assert_eq!(reference_sequence.topology(), MoleculeTopology::Linear);
+
+        let reference_sequence = parse("@SQ\tSN:sq0\tLN:13")?;
+        assert!(reference_sequence.molecule_topology().is_none());
+        assert_eq!(reference_sequence.topology(), MoleculeTopology::Linear);
+
+        Ok(())
+    }
+
     #[test]
     fn test_try_from_record_for_reference_sequence_with_invalid_record() {
         let record = Record::new(
@@ -1,11 +1,14 @@
 //! SAM record and fields.
 
+mod barcode_tags;
 pub mod builder;
 pub mod cigar;
 pub mod data;
 mod field;
 mod flags;
+mod fragment;
 mod mapping_quality;
+mod overlap;
 pub mod position;
 pub mod quality_scores;
 pub mod read_name;
@@ -13,12 +16,15 @@ pub mod reference_sequence_name;
 pub mod sequence;
 
 pub use self::{
-    builder::Builder, cigar::Cigar, data::Data, field::Field, flags::Flags,
-    mapping_quality::MappingQuality, position::Position, quality_scores::QualityScores,
+    barcode_tags::BarcodeTags, builder::Builder, cigar::Cigar, data::Data, field::Field,
+    flags::Flags, fragment::fragment_span, mapping_quality::MappingQuality,
+    overlap::overlapping_positions, position::Position, quality_scores::QualityScores,
     read_name::ReadName, reference_sequence_name::ReferenceSequenceName, sequence::Sequence,
 };
 
-use std::{error, fmt, num, str::FromStr};
+use std::{error, fmt, num, ops::Range, str::FromStr};
+
+use crate::{header::ReadGroup, Header};
 
 pub(crate) const NULL_FIELD: &str = "*";
 const ZERO_FIELD: &str = "0";
@@ -398,6 +404,160 @@ impl Record {
         &self.quality_scores
     }
 
+    /// Calculates the number of aligned bases.
+    ///
+    /// This sums the lengths of the CIGAR operations that align a read base to a reference base,
+    /// i.e., alignment matches (`M`), sequence matches (`=`), and sequence mismatches (`X`).
+    /// Unlike the read length and the reference span, this excludes insertions (`I`), deletions
+    /// (`D`), skipped regions (`N`), clips (`S`, `H`), and padding (`P`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    ///
+    /// let record = sam::Record::builder()
+    ///     .set_cigar("2S3M1I2=1X".parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.aligned_length(), 6);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn aligned_length(&self) -> u32 {
+        use cigar::op::Kind;
+
+        self.cigar()
+            .iter()
+            .filter(|op| matches!(op.kind(), Kind::Match | Kind::SeqMatch | Kind::SeqMismatch))
+            .map(|op| op.len())
+            .sum()
+    }
+
+    /// Calculates the unclipped start position.
+    ///
+    /// This is the alignment start (`POS`) moved back by the lengths of the leading soft (`S`)
+    /// and hard (`H`) clips, i.e., the position the first base of the original read would align
+    /// to. The result may be less than 1.
+    ///
+    /// This returns `None` if the record has no position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_sam::{self as sam, record::Position};
+    ///
+    /// let record = sam::Record::builder()
+    ///     .set_position(Position::try_from(100)?)
+    ///     .set_cigar("2H3S10M".parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.unclipped_start(), Some(95));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn unclipped_start(&self) -> Option<i32> {
+        let start = self.position().map(i32::from)?;
+        let clip_len = clip_len(self.cigar().iter());
+        Some(start - clip_len)
+    }
+
+    /// Calculates the unclipped end position.
+    ///
+    /// This is the alignment end moved forward by the lengths of the trailing soft (`S`) and hard
+    /// (`H`) clips. The alignment end is the last reference position covered by the alignment,
+    /// i.e., the start plus the reference length of the CIGAR minus 1.
+    ///
+    /// This returns `None` if the record has no position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_sam::{self as sam, record::Position};
+    ///
+    /// let record = sam::Record::builder()
+    ///     .set_position(Position::try_from(100)?)
+    ///     .set_cigar("10M3S2H".parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.unclipped_end(), Some(114));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn unclipped_end(&self) -> Option<i32> {
+        let start = self.position().map(i32::from)?;
+        let end = start + self.cigar().reference_len() as i32 - 1;
+        let clip_len = clip_len(self.cigar().iter().rev());
+        Some(end + clip_len)
+    }
+
+    /// Returns the bases of the sequence that are not soft clipped.
+    ///
+    /// Leading and trailing soft clips (`S`) in the CIGAR are excluded. Hard clipped bases are not
+    /// stored in the sequence, so hard clips (`H`) are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    ///
+    /// let record = sam::Record::builder()
+    ///     .set_cigar("2S3M1S".parse()?)
+    ///     .set_sequence("TTACGA".parse()?)
+    ///     .build()?;
+    ///
+    /// let bases: String = record.aligned_sequence().iter().map(|&b| char::from(b)).collect();
+    /// assert_eq!(bases, "ACG");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn aligned_sequence(&self) -> &[sequence::Base] {
+        let range = self.aligned_range(self.sequence.len());
+        self.sequence.get(range).unwrap_or_default()
+    }
+
+    /// Returns the quality scores of the bases that are not soft clipped.
+    ///
+    /// This is the quality scores counterpart of [`Self::aligned_sequence`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    ///
+    /// let record = sam::Record::builder()
+    ///     .set_cigar("2S3M1S".parse()?)
+    ///     .set_sequence("TTACGA".parse()?)
+    ///     .set_quality_scores("NDLSBA".parse()?)
+    ///     .build()?;
+    ///
+    /// let scores: Vec<_> = record.aligned_quality_scores().iter().copied().map(u8::from).collect();
+    /// assert_eq!(scores, [43, 50, 33]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn aligned_quality_scores(&self) -> &[quality_scores::Score] {
+        let range = self.aligned_range(self.quality_scores.len());
+        self.quality_scores.get(range).unwrap_or_default()
+    }
+
+    // Returns the range of read positions that are not soft clipped, given the read length.
+    fn aligned_range(&self, len: usize) -> Range<usize> {
+        use cigar::op::Kind;
+
+        fn clip_len<'a, I>(ops: I) -> usize
+        where
+            I: Iterator<Item = &'a cigar::Op>,
+        {
+            ops.filter(|op| op.kind() != Kind::HardClip)
+                .take_while(|op| op.kind() == Kind::SoftClip)
+                .map(|op| op.len() as usize)
+                .sum()
+        }
+
+        let start = clip_len(self.cigar.iter());
+        let end = len.saturating_sub(clip_len(self.cigar.iter().rev()));
+
+        start..end.max(start)
+    }
+
     /// Returns a mutable reference to the quality scores.
     ///
     /// # Examples
@@ -466,6 +626,199 @@ impl Record {
     pub fn data_mut(&mut self) -> &mut Data {
         &mut self.data
     }
+
+    /// Returns the string value of the data field with the given tag.
+    ///
+    /// This returns `None` if the record does not have the field or its value is not a string
+    /// (`Z`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, record::data::field::Tag};
+    ///
+    /// let record = sam::Record::builder()
+    ///     .set_data("RG:Z:rg0\tNH:i:1".parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.tag_string(&Tag::ReadGroup), Some("rg0"));
+    /// assert!(record.tag_string(&Tag::AlignmentHitCount).is_none());
+    /// assert!(record.tag_string(&Tag::Comment).is_none());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn tag_string(&self, tag: &data::field::Tag) -> Option<&str> {
+        self.data()
+            .get(tag)
+            .and_then(|field| field.value().as_str())
+    }
+
+    /// Returns the cell barcode of this record.
+    ///
+    /// The cell barcode is read from the data field with the cell barcode tag of the given barcode
+    /// tags, e.g., `CB` by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, record::BarcodeTags};
+    ///
+    /// let record = sam::Record::builder()
+    ///     .set_data("CB:Z:AAAC\tUB:Z:GGTT".parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.cell_barcode(&BarcodeTags::default()), Some("AAAC"));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn cell_barcode(&self, tags: &BarcodeTags) -> Option<&str> {
+        self.tag_string(tags.cell_barcode())
+    }
+
+    /// Returns the unique molecular identifier (UMI) of this record.
+    ///
+    /// The UMI is read from the data field with the UMI tag of the given barcode tags, e.g., `UB`
+    /// by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, record::BarcodeTags};
+    ///
+    /// let record = sam::Record::builder()
+    ///     .set_data("CB:Z:AAAC\tUB:Z:GGTT".parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.umi(&BarcodeTags::default()), Some("GGTT"));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn umi(&self, tags: &BarcodeTags) -> Option<&str> {
+        self.tag_string(tags.umi())
+    }
+
+    /// Returns the read group of this record.
+    ///
+    /// This resolves the read group ID in the `RG` data field to the read group declared in the
+    /// given header. If the record does not have a read group or the read group is not declared
+    /// in the header, this returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// use noodles_sam::{self as sam, header::ReadGroup, record::{data, Data}};
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_read_group(ReadGroup::new("rg0"))
+    ///     .build();
+    ///
+    /// let record = sam::Record::default();
+    /// assert!(record.read_group(&header).is_none());
+    ///
+    /// let data = Data::try_from(vec![data::Field::new(
+    ///     data::field::Tag::ReadGroup,
+    ///     data::field::Value::String(String::from("rg0")),
+    /// )])?;
+    /// let record = sam::Record::builder().set_data(data).build()?;
+    /// assert_eq!(record.read_group(&header).map(|rg| rg.id()), Some("rg0"));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn read_group<'a>(&self, header: &'a Header) -> Option<&'a ReadGroup> {
+        self.data()
+            .get(&data::field::Tag::ReadGroup)
+            .and_then(|field| field.value().as_str())
+            .and_then(|id| header.read_groups().get(id))
+    }
+
+    /// Converts this record to the forward strand.
+    ///
+    /// If the record is reverse complemented, the sequence is reverse complemented, the quality
+    /// scores and CIGAR operations are reversed, and the reverse complemented flag is unset.
+    /// Otherwise, the record is unchanged.
+    ///
+    /// The position is not changed, as it is already the leftmost position on the forward strand.
+    /// Data fields that depend on the orientation, e.g., `MD`, are also not changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, record::Flags};
+    ///
+    /// let mut record = sam::Record::builder()
+    ///     .set_flags(Flags::REVERSE_COMPLEMENTED)
+    ///     .set_cigar("1S3M".parse()?)
+    ///     .set_sequence("ACGG".parse()?)
+    ///     .set_quality_scores("NDLS".parse()?)
+    ///     .build()?;
+    ///
+    /// record.to_forward_strand();
+    ///
+    /// assert!(record.flags().is_empty());
+    /// assert_eq!(record.cigar().to_string(), "3M1S");
+    /// assert_eq!(record.sequence().to_string(), "CCGT");
+    /// assert_eq!(record.quality_scores().to_string(), "SLDN");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_forward_strand(&mut self) {
+        if !self.flags.is_reverse_complemented() {
+            return;
+        }
+
+        let sequence: Vec<_> = self.sequence.iter().rev().map(|b| b.complement()).collect();
+        self.sequence = Sequence::from(sequence);
+
+        let quality_scores: Vec<_> = self.quality_scores.iter().rev().copied().collect();
+        self.quality_scores = QualityScores::from(quality_scores);
+
+        let ops: Vec<_> = self.cigar.iter().rev().copied().collect();
+        self.cigar = Cigar::from(ops);
+
+        self.flags.remove(Flags::REVERSE_COMPLEMENTED);
+    }
+
+    /// Renames the reference sequence and mate reference sequence of this record.
+    ///
+    /// Each of the reference sequence name and mate reference sequence name is replaced by `to`
+    /// if it is `from`. This is typically paired with [`crate::Header::rename_reference`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    ///
+    /// let mut record = sam::Record::builder()
+    ///     .set_reference_sequence_name("1".parse()?)
+    ///     .set_mate_reference_sequence_name("2".parse()?)
+    ///     .build()?;
+    ///
+    /// record.rename_reference("1", &"chr1".parse()?);
+    ///
+    /// assert_eq!(record.reference_sequence_name().map(|name| name.as_str()), Some("chr1"));
+    /// assert_eq!(record.mate_reference_sequence_name().map(|name| name.as_str()), Some("2"));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn rename_reference(&mut self, from: &str, to: &ReferenceSequenceName) {
+        let names = self
+            .reference_sequence_name
+            .iter_mut()
+            .chain(self.mate_reference_sequence_name.iter_mut());
+
+        for name in names {
+            if name.as_str() == from {
+                *name = to.clone();
+            }
+        }
+    }
+}
+
+// Sums the lengths of the soft and hard clips at the start of the given CIGAR operations.
+fn clip_len<'a, I>(ops: I) -> i32
+where
+    I: Iterator<Item = &'a cigar::Op>,
+{
+    use cigar::op::Kind;
+
+    ops.take_while(|op| matches!(op.kind(), Kind::SoftClip | Kind::HardClip))
+        .map(|op| op.len() as i32)
+        .sum()
 }
 
 impl Default for Record {
@@ -770,6 +1123,137 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_to_forward_strand() -> Result<(), Box<dyn std::error::Error>> {
+        let mut record = Record::builder()
+            .set_flags(Flags::PAIRED | Flags::REVERSE_COMPLEMENTED)
+            .set_reference_sequence_name("sq0".parse()?)
+            .set_position(Position::try_from(8)?)
+            .set_cigar("2S3M1I2M1D1M".parse()?)
+            .set_sequence("TTACGATGC".parse()?)
+            .set_quality_scores("ABCDEFGHI".parse()?)
+            .build()?;
+
+        record.to_forward_strand();
+
+        assert_eq!(record.flags(), Flags::PAIRED);
+        assert_eq!(record.position(), Some(Position::try_from(8)?));
+        assert_eq!(record.cigar().to_string(), "1M1D2M1I3M2S");
+        assert_eq!(record.sequence().to_string(), "GCATCGTAA");
+        assert_eq!(record.quality_scores().to_string(), "IHGFEDCBA");
+        assert_eq!(record.cigar().read_len() as usize, record.sequence().len());
+
+        let expected = record.clone();
+        record.to_forward_strand();
+        assert_eq!(record, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_aligned_length() -> Result<(), Box<dyn std::error::Error>> {
+        let record = Record::default();
+        assert_eq!(record.aligned_length(), 0);
+
+        let record = Record::builder()
+            .set_cigar("3S5M2I5M1D3M".parse()?)
+            .build()?;
+
+        assert_eq!(record.aligned_length(), 13);
+        assert_eq!(record.cigar().read_len(), 18);
+        assert_eq!(record.cigar().reference_len(), 14);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unclipped_start_and_end() -> Result<(), Box<dyn std::error::Error>> {
+        let record = Record::builder()
+            .set_position(Position::try_from(100)?)
+            .set_cigar("3S10M2S".parse()?)
+            .build()?;
+
+        assert_eq!(record.unclipped_start(), Some(97));
+        assert_eq!(record.unclipped_end(), Some(111));
+
+        let record = Record::builder()
+            .set_position(Position::try_from(100)?)
+            .set_cigar("1H3S5M2D5M2S4H".parse()?)
+            .build()?;
+
+        assert_eq!(record.unclipped_start(), Some(96));
+        assert_eq!(record.unclipped_end(), Some(117));
+
+        let record = Record::builder().set_cigar("3S10M2S".parse()?).build()?;
+        assert!(record.unclipped_start().is_none());
+        assert!(record.unclipped_end().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_aligned_sequence_and_quality_scores() -> Result<(), Box<dyn std::error::Error>> {
+        let record = Record::builder()
+            .set_cigar("1H3S10M2S".parse()?)
+            .set_sequence("NNNACGTACGTACNN".parse()?)
+            .set_quality_scores("!!!ABCDEFGHIJ!!".parse()?)
+            .build()?;
+
+        let bases: String = record
+            .aligned_sequence()
+            .iter()
+            .map(|&b| char::from(b))
+            .collect();
+        assert_eq!(bases, "ACGTACGTAC");
+
+        let scores: String = record
+            .aligned_quality_scores()
+            .iter()
+            .map(|&score| char::from(u8::from(score) + b'!'))
+            .collect();
+        assert_eq!(scores, "ABCDEFGHIJ");
+
+        let record = Record::builder()
+            .set_cigar("3S10M2S".parse()?)
+            .set_sequence("NNNACGTACGTACNN".parse()?)
+            .build()?;
+
+        assert_eq!(record.aligned_sequence().len(), 10);
+        assert!(record.aligned_quality_scores().is_empty());
+
+        let record = Record::default();
+        assert!(record.aligned_sequence().is_empty());
+        assert!(record.aligned_quality_scores().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rename_reference() -> Result<(), Box<dyn std::error::Error>> {
+        let mut record = Record::builder()
+            .set_reference_sequence_name("1".parse()?)
+            .set_position(Position::try_from(8)?)
+            .set_mate_reference_sequence_name("1".parse()?)
+            .set_mate_position(Position::try_from(13)?)
+            .build()?;
+
+        let chr1: ReferenceSequenceName = "chr1".parse()?;
+        record.rename_reference("1", &chr1);
+        assert_eq!(record.reference_sequence_name(), Some(&chr1));
+        assert_eq!(record.mate_reference_sequence_name(), Some(&chr1));
+
+        record.rename_reference("2", &"chr2".parse()?);
+        assert_eq!(record.reference_sequence_name(), Some(&chr1));
+        assert_eq!(record.mate_reference_sequence_name(), Some(&chr1));
+
+        let mut record = Record::default();
+        record.rename_reference("1", &chr1);
+        assert!(record.reference_sequence_name().is_none());
+        assert!(record.mate_reference_sequence_name().is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() {
         let record = Record::default();
@@ -793,6 +1277,53 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_cell_barcode_and_umi() -> Result<(), Box<dyn std::error::Error>> {
+        use data::field::Tag;
+
+        let record: Record =
+            "r0\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\tCB:Z:AAAC\tUB:Z:GGTT\tNH:i:1".parse()?;
+
+        let tags = BarcodeTags::default();
+        assert_eq!(record.cell_barcode(&tags), Some("AAAC"));
+        assert_eq!(record.umi(&tags), Some("GGTT"));
+
+        let tags = BarcodeTags::new(Tag::CellBarcodeSequence, Tag::UmiSequence);
+        assert!(record.cell_barcode(&tags).is_none());
+        assert!(record.umi(&tags).is_none());
+
+        assert!(record.tag_string(&Tag::AlignmentHitCount).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_group() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_read_group(ReadGroup::new("rg0"))
+            .build();
+
+        let data = Data::try_from(vec![data::Field::new(
+            data::field::Tag::ReadGroup,
+            data::field::Value::String(String::from("rg0")),
+        )])?;
+        let record = Record::builder().set_data(data).build()?;
+
+        assert_eq!(record.read_group(&header), header.read_groups().get("rg0"));
+
+        let data = Data::try_from(vec![data::Field::new(
+            data::field::Tag::ReadGroup,
+            data::field::Value::String(String::from("rg1")),
+        )])?;
+        let record = Record::builder().set_data(data).build()?;
+        assert!(record.read_group(&header).is_none());
+
+        let record = Record::default();
+        assert!(record.read_group(&header).is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn test_from_str_with_invalid_position() {
         let s = "*\t0\tsq0\t-1\t255\t4M\t*\t0\t0\tACGT\tNDLS";
@@ -0,0 +1,67 @@
+use super::data::field::Tag;
+
+/// The data field tags that hold the cell barcode and UMI of a record.
+///
+/// The default tags are `CB` for the cell barcode and `UB` for the UMI, which are commonly used by
+/// single-cell pipelines.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::record::{data::field::Tag, BarcodeTags};
+///
+/// let tags = BarcodeTags::default();
+/// assert_eq!(tags.cell_barcode(), &Tag::CellBarcodeId);
+/// assert_eq!(tags.umi(), &Tag::Other(String::from("UB")));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BarcodeTags {
+    cell_barcode: Tag,
+    umi: Tag,
+}
+
+impl BarcodeTags {
+    /// Creates a set of barcode tags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::{data::field::Tag, BarcodeTags};
+    /// let tags = BarcodeTags::new(Tag::CellBarcodeSequence, Tag::UmiSequence);
+    /// ```
+    pub fn new(cell_barcode: Tag, umi: Tag) -> Self {
+        Self { cell_barcode, umi }
+    }
+
+    /// Returns the tag of the cell barcode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::{data::field::Tag, BarcodeTags};
+    /// let tags = BarcodeTags::new(Tag::CellBarcodeSequence, Tag::UmiSequence);
+    /// assert_eq!(tags.cell_barcode(), &Tag::CellBarcodeSequence);
+    /// ```
+    pub fn cell_barcode(&self) -> &Tag {
+        &self.cell_barcode
+    }
+
+    /// Returns the tag of the UMI.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::{data::field::Tag, BarcodeTags};
+    /// let tags = BarcodeTags::new(Tag::CellBarcodeSequence, Tag::UmiSequence);
+    /// assert_eq!(tags.umi(), &Tag::UmiSequence);
+    /// ```
+    pub fn umi(&self) -> &Tag {
+        &self.umi
+    }
+}
+
+impl Default for BarcodeTags {
+    fn default() -> Self {
+        Self::new(Tag::CellBarcodeId, Tag::Other(String::from("UB")))
+    }
+}
@@ -213,6 +213,8 @@ impl Builder {
 
     /// Sets a SAM record mate position.
     ///
+    /// This value is 1-based.
+    ///
     /// # Examples
     ///
     /// ```
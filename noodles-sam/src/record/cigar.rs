@@ -76,6 +76,79 @@ impl Cigar {
             })
             .sum()
     }
+
+    /// Validates the placement of the CIGAR operations.
+    ///
+    /// Hard clips (`H`) may only be the first and/or last operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::{cigar::{op::Kind, Op, ValidateError}, Cigar};
+    ///
+    /// let cigar = Cigar::from(vec![
+    ///     Op::new(Kind::HardClip, 5),
+    ///     Op::new(Kind::Match, 10),
+    ///     Op::new(Kind::HardClip, 5),
+    /// ]);
+    /// assert!(cigar.validate().is_ok());
+    ///
+    /// let cigar = Cigar::from(vec![
+    ///     Op::new(Kind::Match, 10),
+    ///     Op::new(Kind::HardClip, 5),
+    ///     Op::new(Kind::Match, 10),
+    /// ]);
+    /// assert_eq!(cigar.validate(), Err(ValidateError::InvalidHardClipPosition(1)));
+    /// ```
+    pub fn validate(&self) -> Result<(), ValidateError> {
+        let last_index = self.len().saturating_sub(1);
+
+        for (i, op) in self.iter().enumerate() {
+            if op.kind() == Kind::HardClip && i != 0 && i != last_index {
+                return Err(ValidateError::InvalidHardClipPosition(i));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Merges adjacent operations of the same kind.
+    ///
+    /// Editing a CIGAR, e.g., by trimming or clipping, can leave consecutive operations of the
+    /// same kind, such as `5M5M`. These are combined into a single operation, e.g., `10M`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::{cigar::{op::Kind, Op}, Cigar};
+    ///
+    /// let mut cigar = Cigar::from(vec![
+    ///     Op::new(Kind::Match, 5),
+    ///     Op::new(Kind::Match, 5),
+    ///     Op::new(Kind::SoftClip, 2),
+    /// ]);
+    ///
+    /// cigar.compact();
+    ///
+    /// assert_eq!(cigar, Cigar::from(vec![
+    ///     Op::new(Kind::Match, 10),
+    ///     Op::new(Kind::SoftClip, 2),
+    /// ]));
+    /// ```
+    pub fn compact(&mut self) {
+        let mut ops: Vec<Op> = Vec::with_capacity(self.0.len());
+
+        for op in self.0.drain(..) {
+            match ops.last_mut() {
+                Some(last) if last.kind() == op.kind() => {
+                    *last = Op::new(op.kind(), last.len() + op.len());
+                }
+                _ => ops.push(op),
+            }
+        }
+
+        self.0 = ops;
+    }
 }
 
 impl Deref for Cigar {
@@ -106,6 +179,29 @@ impl From<Vec<Op>> for Cigar {
     }
 }
 
+/// An error returned when a CIGAR fails to validate.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidateError {
+    /// A hard clip (`H`) is neither the first nor last operation.
+    ///
+    /// The value is the index of the operation.
+    InvalidHardClipPosition(usize),
+}
+
+impl error::Error for ValidateError {}
+
+impl fmt::Display for ValidateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidHardClipPosition(i) => write!(
+                f,
+                "invalid hard clip position: expected first or last op, got op {}",
+                i
+            ),
+        }
+    }
+}
+
 /// An error returned when a raw CIGAR string fails to parse.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ParseError {
@@ -115,6 +211,8 @@ pub enum ParseError {
     Invalid,
     /// The CIGAR string has an invalid operation.
     InvalidOp(op::ParseError),
+    /// The CIGAR operations are invalid.
+    InvalidOps(ValidateError),
 }
 
 impl error::Error for ParseError {}
@@ -125,6 +223,7 @@ impl fmt::Display for ParseError {
             Self::Empty => f.write_str("empty input"),
             Self::Invalid => f.write_str("invalid input"),
             Self::InvalidOp(e) => write!(f, "invalid op: {}", e),
+            Self::InvalidOps(e) => write!(f, "invalid ops: {}", e),
         }
     }
 }
@@ -150,11 +249,14 @@ impl FromStr for Cigar {
             start = end + raw_kind.len();
         }
 
-        if start == s.len() {
-            Ok(Self::from(ops))
-        } else {
-            Err(ParseError::Invalid)
+        if start != s.len() {
+            return Err(ParseError::Invalid);
         }
+
+        let cigar = Self::from(ops);
+        cigar.validate().map_err(ParseError::InvalidOps)?;
+
+        Ok(cigar)
     }
 }
 
@@ -203,5 +305,59 @@ mod tests {
 
         assert_eq!("".parse::<Cigar>(), Err(ParseError::Empty));
         assert_eq!("8M13".parse::<Cigar>(), Err(ParseError::Invalid));
+
+        assert_eq!(
+            "10M5H10M".parse::<Cigar>(),
+            Err(ParseError::InvalidOps(
+                ValidateError::InvalidHardClipPosition(1)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_compact() -> Result<(), ParseError> {
+        let mut cigar: Cigar = "5M5M".parse()?;
+        cigar.compact();
+        assert_eq!(cigar, Cigar::from(vec![Op::new(Kind::Match, 10)]));
+
+        let mut cigar: Cigar = "2S3M4M1I1I3M".parse()?;
+        cigar.compact();
+        assert_eq!(cigar.to_string(), "2S7M2I3M");
+
+        let mut cigar: Cigar = "5M2I3M".parse()?;
+        cigar.compact();
+        assert_eq!(cigar.to_string(), "5M2I3M");
+
+        let mut cigar = Cigar::default();
+        cigar.compact();
+        assert!(cigar.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate() {
+        let cigar = Cigar::from(vec![
+            Op::new(Kind::HardClip, 5),
+            Op::new(Kind::Match, 10),
+            Op::new(Kind::HardClip, 5),
+        ]);
+        assert_eq!(cigar.validate(), Ok(()));
+        assert_eq!("5H10M5H".parse(), Ok(cigar));
+
+        let cigar = Cigar::from(vec![Op::new(Kind::HardClip, 5)]);
+        assert_eq!(cigar.validate(), Ok(()));
+
+        assert_eq!(Cigar::default().validate(), Ok(()));
+
+        let cigar = Cigar::from(vec![
+            Op::new(Kind::Match, 10),
+            Op::new(Kind::HardClip, 5),
+            Op::new(Kind::Match, 10),
+        ]);
+        assert_eq!(
+            cigar.validate(),
+            Err(ValidateError::InvalidHardClipPosition(1))
+        );
     }
 }
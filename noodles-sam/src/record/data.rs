@@ -7,20 +7,96 @@ pub use self::field::Field;
 use std::{
     convert::TryFrom,
     error, fmt,
+    hash::{Hash, Hasher},
     ops::{Deref, DerefMut},
     str::FromStr,
 };
 
 use indexmap::IndexMap;
 
+use self::field::Value;
+
 const DELIMITER: char = '\t';
 
 /// SAM record data.
 ///
 /// This is also called optional fields.
+///
+/// Equality and hashing are independent of the order of the fields, i.e., two data maps with the
+/// same fields in a different order are equal. Data is not [`Eq`] because float values can be
+/// `NaN`.
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct Data(IndexMap<field::Tag, Field>);
 
+impl Data {
+    /// Sorts the fields by tag.
+    ///
+    /// This gives the data a canonical field order, which is useful when, e.g., writing or
+    /// comparing the serialized form of records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Data;
+    ///
+    /// let mut data: Data = "RG:Z:rg0\tNH:i:1".parse()?;
+    /// data.canonicalize();
+    /// assert_eq!(data.to_string(), "NH:i:1\tRG:Z:rg0");
+    /// # Ok::<(), noodles_sam::record::data::ParseError>(())
+    /// ```
+    pub fn canonicalize(&mut self) {
+        self.0.sort_by(|a, _, b, _| a.as_ref().cmp(b.as_ref()));
+    }
+}
+
+impl Hash for Data {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut fields: Vec<_> = self.values().collect();
+        fields.sort_by(|a, b| a.tag().as_ref().cmp(b.tag().as_ref()));
+
+        state.write_usize(fields.len());
+
+        for field in fields {
+            field.tag().hash(state);
+            hash_value(field.value(), state);
+        }
+    }
+}
+
+fn hash_value<H>(value: &Value, state: &mut H)
+where
+    H: Hasher,
+{
+    // Positive and negative zero compare equal, so they must hash the same.
+    fn float_bits(n: f32) -> u32 {
+        if n == 0.0 {
+            0
+        } else {
+            n.to_bits()
+        }
+    }
+
+    char::from(value.ty()).hash(state);
+
+    match value {
+        Value::Char(c) => c.hash(state),
+        Value::Int(n) => n.hash(state),
+        Value::Float(n) => float_bits(*n).hash(state),
+        Value::String(s) | Value::Hex(s) => s.hash(state),
+        Value::Int8Array(values) => values.hash(state),
+        Value::UInt8Array(values) => values.hash(state),
+        Value::Int16Array(values) => values.hash(state),
+        Value::UInt16Array(values) => values.hash(state),
+        Value::Int32Array(values) => values.hash(state),
+        Value::UInt32Array(values) => values.hash(state),
+        Value::FloatArray(values) => {
+            for &n in values {
+                float_bits(n).hash(state);
+            }
+        }
+    }
+}
+
 impl Deref for Data {
     type Target = IndexMap<field::Tag, Field>;
 
@@ -142,6 +218,44 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_canonicalize() -> Result<(), TryFromFieldVectorError> {
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash(data: &Data) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            data.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let mut data = Data::try_from(vec![
+            Field::new(Tag::ReadGroup, Value::String(String::from("rg0"))),
+            Field::new(Tag::AlignmentHitCount, Value::Int(1)),
+            Field::new(Tag::Other(String::from("ZF")), Value::Float(0.0)),
+        ])?;
+
+        let other = Data::try_from(vec![
+            Field::new(Tag::Other(String::from("ZF")), Value::Float(-0.0)),
+            Field::new(Tag::AlignmentHitCount, Value::Int(1)),
+            Field::new(Tag::ReadGroup, Value::String(String::from("rg0"))),
+        ])?;
+
+        assert_eq!(data, other);
+        assert_eq!(hash(&data), hash(&other));
+
+        data.canonicalize();
+        assert_eq!(data.to_string(), "NH:i:1\tRG:Z:rg0\tZF:f:0");
+
+        let mut other = other;
+        other.canonicalize();
+        assert_eq!(
+            other.keys().collect::<Vec<_>>(),
+            data.keys().collect::<Vec<_>>()
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_from_str() -> Result<(), TryFromFieldVectorError> {
         assert_eq!("".parse(), Ok(Data::default()));
@@ -587,8 +587,12 @@ impl FromStr for Value {
     }
 }
 
+// § 1.5 "The alignment section: optional fields" (2021-06-03): "A: Printable character
+// `[!-~]`".
 fn parse_char(s: &str) -> Result<char, ParseError> {
-    if let Some(c) = s.chars().next() {
+    let mut chars = s.chars();
+
+    if let (Some(c), None) = (chars.next(), chars.next()) {
         if c.is_ascii_graphic() {
             return Ok(c);
         }
@@ -761,7 +765,12 @@ mod tests {
     #[test]
     fn test_from_str() {
         assert_eq!("A:n".parse(), Ok(Value::Char('n')));
+        assert_eq!("A:!".parse(), Ok(Value::Char('!')));
+        assert_eq!("A:~".parse(), Ok(Value::Char('~')));
         assert_eq!("A:".parse::<Value>(), Err(ParseError::InvalidCharValue));
+        assert_eq!("A: ".parse::<Value>(), Err(ParseError::InvalidCharValue));
+        assert_eq!("A:nd".parse::<Value>(), Err(ParseError::InvalidCharValue));
+        assert_eq!("A:é".parse::<Value>(), Err(ParseError::InvalidCharValue));
         assert_eq!("A:🍜".parse::<Value>(), Err(ParseError::InvalidCharValue));
 
         assert_eq!("i:13".parse(), Ok(Value::Int(13)));
@@ -1,3 +1,5 @@
+use std::fmt;
+
 bitflags::bitflags! {
     /// SAM record flags.
     #[derive(Default)]
@@ -185,6 +187,57 @@ impl Flags {
     pub fn is_supplementary(self) -> bool {
         self.contains(Self::SUPPLEMENTARY)
     }
+
+    /// Returns the names of the set flags.
+    ///
+    /// The names are the same as the ones used by `samtools flags`, in ascending bit order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Flags;
+    ///
+    /// let flags = Flags::from(0x63);
+    /// assert_eq!(flags.names(), ["PAIRED", "PROPER_PAIR", "MREVERSE", "READ1"]);
+    ///
+    /// assert!(Flags::empty().names().is_empty());
+    /// ```
+    pub fn names(self) -> Vec<&'static str> {
+        const NAMES: [(Flags, &str); 12] = [
+            (Flags::PAIRED, "PAIRED"),
+            (Flags::PROPER_PAIR, "PROPER_PAIR"),
+            (Flags::UNMAPPED, "UNMAP"),
+            (Flags::MATE_UNMAPPED, "MUNMAP"),
+            (Flags::REVERSE_COMPLEMENTED, "REVERSE"),
+            (Flags::MATE_REVERSE_COMPLEMENTED, "MREVERSE"),
+            (Flags::READ_1, "READ1"),
+            (Flags::READ_2, "READ2"),
+            (Flags::SECONDARY, "SECONDARY"),
+            (Flags::QC_FAIL, "QCFAIL"),
+            (Flags::DUPLICATE, "DUP"),
+            (Flags::SUPPLEMENTARY, "SUPPLEMENTARY"),
+        ];
+
+        NAMES
+            .iter()
+            .filter(|(flag, _)| self.contains(*flag))
+            .map(|(_, name)| *name)
+            .collect()
+    }
+}
+
+impl fmt::Display for Flags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, name) in self.names().iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+
+            f.write_str(name)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl From<u16> for Flags {
@@ -251,6 +304,28 @@ mod tests {
         assert!(Flags::SUPPLEMENTARY.is_supplementary());
     }
 
+    #[test]
+    fn test_names() {
+        let flags = Flags::from(0x63);
+        assert_eq!(
+            flags.names(),
+            ["PAIRED", "PROPER_PAIR", "MREVERSE", "READ1"]
+        );
+
+        assert!(Flags::empty().names().is_empty());
+        assert_eq!(Flags::all().names().len(), 12);
+    }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(
+            Flags::from(0x63).to_string(),
+            "PAIRED,PROPER_PAIR,MREVERSE,READ1"
+        );
+        assert_eq!(Flags::SUPPLEMENTARY.to_string(), "SUPPLEMENTARY");
+        assert!(Flags::empty().to_string().is_empty());
+    }
+
     #[test]
     fn test_from_u16_for_flags() {
         assert_eq!(Flags::from(0x40), Flags::READ_1);
@@ -0,0 +1,130 @@
+use std::cmp;
+
+use super::Record;
+
+/// Returns the reference interval covered by the fragment of a read pair.
+///
+/// The fragment span is from the leftmost alignment start of either mate to the rightmost
+/// alignment end of either mate, where an alignment end is calculated using the CIGAR operations
+/// that consume the reference sequence.
+///
+/// This returns `None` if either record is unmapped or not in a proper pair, or the mates are not
+/// on the same reference sequence.
+///
+/// The returned interval is 1-based and closed.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use noodles_sam::{self as sam, record::{fragment_span, Flags, Position}};
+///
+/// let flags = Flags::PAIRED | Flags::PROPER_PAIR;
+///
+/// let r1 = sam::Record::builder()
+///     .set_flags(flags | Flags::READ_1)
+///     .set_reference_sequence_name("sq0".parse()?)
+///     .set_position(Position::try_from(8)?)
+///     .set_cigar("4M".parse()?)
+///     .build()?;
+///
+/// let r2 = sam::Record::builder()
+///     .set_flags(flags | Flags::READ_2 | Flags::REVERSE_COMPLEMENTED)
+///     .set_reference_sequence_name("sq0".parse()?)
+///     .set_position(Position::try_from(13)?)
+///     .set_cigar("4M".parse()?)
+///     .build()?;
+///
+/// assert_eq!(fragment_span(&r1, &r2), Some((8, 16)));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn fragment_span(r1: &Record, r2: &Record) -> Option<(i32, i32)> {
+    if !is_properly_paired(r1) || !is_properly_paired(r2) {
+        return None;
+    }
+
+    let reference_sequence_name = r1.reference_sequence_name()?;
+
+    if r2.reference_sequence_name() != Some(reference_sequence_name) {
+        return None;
+    }
+
+    let (r1_start, r1_end) = alignment_span(r1)?;
+    let (r2_start, r2_end) = alignment_span(r2)?;
+
+    Some((cmp::min(r1_start, r2_start), cmp::max(r1_end, r2_end)))
+}
+
+fn is_properly_paired(record: &Record) -> bool {
+    let flags = record.flags();
+    flags.is_proper_pair() && !flags.is_unmapped()
+}
+
+// If no CIGAR operations consume the reference sequence, the span is only the start position.
+fn alignment_span(record: &Record) -> Option<(i32, i32)> {
+    let start = record.position().map(i32::from)?;
+    let reference_len = cmp::max(record.cigar().reference_len(), 1) as i32;
+    Some((start, start + reference_len - 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::record::{Flags, Position};
+
+    use super::*;
+
+    fn build_record(
+        flags: Flags,
+        reference_sequence_name: &str,
+        position: i32,
+        cigar: &str,
+    ) -> Result<Record, Box<dyn std::error::Error>> {
+        Record::builder()
+            .set_flags(flags)
+            .set_reference_sequence_name(reference_sequence_name.parse()?)
+            .set_position(Position::try_from(position)?)
+            .set_cigar(cigar.parse()?)
+            .build()
+            .map_err(|e| e.into())
+    }
+
+    #[test]
+    fn test_fragment_span() -> Result<(), Box<dyn std::error::Error>> {
+        let proper_pair = Flags::PAIRED | Flags::PROPER_PAIR;
+
+        // 100 ... 149         250 ... 297
+        //   r1 (50M)  .......  r2 (2S48M)
+        let r1 = build_record(proper_pair | Flags::READ_1, "sq0", 100, "50M")?;
+        let r2 = build_record(
+            proper_pair | Flags::READ_2 | Flags::REVERSE_COMPLEMENTED,
+            "sq0",
+            250,
+            "2S48M",
+        )?;
+
+        assert_eq!(fragment_span(&r1, &r2), Some((100, 297)));
+        assert_eq!(fragment_span(&r2, &r1), Some((100, 297)));
+
+        // r2 is contained by r1
+        let r2 = build_record(proper_pair | Flags::READ_2, "sq0", 110, "10M")?;
+        assert_eq!(fragment_span(&r1, &r2), Some((100, 149)));
+
+        let r2 = build_record(proper_pair | Flags::READ_2, "sq1", 250, "50M")?;
+        assert!(fragment_span(&r1, &r2).is_none());
+
+        let r2 = build_record(Flags::PAIRED | Flags::READ_2, "sq0", 250, "50M")?;
+        assert!(fragment_span(&r1, &r2).is_none());
+
+        let r2 = build_record(
+            proper_pair | Flags::READ_2 | Flags::UNMAPPED,
+            "sq0",
+            100,
+            "*",
+        )?;
+        assert!(fragment_span(&r1, &r2).is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,134 @@
+use std::cmp;
+
+use super::{cigar::op::Kind, Cigar, Position};
+
+/// Returns the reference positions covered by the bases of both segments.
+///
+/// This is typically used with two mates of a pair, so that coverage tools can count reference
+/// positions in an overlapping region once rather than twice.
+///
+/// Only positions aligned to a read base are considered covered, i.e., those of alignment matches
+/// (`M`), sequence matches (`=`), and sequence mismatches (`X`). Deletions (`D`) and skipped
+/// regions (`N`) consume the reference sequence but do not cover it.
+///
+/// The returned 1-based positions are sorted in ascending order.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use noodles_sam::record::{overlapping_positions, Position};
+///
+/// let positions = overlapping_positions(
+///     Position::try_from(1)?,
+///     &"4M".parse()?,
+///     Position::try_from(3)?,
+///     &"4M".parse()?,
+/// );
+///
+/// assert_eq!(positions, [3, 4]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn overlapping_positions(
+    start: Position,
+    cigar: &Cigar,
+    mate_start: Position,
+    mate_cigar: &Cigar,
+) -> Vec<i32> {
+    let blocks = aligned_blocks(start, cigar);
+    let mate_blocks = aligned_blocks(mate_start, mate_cigar);
+
+    let mut positions = Vec::new();
+
+    let mut i = 0;
+    let mut j = 0;
+
+    while i < blocks.len() && j < mate_blocks.len() {
+        let (a_start, a_end) = blocks[i];
+        let (b_start, b_end) = mate_blocks[j];
+
+        let overlap_start = cmp::max(a_start, b_start);
+        let overlap_end = cmp::min(a_end, b_end);
+
+        if overlap_start <= overlap_end {
+            positions.extend(overlap_start..=overlap_end);
+        }
+
+        if a_end < b_end {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+
+    positions
+}
+
+// Returns the 1-based, closed intervals of the reference sequence that are aligned to read bases.
+fn aligned_blocks(start: Position, cigar: &Cigar) -> Vec<(i32, i32)> {
+    let mut blocks = Vec::new();
+    let mut reference_start = i32::from(start);
+
+    for op in cigar.iter() {
+        let len = op.len() as i32;
+
+        match op.kind() {
+            Kind::Match | Kind::SeqMatch | Kind::SeqMismatch => {
+                if len > 0 {
+                    blocks.push((reference_start, reference_start + len - 1));
+                }
+
+                reference_start += len;
+            }
+            Kind::Deletion | Kind::Skip => reference_start += len,
+            _ => {}
+        }
+    }
+
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::*;
+
+    #[test]
+    fn test_overlapping_positions() -> Result<(), Box<dyn std::error::Error>> {
+        // 10 11 12 13 14 15 16 17 18 19 20 21 22 23
+        //  =  =  =  =  -  -  =  =  =  =              (4M2D4M)
+        //                 =  =  =  =  =  =  =  =  =  (2S2M1I7M)
+        let positions = overlapping_positions(
+            Position::try_from(10)?,
+            &"4M2D4M".parse()?,
+            Position::try_from(15)?,
+            &"2S2M1I7M".parse()?,
+        );
+
+        assert_eq!(positions, [16, 17, 18, 19]);
+
+        // 10 11 12 13 14 15 16 17
+        //  =  =  =  =  .  .  .  =  (4M3N1M)
+        //        =  =  =  =  =  =  (6M)
+        let positions = overlapping_positions(
+            Position::try_from(10)?,
+            &"4M3N1M".parse()?,
+            Position::try_from(12)?,
+            &"6M".parse()?,
+        );
+
+        assert_eq!(positions, [12, 13, 17]);
+
+        let positions = overlapping_positions(
+            Position::try_from(1)?,
+            &"4M".parse()?,
+            Position::try_from(5)?,
+            &"4M".parse()?,
+        );
+
+        assert!(positions.is_empty());
+
+        Ok(())
+    }
+}
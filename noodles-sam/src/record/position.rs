@@ -13,9 +13,48 @@ pub(crate) const UNMAPPED: i32 = 0;
 ///
 /// This represents a 1-based start position on the reference sequence. The value is guaranteed to
 /// be a positive, non-zero integer.
+///
+/// SAM and VCF positions are 1-based, whereas BAM stores 0-based positions. Conversions from and
+/// to raw integers using [`TryFrom`] and [`From`] are always 1-based. Use
+/// [`Self::from_zero_based`] and [`Self::to_zero_based`] to convert 0-based positions.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Position(NonZeroI32);
 
+impl Position {
+    /// Converts a 0-based position to a (1-based) position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_sam::record::Position;
+    ///
+    /// assert_eq!(Position::from_zero_based(7), Position::try_from(8));
+    /// assert!(Position::from_zero_based(-1).is_err());
+    /// ```
+    pub fn from_zero_based(n: i32) -> Result<Self, TryFromIntError> {
+        n.checked_add(1)
+            .and_then(|m| Self::try_from(m).ok())
+            .ok_or(TryFromIntError(n))
+    }
+
+    /// Converts this (1-based) position to a 0-based position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_sam::record::Position;
+    ///
+    /// let position = Position::try_from(8)?;
+    /// assert_eq!(position.to_zero_based(), 7);
+    /// # Ok::<(), noodles_sam::record::position::TryFromIntError>(())
+    /// ```
+    pub fn to_zero_based(self) -> i32 {
+        i32::from(self) - 1
+    }
+}
+
 impl From<Position> for i32 {
     fn from(position: Position) -> Self {
         Self::from(position.0)
@@ -86,6 +125,32 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_zero_based_conversions() -> Result<(), TryFromIntError> {
+        assert_eq!(Position::from_zero_based(0), Position::try_from(1));
+        assert_eq!(Position::try_from(1)?.to_zero_based(), 0);
+
+        for n in [0, 7, i32::MAX - 1].iter().copied() {
+            assert_eq!(Position::from_zero_based(n)?.to_zero_based(), n);
+        }
+
+        for n in [1, 8, i32::MAX].iter().copied() {
+            let position = Position::try_from(n)?;
+            assert_eq!(
+                Position::from_zero_based(position.to_zero_based()),
+                Ok(position)
+            );
+        }
+
+        assert_eq!(Position::from_zero_based(-1), Err(TryFromIntError(-1)));
+        assert_eq!(
+            Position::from_zero_based(i32::MAX),
+            Err(TryFromIntError(i32::MAX))
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_from_str() -> Result<(), TryFromIntError> {
         assert_eq!("13".parse(), Ok(Position::try_from(13)?));
@@ -12,6 +12,49 @@ use super::NULL_FIELD;
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct QualityScores(Vec<Score>);
 
+impl QualityScores {
+    /// Creates quality scores from raw Phred scores.
+    ///
+    /// Each byte is a score that is _not_ offset, i.e., it ranges from 0 to 93 (inclusive).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::QualityScores;
+    ///
+    /// let quality_scores = QualityScores::from_bytes(&[45, 35, 43, 50])?;
+    /// assert_eq!(quality_scores.to_string(), "NDLS");
+    ///
+    /// assert!(QualityScores::from_bytes(&[45, 200]).is_err());
+    /// # Ok::<(), noodles_sam::record::quality_scores::score::TryFromUByteError>(())
+    /// ```
+    pub fn from_bytes(buf: &[u8]) -> Result<Self, score::TryFromUByteError> {
+        buf.iter()
+            .copied()
+            .map(Score::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::from)
+    }
+
+    /// Returns the quality scores as raw Phred scores.
+    ///
+    /// This is the inverse of [`Self::from_bytes`]. Each byte is a score that is _not_ offset.
+    ///
+    /// The scores are not stored as raw bytes, so this allocates a new buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::QualityScores;
+    /// let quality_scores: QualityScores = "NDLS".parse()?;
+    /// assert_eq!(quality_scores.as_bytes(), [45, 35, 43, 50]);
+    /// # Ok::<(), noodles_sam::record::quality_scores::ParseError>(())
+    /// ```
+    pub fn as_bytes(&self) -> Vec<u8> {
+        self.iter().copied().map(u8::from).collect()
+    }
+}
+
 impl Deref for QualityScores {
     type Target = [Score];
 
@@ -101,6 +144,32 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_from_bytes() {
+        let expected = [45, 35, 43, 50, 0, 93]
+            .iter()
+            .cloned()
+            .map(Score::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map(QualityScores::from);
+
+        assert_eq!(
+            QualityScores::from_bytes(&[45, 35, 43, 50, 0, 93]),
+            expected
+        );
+        assert_eq!(QualityScores::from_bytes(&[]), Ok(QualityScores::default()));
+
+        assert!(QualityScores::from_bytes(&[45, 35, 200, 50]).is_err());
+    }
+
+    #[test]
+    fn test_as_bytes() -> Result<(), score::TryFromUByteError> {
+        let data = [45, 35, 43, 50, 0];
+        let quality_scores = QualityScores::from_bytes(&data)?;
+        assert_eq!(quality_scores.as_bytes(), data);
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() -> Result<(), score::TryFromUByteError> {
         let quality_scores = [45, 35, 43, 50, 0]
@@ -9,6 +9,9 @@ use std::{convert::TryFrom, error, fmt, ops::Deref, str::FromStr};
 use super::NULL_FIELD;
 
 /// A SAM record sequence.
+///
+/// Bases are case-insensitive and are stored in uppercase, so lowercase (e.g., soft-masked)
+/// bases are not preserved.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Sequence(Vec<Base>);
 
@@ -69,7 +72,6 @@ impl FromStr for Sequence {
             NULL_FIELD => Ok(Self::default()),
             _ => s
                 .chars()
-                .map(|c| c.to_ascii_uppercase())
                 .map(Base::try_from)
                 .collect::<Result<Vec<_>, _>>()
                 .map(Self::from)
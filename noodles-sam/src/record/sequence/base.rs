@@ -59,6 +59,40 @@ pub enum Base {
     Eq,
 }
 
+impl Base {
+    /// Returns the complement of this base.
+    ///
+    /// IUPAC ambiguity codes are complemented to the code of the complementary bases, e.g., `R`
+    /// (A or G) to `Y` (C or T). Uracil is complemented to adenine. Bases without a complement,
+    /// e.g., `N`, `S`, `W`, undefined bases, and `=`, are returned as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::sequence::Base;
+    /// assert_eq!(Base::A.complement(), Base::T);
+    /// assert_eq!(Base::R.complement(), Base::Y);
+    /// assert_eq!(Base::N.complement(), Base::N);
+    /// ```
+    pub fn complement(self) -> Self {
+        match self {
+            Self::A => Self::T,
+            Self::C => Self::G,
+            Self::G => Self::C,
+            Self::T | Self::U => Self::A,
+            Self::R => Self::Y,
+            Self::Y => Self::R,
+            Self::K => Self::M,
+            Self::M => Self::K,
+            Self::B => Self::V,
+            Self::V => Self::B,
+            Self::D => Self::H,
+            Self::H => Self::D,
+            base => base,
+        }
+    }
+}
+
 impl fmt::Display for Base {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", char::from(*self))
@@ -80,8 +114,13 @@ impl fmt::Display for TryFromCharError {
 impl TryFrom<char> for Base {
     type Error = TryFromCharError;
 
+    /// Converts a character to a base.
+    ///
+    /// The conversion is case-insensitive, e.g., lowercase soft-masked bases (`acgt`) are
+    /// converted to the same bases as uppercase ones. Bases do not keep their case, which matches
+    /// BAM, where the encoding has no case.
     fn try_from(c: char) -> Result<Self, Self::Error> {
-        match c {
+        match c.to_ascii_uppercase() {
             'A' => Ok(Self::A),
             'B' => Ok(Self::B),
             'C' => Ok(Self::C),
@@ -152,6 +191,30 @@ impl From<Base> for char {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_complement() {
+        assert_eq!(Base::A.complement(), Base::T);
+        assert_eq!(Base::C.complement(), Base::G);
+        assert_eq!(Base::G.complement(), Base::C);
+        assert_eq!(Base::T.complement(), Base::A);
+        assert_eq!(Base::U.complement(), Base::A);
+
+        assert_eq!(Base::R.complement(), Base::Y);
+        assert_eq!(Base::Y.complement(), Base::R);
+        assert_eq!(Base::K.complement(), Base::M);
+        assert_eq!(Base::M.complement(), Base::K);
+        assert_eq!(Base::B.complement(), Base::V);
+        assert_eq!(Base::V.complement(), Base::B);
+        assert_eq!(Base::D.complement(), Base::H);
+        assert_eq!(Base::H.complement(), Base::D);
+
+        assert_eq!(Base::N.complement(), Base::N);
+        assert_eq!(Base::S.complement(), Base::S);
+        assert_eq!(Base::W.complement(), Base::W);
+        assert_eq!(Base::X.complement(), Base::X);
+        assert_eq!(Base::Eq.complement(), Base::Eq);
+    }
+
     #[test]
     fn test_try_from_char_for_base() {
         assert_eq!(Base::try_from('A'), Ok(Base::A));
@@ -182,6 +245,12 @@ mod tests {
         assert_eq!(Base::try_from('Z'), Ok(Base::Z));
         assert_eq!(Base::try_from('='), Ok(Base::Eq));
 
+        assert_eq!(Base::try_from('a'), Ok(Base::A));
+        assert_eq!(Base::try_from('c'), Ok(Base::C));
+        assert_eq!(Base::try_from('g'), Ok(Base::G));
+        assert_eq!(Base::try_from('t'), Ok(Base::T));
+        assert_eq!(Base::try_from('n'), Ok(Base::N));
+
         assert_eq!(Base::try_from('*'), Err(TryFromCharError('*')));
     }
 }
@@ -98,6 +98,10 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_header(&mut self, header: &Header) -> io::Result<()> {
+        header
+            .validate()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
         write!(self.inner, "{}", header)
     }
 
@@ -130,6 +134,42 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_write_header_with_invalid_read_group() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::header::ReadGroup;
+
+        let mut writer = Writer::new(Vec::new());
+        let header = Header::builder()
+            .add_read_group(
+                ReadGroup::builder()
+                    .set_id("rg0")
+                    .set_description("a\tb")
+                    .build()?,
+            )
+            .build();
+
+        assert!(matches!(
+            writer.write_header(&header),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+        assert!(writer.get_ref().is_empty());
+
+        let mut writer = Writer::new(Vec::new());
+        let header = Header::builder()
+            .add_read_group(
+                ReadGroup::builder()
+                    .set_id("rg0")
+                    .set_description("a b")
+                    .build()?,
+            )
+            .build();
+
+        writer.write_header(&header)?;
+        assert_eq!(writer.get_ref(), b"@RG\tID:rg0\tDS:a b\n");
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_record_with_data() -> Result<(), Box<dyn std::error::Error>> {
         let mut writer = Writer::new(vec![]);
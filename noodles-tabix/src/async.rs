@@ -7,7 +7,10 @@ pub use self::{reader::Reader, writer::Writer};
 
 use std::path::Path;
 
-use tokio::{fs::File, io};
+use tokio::{
+    fs::File,
+    io::{self, AsyncRead},
+};
 
 use super::Index;
 
@@ -32,7 +35,33 @@ pub async fn read<P>(src: P) -> io::Result<Index>
 where
     P: AsRef<Path>,
 {
-    let mut reader = File::open(src).await.map(Reader::new)?;
+    let file = File::open(src).await?;
+    read_from(file).await
+}
+
+/// Reads the entire contents of a tabix index from an async reader.
+///
+/// The input is expected to be the raw (BGZF-compressed) index, e.g., the contents of a `.tbi`
+/// file that is already in memory.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::io;
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> io::Result<()> {
+/// use noodles_tabix as tabix;
+/// let data = tokio::fs::read("sample.vcf.gz.tbi").await?;
+/// let index = tabix::r#async::read_from(&data[..]).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn read_from<R>(reader: R) -> io::Result<Index>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut reader = Reader::new(reader);
     reader.read_index().await
 }
 
@@ -63,3 +92,28 @@ where
     writer.shutdown().await?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use noodles_csi::BinningIndex;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_read_from() -> io::Result<()> {
+        let index = Index::builder()
+            .set_unplaced_unmapped_record_count(13)
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_index(&index).await?;
+        writer.shutdown().await?;
+
+        let data = writer.into_inner();
+        let actual = read_from(&data[..]).await?;
+
+        assert_eq!(actual.unplaced_unmapped_record_count(), Some(13));
+
+        Ok(())
+    }
+}
@@ -1,7 +1,10 @@
 use std::convert::TryFrom;
 
 use noodles_bgzf as bgzf;
-use noodles_csi::index::reference_sequence::{bin::Chunk, Metadata};
+use noodles_csi::{
+    self as csi,
+    index::reference_sequence::{bin::Chunk, Metadata},
+};
 use tokio::io::{self, AsyncRead, AsyncReadExt};
 
 use crate::{
@@ -78,7 +81,8 @@ where
     let reference_sequence_names = read_reference_sequence_names(reader).await?;
     let reference_sequences = read_reference_sequences(reader, n_ref).await?;
 
-    let unplaced_unmapped_record_count = read_unplaced_unmapped_record_count(reader).await?;
+    let unplaced_unmapped_record_count =
+        csi::r#async::read_unplaced_unmapped_record_count(reader).await?;
 
     let mut builder = Index::builder()
         .set_header(header)
@@ -319,17 +323,6 @@ where
     Ok(Metadata::new(ref_beg, ref_end, n_mapped, n_unmapped))
 }
 
-async fn read_unplaced_unmapped_record_count<R>(reader: &mut R) -> io::Result<Option<u64>>
-where
-    R: AsyncRead + Unpin,
-{
-    match reader.read_u64_le().await {
-        Ok(n_no_coor) => Ok(Some(n_no_coor)),
-        Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => Ok(None),
-        Err(e) => Err(e),
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -37,7 +37,11 @@ pub use self::{index::Index, reader::Reader, writer::Writer};
 #[cfg(feature = "async")]
 pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
 
-use std::{fs::File, io, path::Path};
+use std::{
+    fs::File,
+    io::{self, Read},
+    path::Path,
+};
 
 static MAGIC_NUMBER: &[u8] = b"TBI\x01";
 
@@ -58,7 +62,28 @@ pub fn read<P>(src: P) -> io::Result<Index>
 where
     P: AsRef<Path>,
 {
-    let mut reader = File::open(src).map(Reader::new)?;
+    File::open(src).and_then(read_from)
+}
+
+/// Reads the entire contents of a tabix index from a reader.
+///
+/// The input is expected to be the raw (BGZF-compressed) index, e.g., the contents of a `.tbi`
+/// file that is already in memory.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::io;
+/// use noodles_tabix as tabix;
+/// let data = std::fs::read("sample.vcf.gz.tbi")?;
+/// let index = tabix::read_from(&data[..])?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn read_from<R>(reader: R) -> io::Result<Index>
+where
+    R: Read,
+{
+    let mut reader = Reader::new(reader);
     reader.read_index()
 }
 
@@ -84,3 +109,28 @@ where
     writer.write_index(index)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use noodles_csi::BinningIndex;
+
+    use super::*;
+
+    #[test]
+    fn test_read_from() -> io::Result<()> {
+        let index = Index::builder()
+            .set_unplaced_unmapped_record_count(13)
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_index(&index)?;
+        writer.try_finish()?;
+
+        let data = writer.get_ref();
+        let actual = read_from(&data[..])?;
+
+        assert_eq!(actual.unplaced_unmapped_record_count(), Some(13));
+
+        Ok(())
+    }
+}
@@ -6,7 +6,10 @@ use std::{
 
 use byteorder::{LittleEndian, ReadBytesExt};
 use noodles_bgzf as bgzf;
-use noodles_csi::index::reference_sequence::{bin::Chunk, Metadata};
+use noodles_csi::{
+    self as csi,
+    index::reference_sequence::{bin::Chunk, Metadata},
+};
 
 use crate::index::{
     self,
@@ -70,7 +73,7 @@ where
 
         let names = read_names(&mut self.inner)?;
         let references = read_references(&mut self.inner, n_ref)?;
-        let n_no_coor = read_unplaced_unmapped_record_count(&mut self.inner)?;
+        let n_no_coor = csi::read_unplaced_unmapped_record_count(&mut self.inner)?;
 
         let mut builder = Index::builder()
             .set_header(header)
@@ -310,19 +313,10 @@ where
     Ok(Metadata::new(ref_beg, ref_end, n_mapped, n_unmapped))
 }
 
-fn read_unplaced_unmapped_record_count<R>(reader: &mut R) -> io::Result<Option<u64>>
-where
-    R: Read,
-{
-    match reader.read_u64::<LittleEndian>() {
-        Ok(n) => Ok(Some(n)),
-        Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => Ok(None),
-        Err(e) => Err(e),
-    }
-}
-
 #[cfg(test)]
 mod tests {
+    use noodles_csi::BinningIndex;
+
     use super::*;
 
     #[test]
@@ -480,14 +474,38 @@ mod tests {
     }
 
     #[test]
-    fn test_read_unplaced_unmapped_record_count() -> io::Result<()> {
-        let data = [];
-        let mut reader = &data[..];
-        assert_eq!(read_unplaced_unmapped_record_count(&mut reader)?, None);
+    fn test_read_index_with_unplaced_unmapped_record_count() -> io::Result<()> {
+        fn compress(data: &[u8]) -> io::Result<Vec<u8>> {
+            use std::io::Write;
 
-        let data = [0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
-        let mut reader = &data[..];
-        assert_eq!(read_unplaced_unmapped_record_count(&mut reader)?, Some(8));
+            let mut writer = bgzf::Writer::new(Vec::new());
+            writer.write_all(data)?;
+            writer.finish()
+        }
+
+        let mut data = vec![
+            b'T', b'B', b'I', 0x01, // magic
+            0x00, 0x00, 0x00, 0x00, // n_ref = 0
+            0x00, 0x00, 0x00, 0x00, // format = Generic(GFF)
+            0x01, 0x00, 0x00, 0x00, // col_seq = 1
+            0x04, 0x00, 0x00, 0x00, // col_beg = 4
+            0x05, 0x00, 0x00, 0x00, // col_end = 5
+            0x23, 0x00, 0x00, 0x00, // meta = '#'
+            0x00, 0x00, 0x00, 0x00, // skip = 0
+            0x00, 0x00, 0x00, 0x00, // l_nm = 0
+        ];
+
+        let compressed_data = compress(&data)?;
+        let mut reader = Reader::new(&compressed_data[..]);
+        let index = reader.read_index()?;
+        assert!(index.unplaced_unmapped_record_count().is_none());
+
+        data.extend_from_slice(&[0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]); // n_no_coor = 8
+
+        let compressed_data = compress(&data)?;
+        let mut reader = Reader::new(&compressed_data[..]);
+        let index = reader.read_index()?;
+        assert_eq!(index.unplaced_unmapped_record_count(), Some(8));
 
         Ok(())
     }
@@ -9,7 +9,10 @@ use noodles_tabix as tabix;
 use tokio::io::{self, AsyncBufRead, AsyncBufReadExt, AsyncRead, AsyncSeek};
 
 use self::query::query;
-use crate::{reader::resolve_region, Record};
+use crate::{
+    reader::resolve_region,
+    record::{ParseMode, Record},
+};
 
 const LINE_FEED: char = '\n';
 const CARRIAGE_RETURN: char = '\r';
@@ -53,6 +56,7 @@ const HEADER_PREFIX: u8 = b'#';
 /// ```
 pub struct Reader<R> {
     inner: R,
+    parse_mode: ParseMode,
 }
 
 impl<R> Reader<R>
@@ -69,7 +73,47 @@ where
     /// let reader = vcf::AsyncReader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            parse_mode: ParseMode::default(),
+        }
+    }
+
+    /// Returns the parse mode used to parse records.
+    ///
+    /// The default is [`ParseMode::Strict`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, record::ParseMode};
+    /// let data = [];
+    /// let reader = vcf::AsyncReader::new(&data[..]);
+    /// assert_eq!(reader.parse_mode(), ParseMode::Strict);
+    /// ```
+    pub fn parse_mode(&self) -> ParseMode {
+        self.parse_mode
+    }
+
+    /// Sets the parse mode used to parse records.
+    ///
+    /// This applies to records parsed by the reader, e.g., using [`Self::records`]. See
+    /// [`ParseMode`] for the deviations from the specification that are tolerated in lenient
+    /// mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, record::ParseMode};
+    ///
+    /// let data = [];
+    /// let mut reader = vcf::AsyncReader::new(&data[..]);
+    /// reader.set_parse_mode(ParseMode::Lenient);
+    ///
+    /// assert_eq!(reader.parse_mode(), ParseMode::Lenient);
+    /// ```
+    pub fn set_parse_mode(&mut self, parse_mode: ParseMode) {
+        self.parse_mode = parse_mode;
     }
 
     /// Reads the raw VCF header.
@@ -184,15 +228,16 @@ where
     /// # }
     /// ```
     pub fn records(&mut self) -> impl Stream<Item = io::Result<Record>> + '_ {
+        let parse_mode = self.parse_mode;
+
         Box::pin(stream::try_unfold(
             (&mut self.inner, String::new()),
-            |(mut reader, mut buf)| async {
+            move |(mut reader, mut buf)| async move {
                 buf.clear();
 
                 match read_line(&mut reader, &mut buf).await? {
                     0 => Ok(None),
-                    _ => buf
-                        .parse()
+                    _ => Record::from_str_mode(&buf, parse_mode)
                         .map(|record| Some((record, (reader, buf))))
                         .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
                 }
@@ -111,8 +111,7 @@ where
 
     match reader.read_record(&mut buf).await? {
         0 => Ok(None),
-        _ => buf
-            .parse()
+        _ => Record::from_str_mode(&buf, reader.parse_mode())
             .map(Some)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
     }
@@ -1,6 +1,6 @@
 use tokio::io::{self, AsyncWrite, AsyncWriteExt};
 
-use crate::{Header, Record};
+use crate::{writer::validate_genotypes, Header, Record};
 
 const LINE_FEED: u8 = b'\n';
 
@@ -53,6 +53,10 @@ where
 
     /// Writes a VCF record.
     ///
+    /// Genotype fields are written in the order of the record's own format (`FORMAT`), which may
+    /// differ between records. This returns an error if a genotype has a key that is not in the
+    /// format or if the record has genotypes but no format.
+    ///
     /// # Examples
     ///
     /// ```
@@ -74,9 +78,58 @@ where
     /// # }
     /// ```
     pub async fn write_record(&mut self, record: &Record) -> io::Result<()> {
+        validate_genotypes(record)?;
+
         let raw_record = record.to_string();
         self.inner.write_all(raw_record.as_bytes()).await?;
         self.inner.write_u8(LINE_FEED).await?;
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::record::{Format, Genotype, Position};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_write_record_with_format() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = Writer::new(Vec::new());
+
+        let format: Format = "GT:GQ".parse()?;
+
+        let record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::try_from(1)?)
+            .set_reference_bases("A".parse()?)
+            .set_format(format.clone())
+            .add_genotype(Genotype::from_str_format("0|0:13", &format)?)
+            .build()?;
+
+        writer.write_record(&record).await?;
+
+        let expected = b"sq0\t1\t.\tA\t.\t.\t.\t.\tGT:GQ\t0|0:13\n";
+        assert_eq!(writer.inner, expected);
+
+        // GQ is not in the format.
+        let record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::try_from(2)?)
+            .set_reference_bases("C".parse()?)
+            .set_format("GT".parse()?)
+            .add_genotype(Genotype::from_str_format("0|0:13", &format)?)
+            .build()?;
+
+        let mut writer = Writer::new(Vec::new());
+        assert!(matches!(
+            writer.write_record(&record).await,
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+        assert!(writer.inner.is_empty());
+
+        Ok(())
+    }
+}
@@ -127,6 +127,27 @@ impl Header {
         &self.infos
     }
 
+    /// Returns the information record (`INFO`) with the given ID.
+    ///
+    /// Unlike a lookup in [`Self::infos`], this only compares IDs. It finds the record of a
+    /// nonstandard field regardless of the number, type, and description carried by its key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::Info, record::info::field::Key};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_info(Info::from(Key::SamplesWithDataCount))
+    ///     .build();
+    ///
+    /// assert!(header.info("NS").is_some());
+    /// assert!(header.info("NDLS").is_none());
+    /// ```
+    pub fn info(&self, id: &str) -> Option<&Info> {
+        self.infos.values().find(|info| info.id().as_ref() == id)
+    }
+
     /// Returns a map of filter records (`FILTER`).
     ///
     /// # Examples
@@ -165,6 +186,29 @@ impl Header {
         &self.formats
     }
 
+    /// Returns the genotype format record (`FORMAT`) with the given ID.
+    ///
+    /// Unlike a lookup in [`Self::formats`], this only compares IDs. It finds the record of a
+    /// nonstandard field regardless of the number, type, and description carried by its key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::Format, record::genotype::field::Key};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_format(Format::from(Key::Genotype))
+    ///     .build();
+    ///
+    /// assert!(header.format("GT").is_some());
+    /// assert!(header.format("NDLS").is_none());
+    /// ```
+    pub fn format(&self, id: &str) -> Option<&Format> {
+        self.formats
+            .values()
+            .find(|format| format.id().as_ref() == id)
+    }
+
     /// Returns a map of symbolic alternate alleles (`ALT`).
     ///
     /// # Examples
@@ -830,6 +874,45 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_info_and_format() -> Result<(), ParseError> {
+        use crate::header::{info, Number};
+
+        let header: Header = r#"##fileformat=VCFv4.3
+##INFO=<ID=NS,Number=1,Type=Integer,Description="Number of samples with data">
+##INFO=<ID=NDLS,Number=A,Type=Float,Description="noodles">
+##FORMAT=<ID=GT,Number=1,Type=String,Description="Genotype">
+##FORMAT=<ID=NDLS,Number=.,Type=Integer,Description="noodles">
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO	FORMAT	sample0
+"#
+        .parse()?;
+
+        let info = header.info("NS");
+        assert_eq!(
+            info.map(|info| info.id()),
+            Some(&crate::record::info::field::Key::SamplesWithDataCount)
+        );
+
+        let info = header.info("NDLS");
+        assert_eq!(info.map(|info| info.number()), Some(Number::A));
+        assert_eq!(info.map(|info| info.ty()), Some(info::Type::Float));
+
+        assert!(header.info("AF").is_none());
+
+        let format = header.format("GT");
+        assert_eq!(
+            format.map(|format| format.id()),
+            Some(&crate::record::genotype::field::Key::Genotype)
+        );
+
+        let format = header.format("NDLS");
+        assert_eq!(format.map(|format| format.number()), Some(Number::Unknown));
+
+        assert!(header.format("GQ").is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn test_from_str_without_file_format() {
         let s = r#"##ALT=<ID=DEL,Description="Deletion">
@@ -92,6 +92,22 @@ impl Contig {
     pub fn get(&self, key: &str) -> Option<&str> {
         self.fields.get(key).map(|s| &**s)
     }
+
+    /// Returns the extra fields in the record.
+    ///
+    /// This includes fields other than `ID` and `length`. The fields are in the order they were
+    /// read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Contig;
+    /// let contig = Contig::new("sq0");
+    /// assert!(contig.fields().is_empty());
+    /// ```
+    pub fn fields(&self) -> &IndexMap<String, String> {
+        &self.fields
+    }
 }
 
 impl fmt::Display for Contig {
@@ -221,6 +237,20 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_fmt_with_extra_fields() -> Result<(), Box<dyn std::error::Error>> {
+        let s = r#"##contig=<ID=sq0,length=13,species="Homo sapiens",md5="d7eba311421bbc9d3ada44709dd61534",assembly="GRCh38">"#;
+        let contig = Contig::try_from(s.parse::<Record>()?)?;
+
+        let keys: Vec<_> = contig.fields().keys().map(|key| key.as_str()).collect();
+        assert_eq!(keys, ["species", "md5", "assembly"]);
+
+        assert_eq!(contig.to_string(), s);
+        assert_eq!(contig.clone().to_string(), s);
+
+        Ok(())
+    }
+
     #[test]
     fn test_try_from_record_for_contig() {
         let record = build_record();
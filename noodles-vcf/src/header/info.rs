@@ -1,9 +1,10 @@
 //! VCF header information record and components.
 
+pub mod builder;
 pub mod key;
 pub mod ty;
 
-pub use self::{key::Key, ty::Type};
+pub use self::{builder::Builder, key::Key, ty::Type};
 
 use std::{convert::TryFrom, error, fmt, num};
 
@@ -25,6 +26,18 @@ pub struct Info {
 }
 
 impl Info {
+    /// Returns a builder to create an information record from each of its fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Info;
+    /// let builder = Info::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
     /// Creates a VCF header information record.
     ///
     /// # Examples
@@ -134,6 +147,61 @@ impl Info {
     pub fn fields(&self) -> &IndexMap<String, String> {
         &self.fields
     }
+
+    /// Validates the cardinality of an information field value against the declared number.
+    ///
+    /// The alternate allele count is used to compute the expected number of values for the `A`,
+    /// `R`, and `G` numbers. For `G`, samples are assumed to be diploid. Values with an unknown
+    /// number (`.`) are always valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     header::{info::Type, Info, Number},
+    ///     record::info::field::{Key, Value},
+    /// };
+    ///
+    /// let info = Info::from(Key::SamplesWithDataCount);
+    /// assert!(info.validate_value(&Value::Integer(2), 1).is_ok());
+    /// assert!(info.validate_value(&Value::IntegerArray(vec![2, 3]), 1).is_err());
+    ///
+    /// let info = Info::from(Key::AlleleFrequencies);
+    /// assert!(info.validate_value(&Value::FloatArray(vec![0.25, 0.5]), 2).is_ok());
+    /// assert!(info.validate_value(&Value::FloatArray(vec![0.25]), 2).is_err());
+    /// ```
+    pub fn validate_value(
+        &self,
+        value: &info::field::Value,
+        alternate_allele_count: usize,
+    ) -> Result<(), ValidateValueError> {
+        use info::field::Value;
+
+        let allele_count = alternate_allele_count + 1;
+
+        let expected_len = match self.number {
+            Number::Count(n) => n,
+            Number::A => alternate_allele_count,
+            Number::R => allele_count,
+            Number::G => allele_count * (allele_count + 1) / 2,
+            Number::Unknown => return Ok(()),
+        };
+
+        let actual_len = match value {
+            Value::Flag => 0,
+            Value::Integer(_) | Value::Float(_) | Value::Character(_) | Value::String(_) => 1,
+            Value::IntegerArray(values) => values.len(),
+            Value::FloatArray(values) => values.len(),
+            Value::CharacterArray(values) => values.len(),
+            Value::StringArray(values) => values.len(),
+        };
+
+        if actual_len == expected_len {
+            Ok(())
+        } else {
+            Err(ValidateValueError::CountMismatch(actual_len, expected_len))
+        }
+    }
 }
 
 impl From<info::field::Key> for Info {
@@ -215,6 +283,27 @@ impl fmt::Display for TryFromRecordError {
     }
 }
 
+/// An error returned when an information field value does not match its header definition.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidateValueError {
+    /// The number of values does not match the declared number.
+    CountMismatch(usize, usize),
+}
+
+impl error::Error for ValidateValueError {}
+
+impl fmt::Display for ValidateValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CountMismatch(actual, expected) => write!(
+                f,
+                "value count mismatch: expected {}, got {}",
+                expected, actual
+            ),
+        }
+    }
+}
+
 impl TryFrom<Record> for Info {
     type Error = TryFromRecordError;
 
@@ -343,6 +432,20 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_fmt_with_extra_fields() -> Result<(), Box<dyn std::error::Error>> {
+        let s = r#"##INFO=<ID=NS,Number=1,Type=Integer,Description="Number of samples with data",Version="1",Source="noodles",Accession="nd0">"#;
+        let info = Info::try_from(s.parse::<Record>()?)?;
+
+        let keys: Vec<_> = info.fields().keys().map(|key| key.as_str()).collect();
+        assert_eq!(keys, ["Version", "Source", "Accession"]);
+
+        assert_eq!(info.to_string(), s);
+        assert_eq!(info.clone().to_string(), s);
+
+        Ok(())
+    }
+
     #[test]
     fn test_try_from_record_for_info() {
         let record = build_record();
@@ -570,4 +673,61 @@ mod tests {
             ))
         ));
     }
+
+    #[test]
+    fn test_validate_value() {
+        use info::field::{Key, Value};
+
+        let info = Info::from(Key::SamplesWithDataCount);
+        assert_eq!(info.validate_value(&Value::Integer(2), 1), Ok(()));
+        assert_eq!(
+            info.validate_value(&Value::IntegerArray(vec![2, 3]), 1),
+            Err(ValidateValueError::CountMismatch(2, 1))
+        );
+
+        let info = Info::from(Key::AlleleFrequencies);
+        assert_eq!(
+            info.validate_value(&Value::FloatArray(vec![0.25, 0.5]), 2),
+            Ok(())
+        );
+        assert_eq!(
+            info.validate_value(&Value::FloatArray(vec![0.25]), 2),
+            Err(ValidateValueError::CountMismatch(1, 2))
+        );
+
+        let info = Info::from(Key::TotalReadDepths);
+        assert_eq!(
+            info.validate_value(&Value::IntegerArray(vec![5, 8, 13]), 2),
+            Ok(())
+        );
+
+        let info = Info::new(
+            Key::Other(
+                String::from("GL"),
+                Number::G,
+                Type::Float,
+                String::default(),
+            ),
+            Number::G,
+            Type::Float,
+            String::default(),
+        );
+        assert_eq!(
+            info.validate_value(&Value::FloatArray(vec![0.0; 6]), 2),
+            Ok(())
+        );
+        assert_eq!(
+            info.validate_value(&Value::FloatArray(vec![0.0; 3]), 2),
+            Err(ValidateValueError::CountMismatch(3, 6))
+        );
+
+        let info = Info::from(Key::IsSomaticMutation);
+        assert_eq!(info.validate_value(&Value::Flag, 1), Ok(()));
+
+        let info = Info::from(Key::SvLengths);
+        assert_eq!(
+            info.validate_value(&Value::IntegerArray(vec![-8]), 3),
+            Ok(())
+        );
+    }
 }
@@ -0,0 +1,238 @@
+//! VCF header information record builder.
+
+use std::{error, fmt};
+
+use indexmap::IndexMap;
+
+use super::{Info, Type};
+use crate::{header::Number, record::info};
+
+/// A VCF header information record builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    id: Option<info::field::Key>,
+    number: Option<Number>,
+    ty: Option<Type>,
+    description: Option<String>,
+    fields: IndexMap<String, String>,
+}
+
+/// An error returned when a VCF header information record fails to build.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BuildError {
+    /// The ID is missing.
+    MissingId,
+    /// The number is missing.
+    MissingNumber,
+    /// The type is missing.
+    MissingType,
+    /// The description is missing.
+    MissingDescription,
+}
+
+impl error::Error for BuildError {}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingId => f.write_str("missing ID"),
+            Self::MissingNumber => f.write_str("missing number"),
+            Self::MissingType => f.write_str("missing type"),
+            Self::MissingDescription => f.write_str("missing description"),
+        }
+    }
+}
+
+impl Builder {
+    /// Sets the information field key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{header::{info::Type, Info, Number}, record::info::field::Key};
+    ///
+    /// let info = Info::builder()
+    ///     .set_id(Key::SamplesWithDataCount)
+    ///     .set_number(Number::Count(1))
+    ///     .set_type(Type::Integer)
+    ///     .set_description("Number of samples with data")
+    ///     .build()?;
+    ///
+    /// assert_eq!(info.id(), &Key::SamplesWithDataCount);
+    /// # Ok::<(), noodles_vcf::header::info::builder::BuildError>(())
+    /// ```
+    pub fn set_id(mut self, id: info::field::Key) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the cardinality of the information field value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{header::{info::Type, Info, Number}, record::info::field::Key};
+    ///
+    /// let info = Info::builder()
+    ///     .set_id(Key::SamplesWithDataCount)
+    ///     .set_number(Number::Count(1))
+    ///     .set_type(Type::Integer)
+    ///     .set_description("Number of samples with data")
+    ///     .build()?;
+    ///
+    /// assert_eq!(info.number(), Number::Count(1));
+    /// # Ok::<(), noodles_vcf::header::info::builder::BuildError>(())
+    /// ```
+    pub fn set_number(mut self, number: Number) -> Self {
+        self.number = Some(number);
+        self
+    }
+
+    /// Sets the type of the information field value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{header::{info::Type, Info, Number}, record::info::field::Key};
+    ///
+    /// let info = Info::builder()
+    ///     .set_id(Key::SamplesWithDataCount)
+    ///     .set_number(Number::Count(1))
+    ///     .set_type(Type::Integer)
+    ///     .set_description("Number of samples with data")
+    ///     .build()?;
+    ///
+    /// assert_eq!(info.ty(), Type::Integer);
+    /// # Ok::<(), noodles_vcf::header::info::builder::BuildError>(())
+    /// ```
+    pub fn set_type(mut self, ty: Type) -> Self {
+        self.ty = Some(ty);
+        self
+    }
+
+    /// Sets the description of the information field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{header::{info::Type, Info, Number}, record::info::field::Key};
+    ///
+    /// let info = Info::builder()
+    ///     .set_id(Key::SamplesWithDataCount)
+    ///     .set_number(Number::Count(1))
+    ///     .set_type(Type::Integer)
+    ///     .set_description("Number of samples with data")
+    ///     .build()?;
+    ///
+    /// assert_eq!(info.description(), "Number of samples with data");
+    /// # Ok::<(), noodles_vcf::header::info::builder::BuildError>(())
+    /// ```
+    pub fn set_description<I>(mut self, description: I) -> Self
+    where
+        I: Into<String>,
+    {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Inserts an extra field.
+    ///
+    /// This is a field other than `ID`, `Number`, `Type`, `Description`, and `IDX`. If the key
+    /// already exists, its value is replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{header::{info::Type, Info, Number}, record::info::field::Key};
+    ///
+    /// let info = Info::builder()
+    ///     .set_id(Key::SamplesWithDataCount)
+    ///     .set_number(Number::Count(1))
+    ///     .set_type(Type::Integer)
+    ///     .set_description("Number of samples with data")
+    ///     .insert_field("Source", "noodles")
+    ///     .build()?;
+    ///
+    /// assert_eq!(info.fields().get("Source").map(|s| s.as_str()), Some("noodles"));
+    /// # Ok::<(), noodles_vcf::header::info::builder::BuildError>(())
+    /// ```
+    pub fn insert_field<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.fields.insert(key.into(), value.into());
+        self
+    }
+
+    /// Builds a VCF header information record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Info;
+    /// assert!(Info::builder().build().is_err());
+    /// ```
+    pub fn build(self) -> Result<Info, BuildError> {
+        Ok(Info {
+            id: self.id.ok_or(BuildError::MissingId)?,
+            number: self.number.ok_or(BuildError::MissingNumber)?,
+            ty: self.ty.ok_or(BuildError::MissingType)?,
+            description: self.description.ok_or(BuildError::MissingDescription)?,
+            idx: None,
+            fields: self.fields,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build() -> Result<(), BuildError> {
+        let info = Builder::default()
+            .set_id(info::field::Key::SamplesWithDataCount)
+            .set_number(Number::Count(1))
+            .set_type(Type::Integer)
+            .set_description("Number of samples with data")
+            .insert_field("Source", "noodles")
+            .build()?;
+
+        assert_eq!(
+            info.to_string(),
+            r#"##INFO=<ID=NS,Number=1,Type=Integer,Description="Number of samples with data",Source="noodles">"#
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_missing_fields() {
+        assert_eq!(Builder::default().build(), Err(BuildError::MissingId));
+
+        assert_eq!(
+            Builder::default()
+                .set_id(info::field::Key::SamplesWithDataCount)
+                .build(),
+            Err(BuildError::MissingNumber)
+        );
+
+        assert_eq!(
+            Builder::default()
+                .set_id(info::field::Key::SamplesWithDataCount)
+                .set_number(Number::Count(1))
+                .build(),
+            Err(BuildError::MissingType)
+        );
+
+        assert_eq!(
+            Builder::default()
+                .set_id(info::field::Key::SamplesWithDataCount)
+                .set_number(Number::Count(1))
+                .set_type(Type::Integer)
+                .build(),
+            Err(BuildError::MissingDescription)
+        );
+    }
+}
@@ -0,0 +1,324 @@
+use std::{
+    collections::HashSet,
+    io::{self, Write},
+};
+
+use noodles_bgzf as bgzf;
+use noodles_csi::index::reference_sequence::bin::Chunk;
+use noodles_tabix as tabix;
+
+use super::{Header, Record, Writer};
+
+/// A bgzipped VCF writer that builds a tabix index of the records it writes.
+///
+/// Records must be written in coordinate-sorted order, i.e., grouped by chromosome and, within a
+/// chromosome, in ascending position order. By default, this is verified on each write.
+///
+/// # Examples
+///
+/// ```
+/// # use std::convert::TryFrom;
+/// use noodles_vcf::{self as vcf, header::Contig, record::Position};
+///
+/// let mut writer = vcf::IndexedWriter::new(Vec::new());
+///
+/// let header = vcf::Header::builder()
+///     .add_contig(Contig::new("sq0"))
+///     .build();
+///
+/// writer.write_header(&header)?;
+///
+/// let record = vcf::Record::builder()
+///     .set_chromosome("sq0".parse()?)
+///     .set_position(Position::try_from(8)?)
+///     .set_reference_bases("A".parse()?)
+///     .build()?;
+///
+/// writer.write_record(&record)?;
+///
+/// let (_, index) = writer.finish()?;
+/// assert_eq!(index.reference_sequence_names().len(), 1);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct IndexedWriter<W>
+where
+    W: Write,
+{
+    inner: Writer<bgzf::Writer<W>>,
+    indexer: tabix::index::Indexer,
+    verify_sort_order: bool,
+    previous_record: Option<(String, i32)>,
+    reference_sequence_names: HashSet<String>,
+}
+
+impl<W> IndexedWriter<W>
+where
+    W: Write,
+{
+    /// Creates an indexed VCF writer.
+    ///
+    /// The output is bgzip-compressed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let writer = vcf::IndexedWriter::new(Vec::new());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        let mut indexer = tabix::Index::indexer();
+        indexer.set_header(tabix::index::header::Builder::vcf().build());
+
+        Self {
+            inner: Writer::new(bgzf::Writer::new(inner)),
+            indexer,
+            verify_sort_order: true,
+            previous_record: None,
+            reference_sequence_names: HashSet::new(),
+        }
+    }
+
+    /// Returns whether the sort order of written records is verified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let writer = vcf::IndexedWriter::new(Vec::new());
+    /// assert!(writer.verify_sort_order());
+    /// ```
+    pub fn verify_sort_order(&self) -> bool {
+        self.verify_sort_order
+    }
+
+    /// Sets whether to verify the sort order of written records.
+    ///
+    /// Records must still be written in sorted order when verification is disabled; otherwise,
+    /// the resulting index is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let mut writer = vcf::IndexedWriter::new(Vec::new());
+    /// writer.set_verify_sort_order(false);
+    /// assert!(!writer.verify_sort_order());
+    /// ```
+    pub fn set_verify_sort_order(&mut self, verify_sort_order: bool) {
+        self.verify_sort_order = verify_sort_order;
+    }
+
+    /// Writes a VCF header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut writer = vcf::IndexedWriter::new(Vec::new());
+    ///
+    /// let header = vcf::Header::default();
+    /// writer.write_header(&header)?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn write_header(&mut self, header: &Header) -> io::Result<()> {
+        self.inner.write_header(header)
+    }
+
+    /// Writes a VCF record and adds it to the index.
+    ///
+    /// If sort order verification is enabled, this returns an error if the record is not sorted
+    /// relative to the previously written records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// use noodles_vcf::{self as vcf, record::Position};
+    ///
+    /// let record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::try_from(1)?)
+    ///     .set_reference_bases("A".parse()?)
+    ///     .build()?;
+    ///
+    /// let mut writer = vcf::IndexedWriter::new(Vec::new());
+    /// writer.write_record(&record)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
+        let reference_sequence_name = record.chromosome().to_string();
+        let start = i32::from(record.position());
+        let end = record
+            .end()
+            .map(i32::from)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        if self.verify_sort_order {
+            self.check_sort_order(&reference_sequence_name, start)?;
+        }
+
+        let start_position = self.inner.get_ref().virtual_position();
+        self.inner.write_record(record)?;
+        let end_position = self.inner.get_ref().virtual_position();
+
+        let chunk = Chunk::new(start_position, end_position);
+
+        // The indexer takes a 0-based, half-open interval.
+        self.indexer
+            .add_record(&reference_sequence_name, start - 1, end, chunk);
+
+        if self.previous_record.as_ref().map(|(name, _)| name.as_str())
+            != Some(reference_sequence_name.as_str())
+        {
+            self.reference_sequence_names
+                .insert(reference_sequence_name.clone());
+        }
+
+        self.previous_record = Some((reference_sequence_name, start));
+
+        Ok(())
+    }
+
+    /// Finishes the output stream and builds the index.
+    ///
+    /// This returns the underlying writer and the tabix index of the written records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut writer = vcf::IndexedWriter::new(Vec::new());
+    /// writer.write_header(&vcf::Header::default())?;
+    ///
+    /// let (_, index) = writer.finish()?;
+    /// assert!(index.reference_sequence_names().is_empty());
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn finish(self) -> io::Result<(W, tabix::Index)> {
+        let inner = self.inner.into_inner().finish()?;
+        let index = self.indexer.build();
+        Ok((inner, index))
+    }
+
+    fn check_sort_order(&self, reference_sequence_name: &str, start: i32) -> io::Result<()> {
+        match self.previous_record.as_ref() {
+            Some((previous_name, previous_start)) if previous_name == reference_sequence_name => {
+                if start < *previous_start {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!(
+                            "unsorted record: {}:{} is before {}:{}",
+                            reference_sequence_name, start, previous_name, previous_start
+                        ),
+                    ));
+                }
+            }
+            _ => {
+                if self
+                    .reference_sequence_names
+                    .contains(reference_sequence_name)
+                {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!(
+                            "unsorted record: records on {} are not contiguous",
+                            reference_sequence_name
+                        ),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{convert::TryFrom, io::Cursor};
+
+    use noodles_core::Region;
+
+    use crate::{header::Contig, record::Position, Reader};
+
+    use super::*;
+
+    fn build_record(chromosome: &str, position: i32) -> Result<Record, Box<dyn std::error::Error>> {
+        Record::builder()
+            .set_chromosome(chromosome.parse()?)
+            .set_position(Position::try_from(position)?)
+            .set_reference_bases("A".parse()?)
+            .build()
+            .map_err(|e| e.into())
+    }
+
+    #[test]
+    fn test_write_record() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_contig(Contig::new("sq0"))
+            .add_contig(Contig::new("sq1"))
+            .build();
+
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_header(&header)?;
+
+        writer.write_record(&build_record("sq0", 8)?)?;
+        writer.write_record(&build_record("sq0", 13)?)?;
+        writer.write_record(&build_record("sq0", 21)?)?;
+        writer.write_record(&build_record("sq1", 5)?)?;
+
+        let (data, index) = writer.finish()?;
+
+        let names: Vec<_> = index.reference_sequence_names().iter().collect();
+        assert_eq!(names, ["sq0", "sq1"]);
+
+        let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(data)));
+        reader.read_header()?;
+
+        let region = Region::mapped("sq0", 10..=21);
+        let positions: Vec<_> = reader
+            .query(&index, &region)?
+            .map(|result| result.map(|record| i32::from(record.position())))
+            .collect::<Result<_, _>>()?;
+        assert_eq!(positions, [13, 21]);
+
+        let region = Region::mapped("sq1", 1..=10);
+        let positions: Vec<_> = reader
+            .query(&index, &region)?
+            .map(|result| result.map(|record| i32::from(record.position())))
+            .collect::<Result<_, _>>()?;
+        assert_eq!(positions, [5]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_unsorted_records() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_record(&build_record("sq0", 13)?)?;
+        assert!(matches!(
+            writer.write_record(&build_record("sq0", 8)?),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_record(&build_record("sq0", 8)?)?;
+        writer.write_record(&build_record("sq1", 5)?)?;
+        assert!(matches!(
+            writer.write_record(&build_record("sq0", 13)?),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.set_verify_sort_order(false);
+        writer.write_record(&build_record("sq0", 13)?)?;
+        writer.write_record(&build_record("sq0", 8)?)?;
+
+        Ok(())
+    }
+}
@@ -24,11 +24,14 @@
 mod r#async;
 
 pub mod header;
+mod indexed_writer;
 mod reader;
 pub mod record;
 mod writer;
 
-pub use self::{header::Header, reader::Reader, record::Record, writer::Writer};
+pub use self::{
+    header::Header, indexed_writer::IndexedWriter, reader::Reader, record::Record, writer::Writer,
+};
 
 #[cfg(feature = "async")]
 pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
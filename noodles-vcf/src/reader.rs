@@ -13,6 +13,8 @@ use noodles_core::{region::Interval, Region};
 use noodles_csi::BinningIndex;
 use noodles_tabix as tabix;
 
+use crate::record::ParseMode;
+
 const LINE_FEED: char = '\n';
 const CARRIAGE_RETURN: char = '\r';
 
@@ -48,6 +50,7 @@ const HEADER_PREFIX: u8 = b'#';
 #[derive(Debug)]
 pub struct Reader<R> {
     inner: R,
+    parse_mode: ParseMode,
 }
 
 impl<R> Reader<R>
@@ -69,7 +72,47 @@ where
     /// let reader = vcf::Reader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            parse_mode: ParseMode::default(),
+        }
+    }
+
+    /// Returns the parse mode used to parse records.
+    ///
+    /// The default is [`ParseMode::Strict`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, record::ParseMode};
+    /// let data = [];
+    /// let reader = vcf::Reader::new(&data[..]);
+    /// assert_eq!(reader.parse_mode(), ParseMode::Strict);
+    /// ```
+    pub fn parse_mode(&self) -> ParseMode {
+        self.parse_mode
+    }
+
+    /// Sets the parse mode used to parse records.
+    ///
+    /// This applies to records parsed by the reader, e.g., using [`Self::records`]. See
+    /// [`ParseMode`] for the deviations from the specification that are tolerated in lenient
+    /// mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, record::ParseMode};
+    ///
+    /// let data = [];
+    /// let mut reader = vcf::Reader::new(&data[..]);
+    /// reader.set_parse_mode(ParseMode::Lenient);
+    ///
+    /// assert_eq!(reader.parse_mode(), ParseMode::Lenient);
+    /// ```
+    pub fn set_parse_mode(&mut self, parse_mode: ParseMode) {
+        self.parse_mode = parse_mode;
     }
 
     /// Reads the raw VCF header.
@@ -429,6 +472,32 @@ sq0\t13
         Ok(())
     }
 
+    #[test]
+    fn test_records_with_parse_mode() -> io::Result<()> {
+        let data = b"##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\ta\tc \t.\tPASS\t.
+";
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_header()?;
+
+        assert!(matches!(
+            reader.records().next(),
+            Some(Err(ref e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let mut reader = Reader::new(&data[..]);
+        reader.set_parse_mode(ParseMode::Lenient);
+        reader.read_header()?;
+
+        let records: Vec<_> = reader.records().collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].to_string(), "sq0\t1\t.\tA\tC\t.\tPASS\t.");
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_line() -> io::Result<()> {
         let mut buf = String::new();
@@ -84,8 +84,7 @@ where
         match self.reader.read_record(&mut self.line_buf) {
             Ok(0) => None,
             Ok(_) => Some(
-                self.line_buf
-                    .parse()
+                Record::from_str_mode(&self.line_buf, self.reader.parse_mode())
                     .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
             ),
             Err(e) => Some(Err(e)),
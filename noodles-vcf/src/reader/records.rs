@@ -36,8 +36,7 @@ where
         match self.inner.read_record(&mut self.line_buf) {
             Ok(0) => None,
             Ok(_) => Some(
-                self.line_buf
-                    .parse()
+                Record::from_str_mode(&self.line_buf, self.inner.parse_mode())
                     .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
             ),
             Err(e) => Some(Err(e)),
@@ -9,6 +9,7 @@ pub mod format;
 pub mod genotype;
 pub mod ids;
 pub mod info;
+mod parse_mode;
 pub mod position;
 pub mod quality_score;
 pub mod reference_bases;
@@ -16,12 +17,15 @@ pub(crate) mod value;
 
 pub use self::{
     alternate_bases::AlternateBases, builder::Builder, chromosome::Chromosome, field::Field,
-    filters::Filters, format::Format, genotype::Genotype, ids::Ids, info::Info, position::Position,
-    quality_score::QualityScore, reference_bases::ReferenceBases,
+    filters::Filters, format::Format, genotype::Genotype, ids::Ids, info::Info,
+    parse_mode::ParseMode, position::Position, quality_score::QualityScore,
+    reference_bases::ReferenceBases,
 };
 
 use std::{convert::TryFrom, error, fmt, num, str::FromStr};
 
+use self::alternate_bases::allele::symbol::structural_variant;
+
 pub(crate) const MISSING_FIELD: &str = ".";
 pub(crate) const FIELD_DELIMITER: char = '\t';
 
@@ -273,6 +277,165 @@ impl Record {
         &self.info
     }
 
+    /// Returns the info field with the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     record::{info::{field::{Key, Value}, Field}, Position},
+    /// };
+    ///
+    /// let record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::try_from(1)?)
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_info("NS=3".parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(
+    ///     record.info_field(&Key::SamplesWithDataCount),
+    ///     Some(&Field::new(Key::SamplesWithDataCount, Value::Integer(3)))
+    /// );
+    /// assert!(record.info_field(&Key::AlleleFrequencies).is_none());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn info_field(&self, key: &info::field::Key) -> Option<&info::Field> {
+        self.info.get(key)
+    }
+
+    /// Adds an info field.
+    ///
+    /// New fields are appended to the end of the info fields. If a field with the same key already
+    /// exists, it is replaced in place, i.e., it keeps its position, and the previous field is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     record::{info::{field::{Key, Value}, Field}, Position},
+    /// };
+    ///
+    /// let mut record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::try_from(1)?)
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_alternate_bases("C".parse()?)
+    ///     .set_info("NS=3".parse()?)
+    ///     .build()?;
+    ///
+    /// record.add_info_field(Field::new(Key::AlleleFrequencies, Value::FloatArray(vec![0.5])));
+    /// assert_eq!(record.info().to_string(), "NS=3;AF=0.5");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn add_info_field(&mut self, field: info::Field) -> Option<info::Field> {
+        self.info.insert(field)
+    }
+
+    /// Removes the info field with the given key.
+    ///
+    /// The order of the remaining fields is preserved. This returns the removed field, if it
+    /// existed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     record::{info::field::Key, Position},
+    /// };
+    ///
+    /// let mut record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::try_from(1)?)
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_info("NS=3;DP=13;AA=G".parse()?)
+    ///     .build()?;
+    ///
+    /// assert!(record.remove_info_field(&Key::TotalDepth).is_some());
+    /// assert_eq!(record.info().to_string(), "NS=3;AA=G");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn remove_info_field(&mut self, key: &info::field::Key) -> Option<info::Field> {
+        self.info.remove(key)
+    }
+
+    /// Returns the value of an info field for the given alternate allele.
+    ///
+    /// `allele_index` is the 0-based index of the alternate allele in [`Self::alternate_bases`].
+    /// The number of values of the field is taken from its definition in the header, falling back
+    /// to the number of the key if the field is not defined in the header. For fields with one
+    /// value per alternate allele (`Number=A`), this is the value at `allele_index`; for fields
+    /// with one value per allele (`Number=R`), the first value is of the reference allele, so this
+    /// is the value at `allele_index + 1`.
+    ///
+    /// This returns `None` if the field is missing, its number is not `A` or `R`, or the allele
+    /// index is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     record::{info::field::{Key, Value}, Position},
+    /// };
+    ///
+    /// let header = vcf::Header::default();
+    ///
+    /// let record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::try_from(1)?)
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_alternate_bases("C,G".parse()?)
+    ///     .set_info("AF=0.25,0.5".parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(
+    ///     record.info_for_allele(&Key::AlleleFrequencies, 1, &header),
+    ///     Some(Value::Float(0.5))
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn info_for_allele(
+        &self,
+        key: &info::field::Key,
+        allele_index: usize,
+        header: &crate::Header,
+    ) -> Option<info::field::Value> {
+        use crate::header::Number;
+        use info::field::Value;
+
+        if allele_index >= self.alternate_bases().len() {
+            return None;
+        }
+
+        let number = header
+            .info(key.as_ref())
+            .map(|info| info.number())
+            .unwrap_or_else(|| key.number());
+
+        let i = match number {
+            Number::A => allele_index,
+            Number::R => allele_index + 1,
+            _ => return None,
+        };
+
+        match self.info().get(key)?.value() {
+            Value::IntegerArray(values) => values.get(i).copied().map(Value::Integer),
+            Value::FloatArray(values) => values.get(i).copied().map(Value::Float),
+            Value::CharacterArray(values) => values.get(i).copied().map(Value::Character),
+            Value::StringArray(values) => values.get(i).cloned().map(Value::String),
+            _ => None,
+        }
+    }
+
     /// Returns the format of the genotypes of the record.
     ///
     /// # Examples
@@ -372,7 +535,137 @@ impl fmt::Display for EndError {
     }
 }
 
+/// An error returned when a structural variant record is inconsistent.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SvError {
+    /// The INFO end position (`END`) field is missing for a symbolic alternate allele.
+    MissingEnd,
+    /// The INFO end position (`END`) field value type is invalid.
+    InvalidInfoEndPositionFieldValue,
+    /// The end position is before the start position.
+    EndBeforeStart(i32, i32),
+    /// The INFO SV lengths (`SVLEN`) field value type is invalid.
+    InvalidInfoSvLengthsFieldValue,
+    /// The sign of an SV length does not match the structural variant type.
+    SvLengthSignMismatch(structural_variant::Type, i32),
+}
+
+impl error::Error for SvError {}
+
+impl fmt::Display for SvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingEnd => f.write_str("missing INFO end position (`END`) field"),
+            Self::InvalidInfoEndPositionFieldValue => {
+                f.write_str("invalid INFO end position (`END`) field value type")
+            }
+            Self::EndBeforeStart(end, start) => write!(
+                f,
+                "end position ({}) is before the start position ({})",
+                end, start
+            ),
+            Self::InvalidInfoSvLengthsFieldValue => {
+                f.write_str("invalid INFO SV lengths (`SVLEN`) field value type")
+            }
+            Self::SvLengthSignMismatch(ty, len) => {
+                write!(f, "SV length sign mismatch for {}: {}", ty, len)
+            }
+        }
+    }
+}
+
 impl Record {
+    /// Validates the consistency of the structural variant fields of this record.
+    ///
+    /// This checks that
+    ///
+    ///   * records with a structural variant alternate allele (e.g., `<DEL>`) have an end position
+    ///     (`END`). Other symbolic alleles, e.g., `<NON_REF>` or `<*>`, do not require one,
+    ///   * the end position, if present, is not before the start position (`POS`), and
+    ///   * the SV lengths (`SVLEN`), if present, are negative for deletions (`<DEL>`) and positive
+    ///     for insertions (`<INS>`) and duplications (`<DUP>`). Each SV length is matched to the
+    ///     alternate allele at the same index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// use noodles_vcf::{self as vcf, record::{Position, SvError}};
+    ///
+    /// let record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::try_from(8)?)
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_alternate_bases("<DEL>".parse()?)
+    ///     .set_info("SVTYPE=DEL;END=13;SVLEN=-5".parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.validate_sv(), Ok(()));
+    ///
+    /// let record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::try_from(8)?)
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_alternate_bases("<DEL>".parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.validate_sv(), Err(SvError::MissingEnd));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn validate_sv(&self) -> Result<(), SvError> {
+        use alternate_bases::{allele::Symbol, Allele};
+        use info::field::{Key, Value};
+
+        let start = i32::from(self.position());
+
+        match self
+            .info()
+            .get(&Key::EndPosition)
+            .map(|field| field.value())
+        {
+            Some(Value::Integer(end)) => {
+                if *end < start {
+                    return Err(SvError::EndBeforeStart(*end, start));
+                }
+            }
+            Some(_) => return Err(SvError::InvalidInfoEndPositionFieldValue),
+            None => {
+                if self
+                    .alternate_bases()
+                    .iter()
+                    .any(|allele| matches!(allele, Allele::Symbol(Symbol::StructuralVariant(_))))
+                {
+                    return Err(SvError::MissingEnd);
+                }
+            }
+        }
+
+        let sv_lengths = match self.info().get(&Key::SvLengths).map(|field| field.value()) {
+            Some(Value::Integer(n)) => vec![*n],
+            Some(Value::IntegerArray(values)) => values.clone(),
+            Some(_) => return Err(SvError::InvalidInfoSvLengthsFieldValue),
+            None => return Ok(()),
+        };
+
+        for (allele, &len) in self.alternate_bases().iter().zip(sv_lengths.iter()) {
+            if let Allele::Symbol(Symbol::StructuralVariant(sv)) = allele {
+                let is_valid = match sv.ty() {
+                    structural_variant::Type::Deletion => len < 0,
+                    structural_variant::Type::Insertion | structural_variant::Type::Duplication => {
+                        len > 0
+                    }
+                    _ => true,
+                };
+
+                if !is_valid {
+                    return Err(SvError::SvLengthSignMismatch(sv.ty(), len));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Returns or calculates the end position on the reference sequence.
     ///
     /// If available, this returns the value of the `END` INFO field. Otherwise, it is calculated
@@ -454,8 +747,9 @@ impl fmt::Display for Record {
         if let Some(format) = self.format() {
             write!(f, "\t{}", format)?;
 
-            for field in self.genotypes() {
-                write!(f, "\t{}", field)?;
+            for genotype in self.genotypes() {
+                f.write_str("\t")?;
+                genotype.fmt_with_format(f, format)?;
             }
         }
 
@@ -510,6 +804,70 @@ impl fmt::Display for ParseError {
     }
 }
 
+impl Record {
+    /// Parses a raw VCF record using the given parse mode.
+    ///
+    /// Parsing in [`ParseMode::Strict`] is the same as using [`FromStr`]. See
+    /// [`ParseMode::Lenient`] for the deviations from the specification that are tolerated in
+    /// lenient mode.
+    ///
+    /// The record is parsed without a header, so only flags with standard keys set to 0 (e.g.,
+    /// `SOMATIC=0`) are treated as unset. Use [`Info::from_str_header`] to handle nonstandard flags
+    /// declared in the header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, record::ParseMode};
+    ///
+    /// let s = "sq0\t1\t.\ta \tc\t.\tPASS\t.";
+    ///
+    /// assert!(vcf::Record::from_str_mode(s, ParseMode::Strict).is_err());
+    ///
+    /// let record = vcf::Record::from_str_mode(s, ParseMode::Lenient)?;
+    /// assert_eq!(record.to_string(), "sq0\t1\t.\tA\tC\t.\tPASS\t.");
+    /// # Ok::<(), vcf::record::ParseError>(())
+    /// ```
+    pub fn from_str_mode(s: &str, mode: ParseMode) -> Result<Self, ParseError> {
+        match mode {
+            ParseMode::Strict => s.parse(),
+            ParseMode::Lenient => normalize(s).parse(),
+        }
+    }
+}
+
+// Corrects the deviations from the specification that are tolerated by `ParseMode::Lenient`.
+fn normalize(s: &str) -> String {
+    let mut fields: Vec<String> = s
+        .split(FIELD_DELIMITER)
+        .map(|field| field.trim().into())
+        .collect();
+
+    while fields.last().map(|field| field.is_empty()).unwrap_or(false) {
+        fields.pop();
+    }
+
+    if let Some(reference_bases) = fields.get_mut(Field::ReferenceBases as usize) {
+        reference_bases.make_ascii_uppercase();
+    }
+
+    if let Some(alternate_bases) = fields.get_mut(Field::AlternateBases as usize) {
+        *alternate_bases = alternate_bases
+            .split(',')
+            .map(|allele| {
+                if allele.chars().all(|c| c.is_ascii_alphabetic()) {
+                    allele.to_ascii_uppercase()
+                } else {
+                    allele.into()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+    }
+
+    fields.join(&FIELD_DELIMITER.to_string())
+}
+
 impl FromStr for Record {
     type Err = ParseError;
 
@@ -611,6 +969,175 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_add_and_remove_info_fields() -> Result<(), Box<dyn std::error::Error>> {
+        use info::field::{Key, Value};
+
+        let mut record: Record = "sq0\t1\t.\tA\tC\t.\tPASS\tNS=3;DP=13;AA=G".parse()?;
+
+        let field = info::Field::new(Key::AlleleFrequencies, Value::FloatArray(vec![0.5]));
+        assert!(record.add_info_field(field.clone()).is_none());
+        assert_eq!(record.info_field(&Key::AlleleFrequencies), Some(&field));
+
+        let removed = record.remove_info_field(&Key::TotalDepth);
+        assert_eq!(
+            removed,
+            Some(info::Field::new(Key::TotalDepth, Value::Integer(13)))
+        );
+        assert!(record.info_field(&Key::TotalDepth).is_none());
+        assert!(record.remove_info_field(&Key::TotalDepth).is_none());
+
+        let previous = record.add_info_field(info::Field::new(
+            Key::SamplesWithDataCount,
+            Value::Integer(5),
+        ));
+        assert_eq!(
+            previous,
+            Some(info::Field::new(
+                Key::SamplesWithDataCount,
+                Value::Integer(3)
+            ))
+        );
+
+        assert_eq!(
+            record.to_string(),
+            "sq0\t1\t.\tA\tC\t.\tPASS\tNS=5;AA=G;AF=0.5"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str_mode() -> Result<(), ParseError> {
+        let s = "sq0\t1\t.\ta\t<DEL>,g \t.\t PASS\t.\tGT:GQ\t0|1\t";
+
+        assert!(Record::from_str_mode(s, ParseMode::Strict).is_err());
+
+        let record = Record::from_str_mode(s, ParseMode::Lenient)?;
+        assert_eq!(
+            record.to_string(),
+            "sq0\t1\t.\tA\t<DEL>,G\t.\tPASS\t.\tGT:GQ\t0|1"
+        );
+
+        let s = "sq0\t1\t.\tA\t.\t.\tPASS\t.";
+        assert_eq!(
+            Record::from_str_mode(s, ParseMode::Lenient),
+            Record::from_str_mode(s, ParseMode::Strict)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_sv() -> Result<(), Box<dyn std::error::Error>> {
+        fn build(info: &str) -> Result<Record, Box<dyn std::error::Error>> {
+            Record::builder()
+                .set_chromosome("sq0".parse()?)
+                .set_position(Position::try_from(8)?)
+                .set_reference_bases("A".parse()?)
+                .set_alternate_bases("<DEL>".parse()?)
+                .set_info(info.parse()?)
+                .build()
+                .map_err(|e| e.into())
+        }
+
+        let record = build("SVTYPE=DEL;END=13;SVLEN=-5")?;
+        assert_eq!(record.validate_sv(), Ok(()));
+
+        let record = build("SVTYPE=DEL;END=5;SVLEN=-5")?;
+        assert_eq!(record.validate_sv(), Err(SvError::EndBeforeStart(5, 8)));
+
+        let record = build("SVTYPE=DEL;SVLEN=-5")?;
+        assert_eq!(record.validate_sv(), Err(SvError::MissingEnd));
+
+        let record = build("SVTYPE=DEL;END=13;SVLEN=5")?;
+        assert_eq!(
+            record.validate_sv(),
+            Err(SvError::SvLengthSignMismatch(
+                structural_variant::Type::Deletion,
+                5
+            ))
+        );
+
+        for alternate_bases in &["<NON_REF>", "<*>", "C,<NON_REF>"] {
+            let record = Record::builder()
+                .set_chromosome("sq0".parse()?)
+                .set_position(Position::try_from(8)?)
+                .set_reference_bases("A".parse()?)
+                .set_alternate_bases(alternate_bases.parse()?)
+                .build()?;
+
+            assert_eq!(record.validate_sv(), Ok(()));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_info_for_allele() -> Result<(), Box<dyn std::error::Error>> {
+        use info::field::{Key, Value};
+
+        let header: crate::Header = [
+            "##fileformat=VCFv4.3",
+            r#"##INFO=<ID=AF,Number=A,Type=Float,Description="Allele frequency">"#,
+            r#"##INFO=<ID=AD,Number=R,Type=Integer,Description="Total read depth for each allele">"#,
+            "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO",
+            "",
+        ]
+        .join("\n")
+        .parse()?;
+
+        let record: Record = "sq0\t1\t.\tA\tC,G\t.\tPASS\tNS=3;AF=0.25,0.5;AD=8,5,3".parse()?;
+
+        let af = Key::AlleleFrequencies;
+        assert_eq!(
+            record.info_for_allele(&af, 0, &header),
+            Some(Value::Float(0.25))
+        );
+        assert_eq!(
+            record.info_for_allele(&af, 1, &header),
+            Some(Value::Float(0.5))
+        );
+        assert!(record.info_for_allele(&af, 2, &header).is_none());
+
+        let ad = Key::TotalReadDepths;
+        assert_eq!(
+            record.info_for_allele(&ad, 0, &header),
+            Some(Value::Integer(5))
+        );
+        assert_eq!(
+            record.info_for_allele(&ad, 1, &header),
+            Some(Value::Integer(3))
+        );
+        assert!(record.info_for_allele(&ad, 2, &header).is_none());
+
+        assert!(record
+            .info_for_allele(&Key::SamplesWithDataCount, 0, &header)
+            .is_none());
+        assert!(record
+            .info_for_allele(&Key::BaseQuality, 0, &header)
+            .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str_with_telomeric_breakend() -> Result<(), ParseError> {
+        use alternate_bases::Allele;
+
+        let s = "sq0\t0\tbnd0\tN\t.[sq1:13[\t.\tPASS\tSVTYPE=BND";
+        let record: Record = s.parse()?;
+
+        assert_eq!(i32::from(record.position()), 0);
+
+        let alternate_bases = [Allele::Breakend(String::from(".[sq1:13["))];
+        assert_eq!(&record.alternate_bases()[..], &alternate_bases[..]);
+
+        assert_eq!(record.to_string(), s);
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() -> Result<(), Box<dyn std::error::Error>> {
         let record = Record::builder()
@@ -675,6 +1202,23 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_from_str_with_ids() -> Result<(), ParseError> {
+        fn t(s: &str, expected: &[&str]) -> Result<(), ParseError> {
+            let record: Record = s.parse()?;
+            let actual: Vec<_> = record.ids().iter().map(|id| id.as_str()).collect();
+            assert_eq!(actual, expected);
+            assert_eq!(record.to_string(), s);
+            Ok(())
+        }
+
+        t("sq0\t1\t.\tA\t.\t.\t.\t.", &[])?;
+        t("sq0\t1\trs123\tA\t.\t.\t.\t.", &["rs123"])?;
+        t("sq0\t1\trs1;rs2\tA\t.\t.\t.\t.", &["rs1", "rs2"])?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_from_str_with_genotype_info() -> Result<(), Box<dyn std::error::Error>> {
         let s = "chr1\t13\tnd0\tATCG\tA\t5.8\tPASS\tSVTYPE=DEL\tGT:GQ\t0|1:13";
@@ -195,6 +195,8 @@ mod tests {
             Ok(Allele::Breakend(String::from(".GC")))
         );
 
+        assert_eq!("R".parse::<Allele>(), Ok(Allele::Bases(vec![Base::R])));
+
         assert_eq!("".parse::<Allele>(), Err(ParseError::Empty));
         assert!(matches!(
             "<>".parse::<Allele>(),
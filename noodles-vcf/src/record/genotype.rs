@@ -25,6 +25,8 @@ pub enum ParseError {
     Invalid(TryFromFieldsError),
     /// A field is invalid.
     InvalidField(field::ParseError),
+    /// There are more fields than keys in the format.
+    InvalidFieldCount,
 }
 
 impl error::Error for ParseError {}
@@ -35,6 +37,7 @@ impl fmt::Display for ParseError {
             Self::Empty => f.write_str("empty input"),
             Self::Invalid(e) => write!(f, "invalid input: {}", e),
             Self::InvalidField(e) => write!(f, "invalid field: {}", e),
+            Self::InvalidFieldCount => f.write_str("invalid field count"),
         }
     }
 }
@@ -64,6 +67,10 @@ impl Genotype {
             "" => Err(ParseError::Empty),
             MISSING_FIELD => Ok(Self::default()),
             _ => {
+                if s.split(DELIMITER).count() > format.len() {
+                    return Err(ParseError::InvalidFieldCount);
+                }
+
                 let fields = s
                     .split(DELIMITER)
                     .zip(format.iter())
@@ -75,6 +82,45 @@ impl Genotype {
             }
         }
     }
+
+    // Returns whether all the keys of the genotype are in the given format.
+    pub(crate) fn is_compatible(&self, format: &Format) -> bool {
+        self.keys().all(|key| format.contains(key))
+    }
+
+    // Writes the genotype with its fields ordered by the given format.
+    //
+    // Keys missing from the genotype are written as missing values, except for trailing keys,
+    // which are dropped (§ 1.6.2 Genotype fields (2021-01-13)). Keys missing from the format are
+    // not written.
+    pub(crate) fn fmt_with_format(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        format: &Format,
+    ) -> fmt::Result {
+        let len = format
+            .iter()
+            .rposition(|key| self.contains_key(key))
+            .map(|i| i + 1)
+            .unwrap_or(0);
+
+        if len == 0 {
+            return f.write_str(MISSING_FIELD);
+        }
+
+        for (i, key) in format.iter().take(len).enumerate() {
+            if i > 0 {
+                write!(f, "{}", DELIMITER)?;
+            }
+
+            match self.get(key) {
+                Some(field) => write!(f, "{}", field)?,
+                None => f.write_str(field::MISSING_VALUE)?,
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl Deref for Genotype {
@@ -179,6 +225,12 @@ mod tests {
             Err(ParseError::Empty)
         );
 
+        let format = "GT".parse()?;
+        assert_eq!(
+            Genotype::from_str_format("0|0:13", &format),
+            Err(ParseError::InvalidFieldCount)
+        );
+
         Ok(())
     }
 
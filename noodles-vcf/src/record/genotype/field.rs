@@ -7,7 +7,7 @@ pub use self::{key::Key, value::Value};
 
 use std::{error, fmt};
 
-const MISSING_VALUE: &str = ".";
+pub(crate) const MISSING_VALUE: &str = ".";
 
 /// A VCF record genotype field.
 #[derive(Clone, Debug, PartialEq)]
@@ -8,6 +8,8 @@ use std::{convert::TryFrom, error, fmt, ops::Deref, str::FromStr};
 
 use indexmap::IndexMap;
 
+use crate::Header;
+
 use super::MISSING_FIELD;
 
 const DELIMITER: char = ';';
@@ -24,6 +26,50 @@ impl Deref for Info {
     }
 }
 
+impl Info {
+    /// Parses raw VCF record info fields using the definitions in the given header.
+    ///
+    /// See [`Field::from_str_header`]. A flag set to 0 (e.g., `SOMATIC=0`) is treated as unset and
+    /// dropped. Using [`FromStr`] only recognizes standard keys as flags, so a nonstandard flag
+    /// declared in the header is only treated as unset when parsed with this method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::{info::Type, Info, Number},
+    ///     record::info::field::{Key, Value},
+    /// };
+    ///
+    /// let key = Key::Other(String::from("NDLS"), Number::Unknown, Type::Integer, String::default());
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_info(Info::new(key.clone(), Number::Unknown, Type::Integer, String::default()))
+    ///     .build();
+    ///
+    /// let info = vcf::record::Info::from_str_header("NS=2;NDLS=8,13", &header)?;
+    /// assert_eq!(
+    ///     info.get(&key).map(|field| field.value()),
+    ///     Some(&Value::IntegerArray(vec![8, 13]))
+    /// );
+    /// # Ok::<(), vcf::record::info::ParseError>(())
+    /// ```
+    pub fn from_str_header(s: &str, header: &Header) -> Result<Self, ParseError> {
+        parse(s, Some(header))
+    }
+
+    // Inserts a field, replacing the field with the same key in place, if any.
+    pub(crate) fn insert(&mut self, field: Field) -> Option<Field> {
+        self.0.insert(field.key().clone(), field)
+    }
+
+    // Removes a field while preserving the order of the remaining fields.
+    pub(crate) fn remove(&mut self, key: &field::Key) -> Option<Field> {
+        self.0.shift_remove(key)
+    }
+}
+
 impl fmt::Display for Info {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if self.is_empty() {
@@ -69,18 +115,26 @@ impl FromStr for Info {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "" => Err(ParseError::Empty),
-            MISSING_FIELD => Ok(Self::default()),
-            _ => {
-                let fields = s
-                    .split(DELIMITER)
-                    .map(|s| s.parse())
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(ParseError::InvalidField)?;
-
-                Self::try_from(fields).map_err(ParseError::Invalid)
-            }
+        parse(s, None)
+    }
+}
+
+fn parse(s: &str, header: Option<&Header>) -> Result<Info, ParseError> {
+    match s {
+        "" => Err(ParseError::Empty),
+        MISSING_FIELD => Ok(Info::default()),
+        _ => {
+            let fields = s
+                .split(DELIMITER)
+                .filter(|s| !field::is_unset_flag(s, header))
+                .map(|s| match header {
+                    Some(header) => Field::from_str_header(s, header),
+                    None => s.parse(),
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(ParseError::InvalidField)?;
+
+            Info::try_from(fields).map_err(ParseError::Invalid)
         }
     }
 }
@@ -164,7 +218,58 @@ mod tests {
         let actual: Info = "NS=2;AF=0.333,0.667".parse()?;
         assert_eq!(actual.len(), 2);
 
+        let actual: Info = "SOMATIC".parse()?;
+        assert!(actual.get(&field::Key::IsSomaticMutation).is_some());
+        assert_eq!(actual.to_string(), "SOMATIC");
+
+        let actual: Info = "SOMATIC=1".parse()?;
+        assert!(actual.get(&field::Key::IsSomaticMutation).is_some());
+        assert_eq!(actual.to_string(), "SOMATIC");
+
+        let actual: Info = "NS=2;SOMATIC=0".parse()?;
+        assert!(actual.get(&field::Key::IsSomaticMutation).is_none());
+        assert_eq!(actual.to_string(), "NS=2");
+
+        let actual: Info = "NS=2;DB;AF=0.333,0.667;H2".parse()?;
+        let keys: Vec<_> = actual.keys().cloned().collect();
+        assert_eq!(
+            keys,
+            [
+                field::Key::SamplesWithDataCount,
+                field::Key::IsInDbSnp,
+                field::Key::AlleleFrequencies,
+                field::Key::IsInHapMap2,
+            ]
+        );
+        assert_eq!(
+            actual
+                .get(&field::Key::IsInDbSnp)
+                .map(|field| field.value()),
+            Some(&field::Value::Flag)
+        );
+        assert_eq!(actual.to_string(), "NS=2;DB;AF=0.333,0.667;H2");
+
         assert_eq!("".parse::<Info>(), Err(ParseError::Empty));
+
+        let result = "NS=2;AF=0.333;NS=3".parse::<Info>();
+        assert_eq!(
+            result,
+            Err(ParseError::Invalid(TryFromFieldsError::DuplicateKey(
+                field::Key::SamplesWithDataCount
+            )))
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "invalid input: duplicate key: NS"
+        );
+
+        assert_eq!(
+            "DB;DB=1".parse::<Info>(),
+            Err(ParseError::Invalid(TryFromFieldsError::DuplicateKey(
+                field::Key::IsInDbSnp
+            )))
+        );
+
         assert!(matches!(
             "NS=ndls".parse::<Info>(),
             Err(ParseError::InvalidField(_))
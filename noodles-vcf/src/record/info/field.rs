@@ -7,7 +7,7 @@ pub use self::{key::Key, value::Value};
 
 use std::{error, fmt, str::FromStr};
 
-use crate::header::info::Type;
+use crate::{header::info::Type, Header};
 
 const SEPARATOR: char = '=';
 const MAX_COMPONENTS: usize = 2;
@@ -57,6 +57,36 @@ impl Field {
     pub fn value(&self) -> &Value {
         &self.value
     }
+
+    /// Parses a raw VCF record info field using the definitions in the given header.
+    ///
+    /// The number and type of a nonstandard field (i.e., [`Key::Other`]) are taken from its
+    /// information record in the header, if it is defined. Otherwise, this is the same as using
+    /// [`FromStr`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::{info::Type, Info, Number},
+    ///     record::info::{field::{Key, Value}, Field},
+    /// };
+    ///
+    /// let key = Key::Other(String::from("NDLS"), Number::Unknown, Type::Integer, String::default());
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_info(Info::new(key.clone(), Number::Unknown, Type::Integer, String::default()))
+    ///     .build();
+    ///
+    /// let field = Field::from_str_header("NDLS=8,13", &header)?;
+    /// assert_eq!(field.key(), &key);
+    /// assert_eq!(field.value(), &Value::IntegerArray(vec![8, 13]));
+    /// # Ok::<(), vcf::record::info::field::ParseError>(())
+    /// ```
+    pub fn from_str_header(s: &str, header: &Header) -> Result<Self, ParseError> {
+        parse(s, Some(header))
+    }
 }
 
 impl fmt::Display for Field {
@@ -98,30 +128,65 @@ impl FromStr for Field {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut components = s.splitn(MAX_COMPONENTS, SEPARATOR);
+        parse(s, None)
+    }
+}
 
-        let key: Key = components
-            .next()
-            .ok_or(ParseError::MissingKey)
-            .and_then(|s| s.parse().map_err(ParseError::InvalidKey))?;
+fn parse(s: &str, header: Option<&Header>) -> Result<Field, ParseError> {
+    let mut components = s.splitn(MAX_COMPONENTS, SEPARATOR);
+
+    let key = components
+        .next()
+        .ok_or(ParseError::MissingKey)
+        .and_then(|s| parse_key(s, header).map_err(ParseError::InvalidKey))?;
 
-        let value = if let Type::Flag = key.ty() {
-            let t = components.next().unwrap_or_default();
+    let value = if let Type::Flag = key.ty() {
+        let t = components.next().unwrap_or_default();
+        Value::from_str_key(t, &key).map_err(ParseError::InvalidValue)?
+    } else if let Key::Other(..) = key {
+        if let Some(t) = components.next() {
             Value::from_str_key(t, &key).map_err(ParseError::InvalidValue)?
-        } else if let Key::Other(..) = key {
-            if let Some(t) = components.next() {
-                Value::from_str_key(t, &key).map_err(ParseError::InvalidValue)?
-            } else {
-                Value::Flag
-            }
         } else {
-            components
-                .next()
-                .ok_or(ParseError::MissingValue)
-                .and_then(|t| Value::from_str_key(t, &key).map_err(ParseError::InvalidValue))?
-        };
+            Value::Flag
+        }
+    } else {
+        components
+            .next()
+            .ok_or(ParseError::MissingValue)
+            .and_then(|t| Value::from_str_key(t, &key).map_err(ParseError::InvalidValue))?
+    };
+
+    Ok(Field::new(key, value))
+}
+
+// Parses a key, resolving the number, type, and description of a nonstandard key from its
+// definition in the header, if any.
+fn parse_key(s: &str, header: Option<&Header>) -> Result<Key, key::ParseError> {
+    match (s.parse()?, header) {
+        (Key::Other(id, number, ty, description), Some(header)) => match header.info(&id) {
+            Some(info) => Ok(Key::Other(
+                id,
+                info.number(),
+                info.ty(),
+                info.description().into(),
+            )),
+            None => Ok(Key::Other(id, number, ty, description)),
+        },
+        (key, _) => Ok(key),
+    }
+}
 
-        Ok(Self::new(key, value))
+// Returns whether the raw field is a flag with a value of 0.
+//
+// An unset flag is the same as the field being absent. Without a header, only standard keys are
+// known to be flags, i.e., a nonstandard flag (`Key::Other`) is only recognized as unset when its
+// definition is given by the header (see `Info::from_str_header`).
+pub(crate) fn is_unset_flag(s: &str, header: Option<&Header>) -> bool {
+    match s.split_once(SEPARATOR) {
+        Some((k, "0")) => parse_key(k, header)
+            .map(|key| matches!(key.ty(), Type::Flag))
+            .unwrap_or(false),
+        _ => false,
     }
 }
 
@@ -168,6 +233,15 @@ mod tests {
         assert_eq!(actual.key(), &Key::IsSomaticMutation);
         assert_eq!(actual.value(), &Value::Flag);
 
+        let actual: Field = "SOMATIC=1".parse()?;
+        assert_eq!(actual.key(), &Key::IsSomaticMutation);
+        assert_eq!(actual.value(), &Value::Flag);
+
+        assert!(matches!(
+            "SOMATIC=0".parse::<Field>(),
+            Err(ParseError::InvalidValue(value::ParseError::InvalidFlag))
+        ));
+
         let actual: Field = "EVENT=INV0".parse()?;
         assert_eq!(actual.key(), &Key::BreakendEventId);
         assert_eq!(actual.value(), &Value::String(String::from("INV0")));
@@ -198,4 +272,60 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_is_unset_flag() {
+        assert!(is_unset_flag("SOMATIC=0", None));
+        assert!(!is_unset_flag("SOMATIC", None));
+        assert!(!is_unset_flag("SOMATIC=1", None));
+        assert!(!is_unset_flag("NS=0", None));
+        assert!(!is_unset_flag("NDLS=0", None));
+    }
+
+    #[test]
+    fn test_from_str_header() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::header::Info;
+
+        let header: Header = [
+            "##fileformat=VCFv4.3",
+            r#"##INFO=<ID=SVTYPE,Number=1,Type=String,Description="Type of structural variant">"#,
+            r#"##INFO=<ID=NDLS,Number=.,Type=Integer,Description="noodles">"#,
+            r#"##INFO=<ID=IS_NDLS,Number=0,Type=Flag,Description="Is noodles">"#,
+            "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO",
+            "",
+        ]
+        .join("\n")
+        .parse()?;
+
+        let actual = Field::from_str_header("SVTYPE=DEL", &header)?;
+        let info = header.info("SVTYPE").map(Info::id);
+        assert_eq!(Some(actual.key()), info);
+        assert_eq!(actual.key().number(), Number::Count(1));
+        assert_eq!(actual.key().ty(), Type::String);
+        assert_eq!(actual.value(), &Value::String(String::from("DEL")));
+
+        let actual = Field::from_str_header("NDLS=8,13", &header)?;
+        assert_eq!(
+            actual.key(),
+            &Key::Other(
+                String::from("NDLS"),
+                Number::Unknown,
+                Type::Integer,
+                String::from("noodles")
+            )
+        );
+        assert_eq!(actual.value(), &Value::IntegerArray(vec![8, 13]));
+
+        let actual = Field::from_str_header("IS_NDLS", &header)?;
+        assert_eq!(actual.key().ty(), Type::Flag);
+        assert_eq!(actual.value(), &Value::Flag);
+
+        assert!(is_unset_flag("IS_NDLS=0", Some(&header)));
+        assert!(!is_unset_flag("IS_NDLS=0", None));
+
+        let actual = Field::from_str_header("VCF=ndls", &header)?;
+        assert_eq!(actual, "VCF=ndls".parse()?);
+
+        Ok(())
+    }
 }
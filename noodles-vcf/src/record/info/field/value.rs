@@ -19,6 +19,10 @@ pub enum Value {
     /// A single-precision floating-point.
     Float(f32),
     /// A boolean.
+    ///
+    /// A flag is set when it is present and unset when it is absent. It is written without a
+    /// value, e.g., `SOMATIC`. When parsing, `SOMATIC=1` is also accepted as a set flag, and
+    /// `SOMATIC=0` is treated as an unset flag, i.e., the field is omitted from the record info.
     Flag,
     /// A character.
     Character(char),
@@ -190,10 +194,9 @@ fn parse_f32_array(s: &str) -> Result<Value, ParseError> {
 }
 
 fn parse_flag(s: &str) -> Result<Value, ParseError> {
-    if s.is_empty() {
-        Ok(Value::Flag)
-    } else {
-        Err(ParseError::InvalidFlag)
+    match s {
+        "" | "1" => Ok(Value::Flag),
+        _ => Err(ParseError::InvalidFlag),
     }
 }
 
@@ -363,6 +366,8 @@ mod tests {
             String::default(),
         );
         assert_eq!(Value::from_str_key("", &key), Ok(Value::Flag));
+        assert_eq!(Value::from_str_key("1", &key), Ok(Value::Flag));
+        assert_eq!(Value::from_str_key("0", &key), Err(ParseError::InvalidFlag));
 
         let key = Key::Other(
             String::from("BOOL"),
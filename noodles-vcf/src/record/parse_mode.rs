@@ -0,0 +1,26 @@
+/// A VCF record parse mode.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseMode {
+    /// Records must conform to the VCF specification.
+    Strict,
+    /// Minor deviations from the VCF specification are corrected before parsing.
+    ///
+    /// The following deviations are tolerated:
+    ///
+    ///   * leading and trailing whitespace in fields, which is removed, e.g., `A ` is parsed as
+    ///     `A`;
+    ///   * lowercase bases in the reference bases (`REF`) and alternate bases (`ALT`), which are
+    ///     uppercased, e.g., `acgt` is parsed as `ACGT`. Alternate alleles that are not bases,
+    ///     e.g., symbolic alleles (`<DEL>`) and breakends, are not changed; and
+    ///   * trailing empty fields, e.g., from a trailing tab, which are removed.
+    ///
+    /// Missing trailing genotype fields are allowed by the specification and are accepted in
+    /// either mode.
+    Lenient,
+}
+
+impl Default for ParseMode {
+    fn default() -> Self {
+        Self::Strict
+    }
+}
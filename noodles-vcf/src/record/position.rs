@@ -5,6 +5,10 @@ use std::{convert::TryFrom, error, fmt, num, str::FromStr};
 const MIN: i32 = 0;
 
 /// A VCF record position.
+///
+/// A position is 1-based. The exception is a position of 0, which is permitted to represent a
+/// telomere, e.g., in a breakend record that joins the start of a chromosome (VCF 4.3 § 5.4
+/// "Specifying complex rearrangements with breakends").
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Position(i32);
 
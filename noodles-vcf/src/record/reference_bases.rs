@@ -123,6 +123,9 @@ mod tests {
         let bases: ReferenceBases = "AtCgN".parse()?;
         assert_eq!(&bases[..], &expected[..]);
 
+        let bases: ReferenceBases = "ARg".parse()?;
+        assert_eq!(&bases[..], &[Base::A, Base::R, Base::G][..]);
+
         assert_eq!("".parse::<ReferenceBases>(), Err(ParseError::Empty));
         assert_eq!(".".parse::<ReferenceBases>(), Err(ParseError::Missing));
         assert!(matches!(
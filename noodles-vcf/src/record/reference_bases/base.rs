@@ -3,6 +3,9 @@
 use std::{convert::TryFrom, error, fmt};
 
 /// A VCF record reference base.
+///
+/// In addition to `A`, `C`, `G`, `T`, and `N`, this includes the IUPAC ambiguity codes, which some
+/// callers emit in REF and ALT.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Base {
     /// Adenine.
@@ -15,6 +18,26 @@ pub enum Base {
     T,
     /// Any base.
     N,
+    /// Purine (A or G).
+    R,
+    /// Pyrimidine (C or T).
+    Y,
+    /// Strong (C or G).
+    S,
+    /// Weak (A or T).
+    W,
+    /// Keto (G or T).
+    K,
+    /// Amino (A or C).
+    M,
+    /// Not A (C, G, or T).
+    B,
+    /// Not C (A, G, or T).
+    D,
+    /// Not G (A, C, or T).
+    H,
+    /// Not T (A, C, or G).
+    V,
 }
 
 impl From<Base> for char {
@@ -25,6 +48,16 @@ impl From<Base> for char {
             Base::G => 'G',
             Base::T => 'T',
             Base::N => 'N',
+            Base::R => 'R',
+            Base::Y => 'Y',
+            Base::S => 'S',
+            Base::W => 'W',
+            Base::K => 'K',
+            Base::M => 'M',
+            Base::B => 'B',
+            Base::D => 'D',
+            Base::H => 'H',
+            Base::V => 'V',
         }
     }
 }
@@ -37,7 +70,11 @@ impl error::Error for TryFromCharError {}
 
 impl fmt::Display for TryFromCharError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "expected {{A, C, G, T, N}}, got {}", self.0)
+        write!(
+            f,
+            "expected {{A, C, G, T, N, R, Y, S, W, K, M, B, D, H, V}}, got {}",
+            self.0
+        )
     }
 }
 
@@ -51,6 +88,16 @@ impl TryFrom<char> for Base {
             'G' => Ok(Self::G),
             'T' => Ok(Self::T),
             'N' => Ok(Self::N),
+            'R' => Ok(Self::R),
+            'Y' => Ok(Self::Y),
+            'S' => Ok(Self::S),
+            'W' => Ok(Self::W),
+            'K' => Ok(Self::K),
+            'M' => Ok(Self::M),
+            'B' => Ok(Self::B),
+            'D' => Ok(Self::D),
+            'H' => Ok(Self::H),
+            'V' => Ok(Self::V),
             _ => Err(TryFromCharError(value)),
         }
     }
@@ -67,6 +114,16 @@ mod tests {
         assert_eq!(char::from(Base::G), 'G');
         assert_eq!(char::from(Base::T), 'T');
         assert_eq!(char::from(Base::N), 'N');
+        assert_eq!(char::from(Base::R), 'R');
+        assert_eq!(char::from(Base::Y), 'Y');
+        assert_eq!(char::from(Base::S), 'S');
+        assert_eq!(char::from(Base::W), 'W');
+        assert_eq!(char::from(Base::K), 'K');
+        assert_eq!(char::from(Base::M), 'M');
+        assert_eq!(char::from(Base::B), 'B');
+        assert_eq!(char::from(Base::D), 'D');
+        assert_eq!(char::from(Base::H), 'H');
+        assert_eq!(char::from(Base::V), 'V');
     }
 
     #[test]
@@ -76,6 +133,16 @@ mod tests {
         assert_eq!(Base::try_from('G'), Ok(Base::G));
         assert_eq!(Base::try_from('T'), Ok(Base::T));
         assert_eq!(Base::try_from('N'), Ok(Base::N));
+        assert_eq!(Base::try_from('R'), Ok(Base::R));
+        assert_eq!(Base::try_from('Y'), Ok(Base::Y));
+        assert_eq!(Base::try_from('S'), Ok(Base::S));
+        assert_eq!(Base::try_from('W'), Ok(Base::W));
+        assert_eq!(Base::try_from('K'), Ok(Base::K));
+        assert_eq!(Base::try_from('M'), Ok(Base::M));
+        assert_eq!(Base::try_from('B'), Ok(Base::B));
+        assert_eq!(Base::try_from('D'), Ok(Base::D));
+        assert_eq!(Base::try_from('H'), Ok(Base::H));
+        assert_eq!(Base::try_from('V'), Ok(Base::V));
 
         assert_eq!(Base::try_from('Z'), Err(TryFromCharError('Z')));
     }
@@ -69,6 +69,19 @@ where
         &self.inner
     }
 
+    /// Unwraps and returns the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let writer = vcf::Writer::new(Vec::new());
+    /// assert!(writer.into_inner().is_empty());
+    /// ```
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
     /// Writes a VCF header.
     ///
     /// # Examples
@@ -89,6 +102,10 @@ where
 
     /// Writes a VCF record.
     ///
+    /// Genotype fields are written in the order of the record's own format (`FORMAT`), which may
+    /// differ between records. This returns an error if a genotype has a key that is not in the
+    /// format or if the record has genotypes but no format.
+    ///
     /// # Examples
     ///
     /// ```
@@ -106,10 +123,36 @@ where
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
+        validate_genotypes(record)?;
         writeln!(self.inner, "{}", record)
     }
 }
 
+pub(crate) fn validate_genotypes(record: &Record) -> io::Result<()> {
+    match record.format() {
+        Some(format) => {
+            if record.genotypes().iter().all(|g| g.is_compatible(format)) {
+                Ok(())
+            } else {
+                Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "genotype has a key that is not in the format",
+                ))
+            }
+        }
+        None => {
+            if record.genotypes().is_empty() {
+                Ok(())
+            } else {
+                Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "record has genotypes but no format",
+                ))
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::convert::TryFrom;
@@ -175,4 +218,69 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_record_with_different_formats() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::record::genotype::{
+            field::{Key, Value},
+            Field,
+        };
+
+        let mut writer = Writer::new(Vec::new());
+
+        let format_1: Format = "GT:GQ:DP".parse()?;
+
+        let record_1 = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::try_from(1)?)
+            .set_reference_bases("A".parse()?)
+            .set_format(format_1.clone())
+            .add_genotype(Genotype::from_str_format("0|0:13:8", &format_1)?)
+            .add_genotype(Genotype::from_str_format("0|1:21", &format_1)?)
+            .build()?;
+
+        let format_2: Format = "GT:DP:GQ".parse()?;
+
+        // The fields are given in a different order than the format.
+        let genotype = Genotype::try_from(vec![
+            Field::new(Key::Genotype, Some(Value::String(String::from("1|1")))),
+            Field::new(Key::ConditionalGenotypeQuality, Some(Value::Integer(30))),
+            Field::new(Key::ReadDepth, Some(Value::Integer(5))),
+        ])?;
+
+        let record_2 = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::try_from(2)?)
+            .set_reference_bases("C".parse()?)
+            .set_format(format_2)
+            .add_genotype(genotype)
+            .add_genotype(Genotype::from_str_format(".", &format_1)?)
+            .build()?;
+
+        writer.write_record(&record_1)?;
+        writer.write_record(&record_2)?;
+
+        let expected = b"sq0\t1\t.\tA\t.\t.\t.\t.\tGT:GQ:DP\t0|0:13:8\t0|1:21
+sq0\t2\t.\tC\t.\t.\t.\t.\tGT:DP:GQ\t1|1:5:30\t.
+";
+
+        assert_eq!(writer.get_ref(), expected);
+
+        // GQ is not in the format.
+        let record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::try_from(3)?)
+            .set_reference_bases("G".parse()?)
+            .set_format("GT".parse()?)
+            .add_genotype(Genotype::from_str_format("0|0:13", &"GT:GQ".parse()?)?)
+            .build()?;
+
+        let mut writer = Writer::new(Vec::new());
+        assert!(matches!(
+            writer.write_record(&record),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
 }